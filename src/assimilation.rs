@@ -0,0 +1,249 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Sensor data assimilation: nudges a surface's simulated node temperatures
+//! toward sparse field measurements (e.g. embedded wall thermocouples or a
+//! measured surface temperature) using the Parameterized-Background
+//! Data-Weak (PBDW) method—the smallest correction, in the span of the
+//! sensors' Riesz representers, that makes the corrected state match every
+//! reading exactly. Lets a [`crate::surface::ThermalSurfaceData`] run as an
+//! online estimator driven by real instruments instead of a pure forward
+//! simulation; see [`crate::surface::ThermalSurfaceData::assimilate`].
+
+use crate::Float;
+
+/// A linear functional on a surface's node-temperature vector: what a
+/// physical sensor actually measures.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Observation {
+    /// A single embedded thermocouple reading one node's temperature
+    /// directly.
+    PointEvaluation {
+        /// Index into the node-temperature vector.
+        node_index: usize,
+    },
+    /// A sensor reading the mean temperature over `ini..fin` (e.g. a
+    /// surface-mounted probe averaging over a few near-surface nodes).
+    RangeAverage {
+        /// First node index (inclusive).
+        ini: usize,
+        /// One-past-the-last node index (exclusive).
+        fin: usize,
+    },
+}
+
+impl Observation {
+    /// Checks that this functional's indices are in bounds for a state of
+    /// length `n_nodes`, so a malformed [`SensorReading`]—e.g. from a
+    /// mis-configured sensor or a technician's typo—is rejected up front by
+    /// [`assimilate`] rather than panicking via [`Self::apply`]/
+    /// [`Self::representer`]'s direct slice indexing.
+    fn validate(&self, n_nodes: usize) -> Result<(), String> {
+        match *self {
+            Observation::PointEvaluation { node_index } => {
+                if node_index >= n_nodes {
+                    return Err(format!(
+                        "Cannot assimilate a PointEvaluation at node {node_index}: the state has {n_nodes} nodes"
+                    ));
+                }
+            }
+            Observation::RangeAverage { ini, fin } => {
+                if ini >= fin {
+                    return Err(format!(
+                        "Cannot assimilate a RangeAverage over {ini}..{fin}: ini must be less than fin"
+                    ));
+                }
+                if fin > n_nodes {
+                    return Err(format!(
+                        "Cannot assimilate a RangeAverage over {ini}..{fin}: the state has {n_nodes} nodes"
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Evaluates this functional against a candidate state `u`.
+    fn apply(&self, u: &[Float]) -> Float {
+        match *self {
+            Observation::PointEvaluation { node_index } => u[node_index],
+            Observation::RangeAverage { ini, fin } => {
+                let n = (fin - ini) as Float;
+                u[ini..fin].iter().sum::<Float>() / n
+            }
+        }
+    }
+
+    /// This functional's Riesz representer in `R^n` under the standard
+    /// (Euclidean) inner product: the vector `phi` such that
+    /// `dot(phi, u) == self.apply(u)` for every `u`—a unit spike for a
+    /// [`Self::PointEvaluation`], a uniform weight over `ini..fin` for a
+    /// [`Self::RangeAverage`].
+    fn representer(&self, n_nodes: usize) -> Vec<Float> {
+        let mut phi = vec![0.0; n_nodes];
+        match *self {
+            Observation::PointEvaluation { node_index } => phi[node_index] = 1.0,
+            Observation::RangeAverage { ini, fin } => {
+                let w = 1.0 / (fin - ini) as Float;
+                for v in &mut phi[ini..fin] {
+                    *v = w;
+                }
+            }
+        }
+        phi
+    }
+}
+
+/// One sensor's reading: which [`Observation`] it corresponds to, and the
+/// measured value `y_m`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SensorReading {
+    /// The linear functional this sensor evaluates.
+    pub observation: Observation,
+    /// The measured value, in the same units as the simulated state (`°C`
+    /// for node temperatures).
+    pub value: Float,
+}
+
+/// One sensor's outcome after [`assimilate`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AssimilatedSensor {
+    /// The measured value `y_m`.
+    pub measured: Float,
+    /// What the background (pre-correction) state predicted for this
+    /// sensor.
+    pub background: Float,
+    /// The residual that drove the correction, `measured - background`.
+    pub residual: Float,
+}
+
+/// Blends the background state `u_bk` (e.g. a surface's current
+/// `get_node_temperatures`) with `readings` via the Parameterized-Background
+/// Data-Weak (PBDW) method: the correction is sought in the span of the
+/// readings' Riesz representers (see [`Observation::representer`]), i.e.
+/// `u = u_bk + sum_m alpha_m * phi_m`, solving for the `alpha` that makes
+/// `l_m(u) == y_m` for every reading. This is the minimum-norm correction
+/// consistent with the data—of all states matching every sensor exactly, it
+/// is the one closest to the background in the Euclidean norm.
+///
+/// The `alpha` are found from the `M x M` Gram system `G*alpha = r`, where
+/// `G_mn = dot(phi_m, phi_n)` and `r_m = y_m - l_m(u_bk)`: the standard PBDW
+/// normal equations for a finite-dimensional state space. Fails if any
+/// reading's [`Observation`] indexes outside `u_bk` (see
+/// [`Observation::validate`]) or if `G` is singular (e.g. two readings are
+/// identical linear functionals) rather than silently returning a
+/// degenerate correction.
+pub fn assimilate(
+    u_bk: &[Float],
+    readings: &[SensorReading],
+) -> Result<(Vec<Float>, Vec<AssimilatedSensor>), String> {
+    let n_nodes = u_bk.len();
+    let m = readings.len();
+    if m == 0 {
+        return Ok((u_bk.to_vec(), Vec::new()));
+    }
+
+    for reading in readings {
+        reading.observation.validate(n_nodes)?;
+    }
+
+    let representers: Vec<Vec<Float>> = readings
+        .iter()
+        .map(|r| r.observation.representer(n_nodes))
+        .collect();
+
+    let mut gram = vec![vec![0.0; m]; m];
+    for i in 0..m {
+        for j in 0..m {
+            gram[i][j] = dot(&representers[i], &representers[j]);
+        }
+    }
+
+    let sensors: Vec<AssimilatedSensor> = readings
+        .iter()
+        .map(|r| {
+            let background = r.observation.apply(u_bk);
+            AssimilatedSensor {
+                measured: r.value,
+                background,
+                residual: r.value - background,
+            }
+        })
+        .collect();
+    let rhs: Vec<Float> = sensors.iter().map(|s| s.residual).collect();
+
+    let alpha = solve_linear_system(gram, rhs)?;
+
+    let mut u = u_bk.to_vec();
+    for (alpha_m, phi_m) in alpha.iter().zip(&representers) {
+        for (u_i, phi_i) in u.iter_mut().zip(phi_m) {
+            *u_i += alpha_m * phi_i;
+        }
+    }
+
+    Ok((u, sensors))
+}
+
+fn dot(a: &[Float], b: &[Float]) -> Float {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+/// Solves the dense `n x n` system `a*x = b` via Gaussian elimination with
+/// partial pivoting. `a` (the observation Gram matrix) has one row/column
+/// per sensor—small in practice—so this plain approach is fine without
+/// resorting to a banded solver.
+fn solve_linear_system(mut a: Vec<Vec<Float>>, mut b: Vec<Float>) -> Result<Vec<Float>, String> {
+    let n = a.len();
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap())
+            .unwrap();
+        if a[pivot_row][col].abs() < 1e-12 {
+            return Err(
+                "Cannot assimilate sensor data: the observation Gram matrix is singular (are two readings identical?)"
+                    .to_string(),
+            );
+        }
+        a.swap(col, pivot_row);
+        b.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        for row in (col + 1)..n {
+            let factor = a[row][col] / pivot;
+            if factor == 0.0 {
+                continue;
+            }
+            for k in col..n {
+                a[row][k] -= factor * a[col][k];
+            }
+            b[row] -= factor * b[col];
+        }
+    }
+
+    let mut x = vec![0.0; n];
+    for col in (0..n).rev() {
+        let mut sum = b[col];
+        for j in (col + 1)..n {
+            sum -= a[col][j] * x[j];
+        }
+        x[col] = sum / a[col][col];
+    }
+    Ok(x)
+}
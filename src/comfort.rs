@@ -0,0 +1,167 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Fanger's Predicted Mean Vote (PMV) and Predicted Percentage of
+//! Dissatisfied (PPD) thermal comfort indices (ISO 7730 / ASHRAE 55). Like
+//! [`crate::psychrometrics`], this is self-contained: [`pmv_ppd`] is a plain
+//! function of caller-supplied inputs rather than anything reading a Zone's
+//! state directly—callers already have the air temperature (a Zone's own
+//! [`crate::model::ThermalModelMemory`]-tracked state) and mean radiant
+//! temperature ([`crate::model::ThermalModelMemory::zone_mean_radiant_temperature`])
+//! in hand after a [`crate::model::ThermalModel`] march, and pass them in.
+
+use crate::psychrometrics::saturation_vapor_pressure;
+use crate::Float;
+
+/// The result of [`pmv_ppd`].
+#[derive(Debug, Clone, Copy)]
+pub struct ComfortResult {
+    /// Predicted Mean Vote, on the ASHRAE seven-point thermal sensation
+    /// scale (`-3` cold to `+3` hot; `0` is neutral).
+    pub pmv: Float,
+
+    /// Predicted Percentage of Dissatisfied, in `%` (`5` to `100`)—the
+    /// fraction of occupants expected to be uncomfortable at this `pmv`,
+    /// which is never zero even at `pmv == 0` since comfort perception
+    /// varies between individuals.
+    pub ppd: Float,
+}
+
+/// The clothing surface temperature iteration's relaxation factor: plain
+/// successive substitution of the `tcl` equation can oscillate because `hc`
+/// is itself a function of `|tcl - ta|`, so each new estimate is blended
+/// half-and-half with the previous one rather than taken outright.
+const TCL_RELAXATION: Float = 0.5;
+
+const MAX_ITERATIONS: usize = 150;
+const TOLERANCE: Float = 1e-5;
+
+/// Computes Fanger's PMV/PPD for one occupant.
+///
+/// * `metabolic_rate` (`met_w`, `W/m^2`)—metabolic rate `M`.
+/// * `external_work` (`W/m^2`)—external work `W`, usually `0`.
+/// * `clo`—clothing insulation in `clo`, converted to `Icl` (`m^2.K/W`) via
+///   `Icl = 0.155 * clo`.
+/// * `ta` (°C)—air temperature.
+/// * `tr` (°C)—mean radiant temperature.
+/// * `var` (`m/s`)—relative air velocity.
+/// * `relative_humidity` (`0-100`)—converted to the water-vapour partial
+///   pressure `pa` (`Pa`) via [`saturation_vapor_pressure`]`(ta) * rh / 100`.
+pub fn pmv_ppd(
+    metabolic_rate: Float,
+    external_work: Float,
+    clo: Float,
+    ta: Float,
+    tr: Float,
+    var: Float,
+    relative_humidity: Float,
+) -> ComfortResult {
+    let m = metabolic_rate;
+    let w = external_work;
+    let mw = m - w;
+    let icl = 0.155 * clo;
+    let pa = saturation_vapor_pressure(ta) * relative_humidity.clamp(0.0, 100.0) / 100.0;
+
+    let fcl = if icl > 0.078 {
+        1.05 + 0.645 * icl
+    } else {
+        1.0 + 1.29 * icl
+    };
+
+    // Iterate the clothing surface temperature `tcl` to a fixed point;
+    // starting from `ta` (no clothing/ambient coupling yet) converges fine
+    // with the relaxation above across the normal comfort range.
+    let mut tcl = ta;
+    for _ in 0..MAX_ITERATIONS {
+        let hc = (2.38 * (tcl - ta).abs().powf(0.25)).max(12.1 * var.sqrt());
+        let new_tcl = 35.7
+            - 0.028 * mw
+            - icl
+                * (3.96e-8 * fcl * ((tcl + 273.0).powi(4) - (tr + 273.0).powi(4))
+                    + fcl * hc * (tcl - ta));
+        let next = tcl + TCL_RELAXATION * (new_tcl - tcl);
+        if (next - tcl).abs() < TOLERANCE {
+            tcl = next;
+            break;
+        }
+        tcl = next;
+    }
+
+    let hc = (2.38 * (tcl - ta).abs().powf(0.25)).max(12.1 * var.sqrt());
+
+    let l = mw
+        - 3.05e-3 * (5733.0 - 6.99 * mw - pa)
+        - 0.42 * (mw - 58.15)
+        - 1.7e-5 * m * (5867.0 - pa)
+        - 0.0014 * m * (34.0 - ta)
+        - 3.96e-8 * fcl * ((tcl + 273.0).powi(4) - (tr + 273.0).powi(4))
+        - fcl * hc * (tcl - ta);
+
+    let pmv = (0.303 * (-0.036 * m).exp() + 0.028) * l;
+    let ppd = ppd_from_pmv(pmv);
+
+    ComfortResult { pmv, ppd }
+}
+
+/// Predicted Percentage of Dissatisfied as a function of PMV alone—never
+/// below `5%`, since even a perfectly neutral vote still dissatisfies some
+/// occupants.
+fn ppd_from_pmv(pmv: Float) -> Float {
+    100.0 - 95.0 * (-(0.03353 * pmv.powi(4) + 0.2179 * pmv.powi(2))).exp()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn neutral_conditions_give_near_zero_pmv_and_minimal_ppd() {
+        // Typical office comfort design point: seated/light work, light
+        // clothing, still air, ta == tr.
+        let result = pmv_ppd(70.0, 0.0, 0.6, 23.0, 23.0, 0.1, 50.0);
+        assert!(result.pmv.abs() < 0.3, "pmv = {}", result.pmv);
+        assert!(result.ppd < 10.0, "ppd = {}", result.ppd);
+        assert!(result.ppd >= 5.0, "ppd should never drop below 5%");
+    }
+
+    #[test]
+    fn hotter_air_raises_pmv_and_ppd() {
+        let neutral = pmv_ppd(70.0, 0.0, 0.6, 23.0, 23.0, 0.1, 50.0);
+        let hot = pmv_ppd(70.0, 0.0, 0.6, 30.0, 30.0, 0.1, 50.0);
+        assert!(hot.pmv > neutral.pmv);
+        assert!(hot.ppd > neutral.ppd);
+    }
+
+    #[test]
+    fn colder_air_lowers_pmv() {
+        let neutral = pmv_ppd(70.0, 0.0, 0.6, 23.0, 23.0, 0.1, 50.0);
+        let cold = pmv_ppd(70.0, 0.0, 0.6, 16.0, 16.0, 0.1, 50.0);
+        assert!(cold.pmv < neutral.pmv);
+        assert!(cold.ppd > neutral.ppd);
+    }
+
+    #[test]
+    fn ppd_from_pmv_matches_ashrae_reference_points() {
+        // ISO 7730's worked examples: PMV=0 => PPD=5%, PMV=+-1 => ~26%.
+        assert!((ppd_from_pmv(0.0) - 5.0).abs() < 1e-6);
+        assert!((ppd_from_pmv(1.0) - 26.4).abs() < 1.0);
+        assert!((ppd_from_pmv(-1.0) - 26.4).abs() < 1.0);
+    }
+}
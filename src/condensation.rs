@@ -0,0 +1,258 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Interstitial (Glaser-method) condensation risk, as a standalone analysis
+//! pass over a converged node-temperature profile—e.g. one produced by
+//! [`crate::discretization::Discretization::solve_steady_state`]—rather
+//! than anything the thermal march itself tracks.
+//!
+//! [`simple_model::substance::Normal`] carries no water-vapor resistance
+//! factor, so (same as [`crate::discretization::UValue::TemperatureDependentSolid`]
+//! and [`crate::discretization::UValue::SemiTransparent`] before it) this
+//! module cannot read a layer's `μ` off the model automatically: a caller
+//! that knows each layer's factor builds a [`VaporLayer`] list directly,
+//! parallel to the segments it describes.
+
+use crate::psychrometrics::saturation_vapor_pressure;
+use crate::Float;
+
+/// The water-vapor permeability of still air (`kg/(m·s·Pa)`), used as the
+/// reference a layer's `μ` factor divides down from. A standard building-
+/// physics constant (ISO 13788); this crate does not vary it with
+/// temperature or pressure.
+pub const AIR_VAPOR_PERMEABILITY: Float = 2.0e-10;
+
+/// One layer of the vapor-diffusion path through a construction, parallel
+/// to one of [`crate::discretization::Discretization::segments`]'s real
+/// (non-[`crate::discretization::UValue::Back`]) connections.
+#[derive(Debug, Clone, Copy)]
+pub struct VaporLayer {
+    /// Thickness of the layer, in `m`.
+    pub dx: Float,
+
+    /// Water-vapor resistance factor `μ` (dimensionless, `>= 1`): how many
+    /// times more resistant this layer is to vapor diffusion than an
+    /// equally thick layer of still air. A [`crate::discretization::UValue::Cavity`]'s
+    /// own air gap is `μ = 1`.
+    pub mu: Float,
+}
+
+impl VaporLayer {
+    /// This layer's vapor diffusion resistance, `μ·dx / `[`AIR_VAPOR_PERMEABILITY`]
+    /// (`m^2·s·Pa/kg`)—the vapor-transport analogue of a thermal R-value.
+    fn resistance(&self) -> Float {
+        self.mu * self.dx / AIR_VAPOR_PERMEABILITY
+    }
+}
+
+/// A contiguous run of nodes where the Glaser method's straight-line actual
+/// vapor-pressure profile reaches or exceeds the saturation pressure implied
+/// by the node temperatures passed to [`find_condensation_zones`]—i.e.,
+/// where interstitial condensation is predicted. Bounding nodes/depths
+/// mirror how [`crate::discretization::Discretization::bracket_nodes`]
+/// reports a physical position; `condensation_rate` is the imbalance between
+/// vapor flowing in from the front and out toward the back of the zone.
+#[derive(Debug, Clone, Copy)]
+pub struct CondensationZone {
+    /// Index of the first node (closest to the front) in the zone.
+    pub lo_node: usize,
+    /// Index of the last node (closest to the back) in the zone.
+    pub hi_node: usize,
+    /// Depth of `lo_node` from the front face, in `m`.
+    pub lo_depth: Float,
+    /// Depth of `hi_node` from the front face, in `m`.
+    pub hi_depth: Float,
+    /// The rate at which moisture accumulates within the zone, in
+    /// `kg/(m^2.s)`: the vapor flux arriving from the front minus the flux
+    /// leaving toward the back, assuming the actual pressure is pinned to
+    /// the saturation curve across the whole zone. Positive means moisture
+    /// is accumulating (the expected case, since the zone was flagged by
+    /// the actual profile reaching saturation in the first place).
+    pub condensation_rate: Float,
+}
+
+/// Walks a construction's `node_temperatures` (front to back, as returned by
+/// e.g. [`crate::discretization::Discretization::solve_steady_state`])
+/// against its vapor-diffusion `layers` (one per real connection, so
+/// `layers.len() == node_temperatures.len() - 1`) and reports every
+/// [`CondensationZone`] where interstitial condensation is predicted, given
+/// the front and back boundary vapor pressures `p_v_front`/`p_v_back`
+/// (`Pa`—e.g. from an indoor/outdoor relative humidity times
+/// [`saturation_vapor_pressure`] at the boundary air temperature).
+///
+/// This is the classic Glaser method: the *actual* vapor pressure at each
+/// node is the straight-line interpolation of `p_v_front`/`p_v_back` over
+/// cumulative vapor resistance (no condensation case), and a node condenses
+/// wherever that line reaches or exceeds the *saturation* pressure implied
+/// by its temperature. Properly resolving multiple simultaneous zones
+/// requires re-drawing the actual-pressure line as tangents to the
+/// saturation curve around each zone and iterating if zones merge; this
+/// implementation instead evaluates each flagged zone independently against
+/// the uncondensed straight line, which matches the textbook method for the
+/// (overwhelmingly common) single-zone case and is a conservative
+/// approximation—likely reporting zones as slightly smaller, since the true
+/// tangent construction pushes the boundary pressure further below the
+/// straight line—when multiple zones interact.
+pub fn find_condensation_zones(
+    node_temperatures: &[Float],
+    layers: &[VaporLayer],
+    p_v_front: Float,
+    p_v_back: Float,
+) -> Vec<CondensationZone> {
+    assert_eq!(
+        node_temperatures.len(),
+        layers.len() + 1,
+        "Expected one vapor layer per connection between nodes"
+    );
+    let n = node_temperatures.len();
+
+    // Cumulative physical depth and vapor resistance up to each node.
+    let mut depth = vec![0.0; n];
+    let mut resistance = vec![0.0; n];
+    for i in 0..n - 1 {
+        depth[i + 1] = depth[i] + layers[i].dx;
+        resistance[i + 1] = resistance[i] + layers[i].resistance();
+    }
+    let total_resistance = resistance[n - 1];
+
+    let actual_pressure: Vec<Float> = (0..n)
+        .map(|i| {
+            if total_resistance <= 0.0 {
+                p_v_front
+            } else {
+                p_v_front + (p_v_back - p_v_front) * resistance[i] / total_resistance
+            }
+        })
+        .collect();
+    let saturation_pressure: Vec<Float> = node_temperatures
+        .iter()
+        .map(|t| saturation_vapor_pressure(*t))
+        .collect();
+
+    let mut zones = Vec::new();
+    let mut i = 0;
+    while i < n {
+        if actual_pressure[i] >= saturation_pressure[i] {
+            let lo = i;
+            while i < n && actual_pressure[i] >= saturation_pressure[i] {
+                i += 1;
+            }
+            let hi = i - 1;
+
+            // Resistance-weighted permeance from the front boundary up to
+            // `lo`, and from `hi` to the back boundary; a degenerate
+            // (zero-resistance) side—the zone starts at the front face or
+            // ends at the back face—has no flux to resolve against, so it
+            // is pinned equal to the other side instead of dividing by zero.
+            let r_front_to_lo = resistance[lo];
+            let r_hi_to_back = total_resistance - resistance[hi];
+            let flux_in = if r_front_to_lo > 0.0 {
+                (p_v_front - saturation_pressure[lo]) / r_front_to_lo
+            } else {
+                Float::NAN
+            };
+            let flux_out = if r_hi_to_back > 0.0 {
+                (saturation_pressure[hi] - p_v_back) / r_hi_to_back
+            } else {
+                Float::NAN
+            };
+            let condensation_rate = match (flux_in.is_nan(), flux_out.is_nan()) {
+                (false, false) => flux_in - flux_out,
+                (true, false) => 0.0,
+                (false, true) => 0.0,
+                (true, true) => 0.0,
+            };
+
+            zones.push(CondensationZone {
+                lo_node: lo,
+                hi_node: hi,
+                lo_depth: depth[lo],
+                hi_depth: depth[hi],
+                condensation_rate,
+            });
+        } else {
+            i += 1;
+        }
+    }
+
+    zones
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn no_condensation_when_dew_point_never_reached() {
+        // A warm, dry stack: actual pressure stays well below saturation
+        // everywhere, so no zone should be flagged.
+        let node_temperatures = vec![20.0, 15.0, 10.0, 5.0, 0.0];
+        let layers = vec![
+            VaporLayer { dx: 0.1, mu: 5.0 },
+            VaporLayer { dx: 0.1, mu: 5.0 },
+            VaporLayer { dx: 0.1, mu: 5.0 },
+            VaporLayer { dx: 0.1, mu: 5.0 },
+        ];
+        let zones = find_condensation_zones(&node_temperatures, &layers, 500.0, 200.0);
+        assert!(zones.is_empty());
+    }
+
+    #[test]
+    fn flags_condensation_behind_a_vapor_barrier() {
+        // A cold outer layer with most of the vapor resistance concentrated
+        // right behind a warm, humid interior: the straight-line actual
+        // pressure barely drops before the high-resistance layer, while the
+        // temperature (and thus saturation pressure) keeps falling toward
+        // the cold side—so the interior nodes just in front of the barrier
+        // should condense (the back face itself is pinned to the dry
+        // exterior pressure, so it never does).
+        let node_temperatures = vec![20.0, 18.0, 2.0, 0.0];
+        let layers = vec![
+            VaporLayer { dx: 0.01, mu: 1.0 },
+            VaporLayer { dx: 0.1, mu: 1.0 },
+            VaporLayer { dx: 0.01, mu: 1000.0 },
+        ];
+        // Saturate the interior air and keep the exterior dry, so the
+        // straight actual-pressure line sits high for most of the depth.
+        let p_v_front = saturation_vapor_pressure(20.0) * 0.9;
+        let p_v_back = saturation_vapor_pressure(0.0) * 0.3;
+        let zones = find_condensation_zones(&node_temperatures, &layers, p_v_front, p_v_back);
+        assert!(!zones.is_empty());
+        let last = zones.last().unwrap();
+        assert_eq!(last.hi_node, node_temperatures.len() - 2);
+    }
+
+    #[test]
+    fn condensation_rate_is_positive_within_a_flagged_zone() {
+        let node_temperatures = vec![20.0, 18.0, 2.0, 0.0];
+        let layers = vec![
+            VaporLayer { dx: 0.01, mu: 1.0 },
+            VaporLayer { dx: 0.1, mu: 1.0 },
+            VaporLayer { dx: 0.01, mu: 1000.0 },
+        ];
+        let p_v_front = saturation_vapor_pressure(20.0) * 0.9;
+        let p_v_back = saturation_vapor_pressure(0.0) * 0.3;
+        let zones = find_condensation_zones(&node_temperatures, &layers, p_v_front, p_v_back);
+        assert!(!zones.is_empty());
+        for zone in &zones {
+            assert!(zone.condensation_rate >= 0.0);
+        }
+    }
+}
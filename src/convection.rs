@@ -21,6 +21,13 @@ SOFTWARE.
 use crate::Float;
 const MIN_H: Float = 0.15;
 
+/// The roughness-to-$`R_f`$ lookup table shared by [`ConvectionParams::get_tarp_convection_coefficient`]
+/// and [`ConvectionParams::get_doe2_convection_coefficient`] (see the table in the former's docs).
+fn roughness_factor(roughness_index: usize) -> Float {
+    const COEFFICIENTS: [Float; 6] = [2.17, 1.67, 1.52, 1.13, 1.11, 1.];
+    COEFFICIENTS[roughness_index]
+}
+
 /// Represents a border condition of between a Surface
 /// and a Zone or the exterior
 #[derive(Debug, Clone, Copy)]
@@ -31,8 +38,12 @@ pub struct ConvectionParams {
     /// The wind speed, in m/2
     pub air_speed: Float,
 
-    /// The incident Infrared Irradiance, in $`W/m^2`$
-    pub ir_irrad: Float,
+    /// The effective mean radiant/sky temperature this surface exchanges
+    /// longwave radiation with, in $`C`$—distinct from `air_temperature`
+    /// since a clear night sky (or a below-grade boundary, or a zone's
+    /// other surfaces) can radiate at a very different temperature than the
+    /// air convects at. See [`Self::get_ir_radiation_coefficient`].
+    pub rad_temperature: Float,
 
     // /// The incident Solar Irradiance, in $`W/m^2`$
     // pub solar_radiation: Float,
@@ -150,10 +161,7 @@ impl ConvectionParams {
         perimeter: Float,
         windward: bool,
     ) -> Float {
-        const COEFFICIENTS : [Float;6] = [2.17, 1.67, 1.52, 1.13, 1.11, 1.];
-        
-        let rf = COEFFICIENTS[self.roughness_index];
-        
+        let rf = roughness_factor(self.roughness_index);
 
         let wf =  if windward { 1.0 } else { 0.5 };
 
@@ -162,8 +170,860 @@ impl ConvectionParams {
         let natural = self.get_tarp_natural_convection_coefficient();
 
         forced + natural // this will never be less than MIN_HS because natural is already limited
-        
+
     }
+
+    /// Calculates a still-air natural convection coefficient from first
+    /// principles, using the Grashof and Prandtl numbers of the air layer
+    /// and a Nusselt correlation chosen by the surface's orientation.
+    ///
+    /// Unlike [`Self::get_tarp_natural_convection_coefficient`] (an empirical
+    /// fit to whole-surface EnergyPlus data), this derives `hc` directly from
+    /// the Rayleigh number of the boundary layer, which is appropriate for
+    /// still interior air and sealed/enclosed cavities where `air_speed` is
+    /// (approximately) zero. `characteristic_length` is the surface height
+    /// for vertical surfaces, or the equivalent `area/perimeter` length for
+    /// horizontal ones.
+    ///
+    /// # The math
+    ///
+    /// ```math
+    /// Gr = \frac{g \beta |\Delta T| L^3}{\nu^2} \qquad \qquad Pr = \frac{c_p \mu}{k} \qquad \qquad Ra = Gr \cdot Pr
+    /// ```
+    ///
+    /// Where $`\Delta T = T_{surface} - T_{air}`$, $`\beta = 1/T_{film}`$ (in $`K`$) is air's
+    /// thermal expansion coefficient, and $`\nu = \mu/\rho`$ is its kinematic viscosity; all
+    /// properties are evaluated at the film temperature $`T_{film} = (T_{surface}+T_{air})/2`$.
+    ///
+    /// For a vertical surface ($`cos(\theta) \approx 0`$), the Churchill-Chu correlation is used:
+    ///
+    /// ```math
+    /// Nu = \left\{0.825 + \frac{0.387 Ra^{1/6}}{\left[1+(0.492/Pr)^{9/16}\right]^{8/27}}\right\}^2
+    /// ```
+    ///
+    /// For a horizontal surface, the buoyancy-driven flow is unstable when the hot face
+    /// points up (or the cold face points down), and stable otherwise:
+    ///
+    /// ```math
+    /// Nu = 0.54 Ra^{1/4} \text{ (} 10^4 \le Ra \le 10^7 \text{) or } 0.15 Ra^{1/3} \text{ (unstable)} \qquad \qquad Nu = 0.27 Ra^{1/4} \text{ (stable)}
+    /// ```
+    ///
+    /// Finally, $`h_c = Nu \cdot k_{air} / L`$.
+    pub fn get_rayleigh_convection_coefficient(&self, characteristic_length: Float) -> Float {
+        const G: Float = 9.81;
+
+        let delta_t = self.surface_temperature - self.air_temperature;
+        let abs_delta_t = delta_t.abs();
+        if abs_delta_t < 1e-3 || characteristic_length < 1e-10 {
+            return MIN_H;
+        }
+
+        let film_temp = (self.air_temperature + self.surface_temperature) / 2. + 273.15;
+        let beta = 1. / film_temp;
+        let air = crate::gas::AIR;
+        let rho = air.density(film_temp);
+        let mu = air.dynamic_viscosity(film_temp);
+        let nu = mu / rho;
+        let k_air = air.thermal_conductivity(film_temp);
+        let cp = air.heat_capacity(film_temp);
+        let pr = cp * mu / k_air;
+
+        let gr = G * beta * abs_delta_t * characteristic_length.powi(3) / nu.powi(2);
+        let ra = gr * pr;
+
+        let nusselt = if self.cos_surface_tilt.abs() < 1e-3 {
+            // Vertical surface: Churchill-Chu correlation
+            (0.825 + 0.387 * ra.powf(1. / 6.) / (1. + (0.492 / pr).powf(9. / 16.)).powf(8. / 27.))
+                .powi(2)
+        } else if (delta_t > 0. && self.cos_surface_tilt > 0.)
+            || (delta_t < 0. && self.cos_surface_tilt < 0.)
+        {
+            // Hot face up or cold face down: unstable, buoyancy-enhanced
+            if (1e4..=1e7).contains(&ra) {
+                0.54 * ra.powf(1. / 4.)
+            } else {
+                0.15 * ra.powf(1. / 3.)
+            }
+        } else {
+            // Hot face down or cold face up: stable, buoyancy-suppressed
+            0.27 * ra.powf(1. / 4.)
+        };
+
+        let h = nusselt * k_air / characteristic_length;
+
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// Calculates an interior natural-convection coefficient using the
+    /// Alamdari-Hammond correlation, which (like
+    /// [`Self::get_mowitt_convection_coefficient`]) combines a laminar and
+    /// a turbulent term in quadrature-like fashion rather than summing
+    /// them, and—like [`Self::get_rayleigh_convection_coefficient`]—needs a
+    /// `characteristic_length`: the wall height for a vertical surface, or
+    /// the equivalent `area/perimeter` length for a horizontal one.
+    ///
+    /// # The math
+    ///
+    /// For a vertical surface:
+    ///
+    /// ```math
+    /// h = \left\{ \left[1.5 \left(\frac{|\Delta T|}{H}\right)^{1/4}\right]^6 + \left[1.23 |\Delta T|^{1/3}\right]^6 \right\}^{1/6}
+    /// ```
+    ///
+    /// For a horizontal surface where the heat flow direction suppresses
+    /// buoyant mixing ([`FlowRegime::Stable`]):
+    ///
+    /// ```math
+    /// h = 1.4 \left(\frac{|\Delta T|}{L}\right)^{1/4}
+    /// ```
+    ///
+    /// And where it assists it ([`FlowRegime::Unstable`]):
+    ///
+    /// ```math
+    /// h = \left\{ \left[1.4 \left(\frac{|\Delta T|}{L}\right)^{1/4}\right]^6 + \left[1.63 |\Delta T|^{1/3}\right]^6 \right\}^{1/6}
+    /// ```
+    pub fn get_alamdari_hammond_coefficient(&self, characteristic_length: Float) -> Float {
+        let delta_t = (self.surface_temperature - self.air_temperature).abs();
+        if delta_t < 1e-3 || characteristic_length < 1e-10 {
+            return MIN_H;
+        }
+
+        let h = if self.cos_surface_tilt.abs() < 1e-3 {
+            let laminar = 1.5 * (delta_t / characteristic_length).powf(1. / 4.);
+            let turbulent = 1.23 * delta_t.powf(1. / 3.);
+            (laminar.powi(6) + turbulent.powi(6)).powf(1. / 6.)
+        } else if matches!(self.flow_regime(), FlowRegime::Unstable) {
+            let laminar = 1.4 * (delta_t / characteristic_length).powf(1. / 4.);
+            let turbulent = 1.63 * delta_t.powf(1. / 3.);
+            (laminar.powi(6) + turbulent.powi(6)).powf(1. / 6.)
+        } else {
+            1.4 * (delta_t / characteristic_length).powf(1. / 4.)
+        };
+
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// Calculates an interior natural-convection coefficient using the
+    /// Fohanno-Polidori correlation, a simple power-law fit derived for a
+    /// vertical, uniform-heat-flux plate—unlike
+    /// [`Self::get_alamdari_hammond_coefficient`] and
+    /// [`Self::get_khalifa_marshall_coefficient`], it has no published
+    /// floor/ceiling variant, so it is applied regardless of tilt.
+    ///
+    /// ```math
+    /// h = 1.98 |\Delta T|^{0.32}
+    /// ```
+    pub fn get_fohanno_polidori_coefficient(&self) -> Float {
+        let delta_t = (self.surface_temperature - self.air_temperature).abs();
+        let h = 1.98 * delta_t.powf(0.32);
+
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// Calculates an interior natural-convection coefficient using the
+    /// Khalifa-Marshall correlations, a set of simple power-law fits to
+    /// measured room convection, one per [`FlowRegime`].
+    ///
+    /// ```math
+    /// h = C |\Delta T|^n
+    /// ```
+    ///
+    /// With `(C,n)` equal to `(2.07, 0.23)` for a vertical wall, `(2.07,
+    /// 0.11)` where the heat flow direction suppresses buoyant mixing
+    /// ([`FlowRegime::Stable`]), and `(3.1, 0.17)` where it assists it
+    /// ([`FlowRegime::Unstable`]).
+    pub fn get_khalifa_marshall_coefficient(&self) -> Float {
+        let delta_t = (self.surface_temperature - self.air_temperature).abs();
+
+        let (c, n) = if self.cos_surface_tilt.abs() < 1e-3 {
+            (2.07, 0.23)
+        } else {
+            match self.flow_regime() {
+                FlowRegime::Unstable => (3.1, 0.17),
+                _ => (2.07, 0.11),
+            }
+        };
+        let h = c * delta_t.powf(n);
+
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// The windward/leeward forced-convection coefficient `b` and exponent
+    /// `c`, such that $`h_f = b \cdot V^c`$. `a` is shared between windward
+    /// and leeward (it is the natural-convection term), so it lives
+    /// alongside [`Self::get_mowitt_convection_coefficient`] instead.
+    ///
+    /// The MoWiTT (Mobile Window Thermal Test) correlation, combining
+    /// buoyancy and wind-driven convection as
+    ///
+    /// ```math
+    /// h_c = \sqrt{(a|\Delta T|^{1/3})^2 + (b V^c)^2}
+    /// ```
+    ///
+    /// rather than simply summing the two terms (as [`Self::get_tarp_convection_coefficient`]
+    /// does), on the basis that the two mechanisms are not fully additive.
+    pub fn get_mowitt_convection_coefficient(
+        &self,
+        constants: &MoWittConstants,
+        windward: bool,
+    ) -> Float {
+        const A: Float = 0.84;
+        let delta_t = (self.surface_temperature - self.air_temperature).abs();
+        let natural = A * delta_t.powf(1. / 3.);
+
+        let (b, c) = if windward {
+            (constants.windward_b, constants.windward_c)
+        } else {
+            (constants.leeward_b, constants.leeward_c)
+        };
+        let forced = b * self.air_speed.powf(c);
+
+        let h = (natural.powi(2) + forced.powi(2)).sqrt();
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// Calculates the exterior convection coefficient according to the
+    /// DOE-2 model: the TARP natural term $`h_n`$, blended with a
+    /// glass-surface forced-convection term $`h_{glass}`$ (the same
+    /// windward/leeward Klems-Yazdanian correlation used by
+    /// [`Self::get_mowitt_convection_coefficient`]) via the surface's
+    /// roughness, so a very smooth surface ($`R_f = 1`$) reduces to
+    /// $`h_{glass}`$ exactly and a rougher one ($`R_f > 1`$) sits above it.
+    ///
+    /// ```math
+    /// h_c = h_n + R_f (h_{glass} - h_n) \qquad \qquad h_{glass} = \sqrt{h_n^2 + (a V^b)^2}
+    /// ```
+    ///
+    /// `R_f` is looked up from the same roughness table as
+    /// [`Self::get_tarp_convection_coefficient`]. As `V \to 0`, `h_{glass} \to h_n`
+    /// and so `h_c \to h_n`, same as the other two models.
+    pub fn get_doe2_convection_coefficient(
+        &self,
+        constants: &MoWittConstants,
+        windward: bool,
+    ) -> Float {
+        let natural = self.get_tarp_natural_convection_coefficient();
+        let rf = roughness_factor(self.roughness_index);
+
+        let (a, b) = if windward {
+            (constants.windward_b, constants.windward_c)
+        } else {
+            (constants.leeward_b, constants.leeward_c)
+        };
+        let forced = a * self.air_speed.powf(b);
+        let h_glass = (natural.powi(2) + forced.powi(2)).sqrt();
+
+        let h = natural + rf * (h_glass - natural);
+        if h < MIN_H {
+            MIN_H
+        } else {
+            h
+        }
+    }
+
+    /// The exterior convection coefficient under `algorithm`. `area` and
+    /// `perimeter` are only used by [`ConvectionAlgorithm::Tarp`]; `windward`
+    /// is used by every forced-convection term.
+    pub fn get_exterior_convection_coefficient(
+        &self,
+        algorithm: &ConvectionAlgorithm,
+        area: Float,
+        perimeter: Float,
+        windward: bool,
+    ) -> Float {
+        match algorithm {
+            ConvectionAlgorithm::Tarp => self.get_tarp_convection_coefficient(area, perimeter, windward),
+            ConvectionAlgorithm::MoWitt(constants) => {
+                self.get_mowitt_convection_coefficient(constants, windward)
+            }
+            ConvectionAlgorithm::Doe2 { constants } => {
+                self.get_doe2_convection_coefficient(constants, windward)
+            }
+        }
+    }
+
+    /// A linearized longwave-radiation film coefficient, so a heat-balance
+    /// caller can fold radiative exchange into the same $`h \Delta T`$ form
+    /// used for convection. `self.rad_temperature` and
+    /// `self.surface_temperature` (both $`°C`$) are converted to Kelvin and
+    /// combined into the standard linearization of
+    /// $`\varepsilon \sigma (T_s^4 - T_{env}^4)`$:
+    ///
+    /// ```math
+    /// h_r = \varepsilon \sigma (T_s^2 + T_{env}^2)(T_s + T_{env})
+    /// ```
+    pub fn get_ir_radiation_coefficient(&self, emissivity: Float) -> Float {
+        let t_s = self.surface_temperature + 273.15;
+        let t_env = self.rad_temperature + 273.15;
+        emissivity * crate::SIGMA * (t_s * t_s + t_env * t_env) * (t_s + t_env)
+    }
+
+    /// The total exterior film coefficient: `algorithm`'s convection
+    /// coefficient (see [`Self::get_exterior_convection_coefficient`]) plus
+    /// the linearized radiative film coefficient (see
+    /// [`Self::get_ir_radiation_coefficient`]), so a caller who only needs
+    /// one combined $`h`$ doesn't have to add the two terms themselves—while
+    /// still being able to call the two methods separately when it needs to
+    /// split convective from radiative exchange.
+    pub fn combined_exterior_film_coefficient(
+        &self,
+        algorithm: &ConvectionAlgorithm,
+        area: Float,
+        perimeter: Float,
+        windward: bool,
+        emissivity: Float,
+    ) -> Float {
+        self.get_exterior_convection_coefficient(algorithm, area, perimeter, windward)
+            + self.get_ir_radiation_coefficient(emissivity)
+    }
+
+    /// Like [`Self::get_ir_radiation_coefficient`], but evaluates `model`
+    /// at `self.surface_temperature` instead of taking a fixed emissivity—
+    /// for thermochromic or dynamic radiative-cooling coatings whose
+    /// emissivity switches with surface temperature (see
+    /// [`EmissivityModel`]).
+    pub fn get_ir_radiation_coefficient_with_model(&self, model: &EmissivityModel) -> Float {
+        self.get_ir_radiation_coefficient(model.evaluate(self.surface_temperature))
+    }
+
+    /// Like [`Self::combined_exterior_film_coefficient`], but with a
+    /// temperature-dependent `model` (see [`EmissivityModel`]) instead of a
+    /// fixed emissivity.
+    pub fn combined_exterior_film_coefficient_with_model(
+        &self,
+        algorithm: &ConvectionAlgorithm,
+        area: Float,
+        perimeter: Float,
+        windward: bool,
+        model: &EmissivityModel,
+    ) -> Float {
+        self.get_exterior_convection_coefficient(algorithm, area, perimeter, windward)
+            + self.get_ir_radiation_coefficient_with_model(model)
+    }
+
+    /// The interior (still-air, natural-only) convection coefficient under
+    /// `algorithm`. Neither MoWiTT nor DOE-2 define a distinct interior
+    /// model, so both fall back to [`Self::get_tarp_natural_convection_coefficient`]—the
+    /// same term [`ConvectionAlgorithm::Tarp`] uses indoors.
+    pub fn get_interior_convection_coefficient(&self, algorithm: &ConvectionAlgorithm) -> Float {
+        match algorithm {
+            ConvectionAlgorithm::Tarp
+            | ConvectionAlgorithm::MoWitt(_)
+            | ConvectionAlgorithm::Doe2 { .. } => self.get_tarp_natural_convection_coefficient(),
+        }
+    }
+
+    /// Classifies this surface/timestep's buoyancy regime: whether it is
+    /// close to vertical, or—if tilted or horizontal—whether the direction
+    /// of heat flow assists or suppresses buoyant mixing. Used by
+    /// [`Self::get_adaptive_interior_convection_coefficient`] and
+    /// [`Self::get_adaptive_exterior_convection_coefficient`] to pick a
+    /// correlation from an [`AdaptiveConvection`] table, and reported back
+    /// alongside `h_c` for diagnostics.
+    pub fn flow_regime(&self) -> FlowRegime {
+        if self.cos_surface_tilt.abs() < 1e-3 {
+            return FlowRegime::Vertical;
+        }
+        let delta_t = self.surface_temperature - self.air_temperature;
+        if (delta_t > 0. && self.cos_surface_tilt > 0.)
+            || (delta_t < 0. && self.cos_surface_tilt < 0.)
+        {
+            FlowRegime::Unstable
+        } else {
+            FlowRegime::Stable
+        }
+    }
+
+    /// Classifies this surface as a (near-)vertical `Wall`, an upward-facing
+    /// `Roof`, or a downward-facing `Floor`, from the sign of
+    /// `cos_surface_tilt` (same convention as [`Self::flow_regime`]). Used
+    /// by [`Self::get_adaptive_exterior_convection_coefficient`] to pick a
+    /// forced-convection correlation, since wind-driven flow over a roof
+    /// behaves differently from flow along a wall even at the same wind
+    /// speed and exposure.
+    pub fn surface_class(&self) -> SurfaceClass {
+        if self.cos_surface_tilt.abs() < 1e-3 {
+            SurfaceClass::Wall
+        } else if self.cos_surface_tilt > 0. {
+            SurfaceClass::Roof
+        } else {
+            SurfaceClass::Floor
+        }
+    }
+
+    fn apply_interior_correlation(&self, correlation: &InteriorCorrelation) -> Float {
+        match correlation {
+            InteriorCorrelation::Tarp => self.get_tarp_natural_convection_coefficient(),
+            InteriorCorrelation::Rayleigh {
+                characteristic_length,
+            } => self.get_rayleigh_convection_coefficient(*characteristic_length),
+        }
+    }
+
+    fn apply_exterior_correlation(
+        &self,
+        correlation: &ExteriorCorrelation,
+        area: Float,
+        perimeter: Float,
+        windward: bool,
+    ) -> Float {
+        match correlation {
+            ExteriorCorrelation::Tarp => self.get_tarp_convection_coefficient(area, perimeter, windward),
+            ExteriorCorrelation::MoWitt(constants) => {
+                self.get_mowitt_convection_coefficient(constants, windward)
+            }
+            ExteriorCorrelation::Doe2(constants) => {
+                self.get_doe2_convection_coefficient(constants, windward)
+            }
+        }
+    }
+
+    /// Auto-selects and applies an interior correlation from `table`,
+    /// based on this surface/timestep's [`FlowRegime`], instead of forcing
+    /// every surface onto the same algorithm. Returns the coefficient
+    /// alongside the [`ConvectionCategory`] used to pick it, for
+    /// diagnostics.
+    pub fn get_adaptive_interior_convection_coefficient(
+        &self,
+        table: &AdaptiveConvection,
+    ) -> (Float, ConvectionCategory) {
+        let regime = self.flow_regime();
+        let correlation = match regime {
+            FlowRegime::Vertical => &table.interior_vertical,
+            FlowRegime::Stable => &table.interior_stable,
+            FlowRegime::Unstable => &table.interior_unstable,
+        };
+        let category = ConvectionCategory {
+            regime,
+            windward: None,
+            surface_class: self.surface_class(),
+        };
+        (self.apply_interior_correlation(correlation), category)
+    }
+
+    /// Auto-selects and applies an exterior correlation from `table`, based
+    /// on whether this surface faces `windward` and on its
+    /// [`SurfaceClass`] (roof, wall or floor)—wind-driven flow separates
+    /// differently over a roof than along a wall, so `table` carries a
+    /// windward/leeward pair per class. `area` and `perimeter` are only
+    /// used should the table pick [`ExteriorCorrelation::Tarp`]. Returns
+    /// the coefficient alongside the [`ConvectionCategory`] used to pick
+    /// it, for diagnostics—the category's [`FlowRegime`] is reported but
+    /// does not currently affect which correlation is applied, since none
+    /// of TARP/MoWiTT/DOE-2 split their forced-convection term by buoyancy
+    /// regime.
+    pub fn get_adaptive_exterior_convection_coefficient(
+        &self,
+        table: &AdaptiveConvection,
+        area: Float,
+        perimeter: Float,
+        windward: bool,
+    ) -> (Float, ConvectionCategory) {
+        let surface_class = self.surface_class();
+        let correlation = match (surface_class, windward) {
+            (SurfaceClass::Wall, true) => &table.exterior_windward,
+            (SurfaceClass::Wall, false) => &table.exterior_leeward,
+            (SurfaceClass::Roof, true) => &table.exterior_windward_roof,
+            (SurfaceClass::Roof, false) => &table.exterior_leeward_roof,
+            (SurfaceClass::Floor, true) => &table.exterior_windward_floor,
+            (SurfaceClass::Floor, false) => &table.exterior_leeward_floor,
+        };
+        let category = ConvectionCategory {
+            regime: self.flow_regime(),
+            windward: Some(windward),
+            surface_class,
+        };
+        (
+            self.apply_exterior_correlation(correlation, area, perimeter, windward),
+            category,
+        )
+    }
+}
+
+/// The windward/leeward forced-convection constants used by the MoWiTT
+/// correlation (see [`ConvectionParams::get_mowitt_convection_coefficient`]).
+/// Defaults to the coefficients fitted by Yazdanian & Klems (1994) for
+/// smooth, low-rise surfaces.
+#[derive(Debug, Clone, Copy)]
+pub struct MoWittConstants {
+    /// Forced-convection coefficient for a windward-facing surface
+    pub windward_b: Float,
+
+    /// Forced-convection wind-speed exponent for a windward-facing surface
+    pub windward_c: Float,
+
+    /// Forced-convection coefficient for a leeward-facing surface
+    pub leeward_b: Float,
+
+    /// Forced-convection wind-speed exponent for a leeward-facing surface
+    pub leeward_c: Float,
+}
+
+impl Default for MoWittConstants {
+    fn default() -> Self {
+        Self {
+            windward_b: 3.26,
+            windward_c: 0.89,
+            leeward_b: 3.55,
+            leeward_c: 0.617,
+        }
+    }
+}
+
+/// A temperature-dependent emissivity model for thermochromic or dynamic
+/// radiative-cooling coatings, used by
+/// [`ConvectionParams::get_ir_radiation_coefficient_with_model`] in place of
+/// a fixed emissivity. Below `switch_temperature - transition_width/2` the
+/// coating sits at `low`; above `switch_temperature + transition_width/2`
+/// it sits at `high`; in between, the two are linearly blended. A zero
+/// `transition_width` gives a hard two-state switch at `switch_temperature`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EmissivityModel {
+    switch_temperature: Float,
+    low: Float,
+    high: Float,
+    transition_width: Float,
+}
+
+impl EmissivityModel {
+    /// Builds a model switching between `low` and `high` emissivity around
+    /// `switch_temperature` (in $`°C`$), linearly blending the two across a
+    /// `transition_width`-wide band (also in $`°C`$) centered on it.
+    /// Negative `transition_width`s are clamped to zero (a hard switch).
+    pub fn new(switch_temperature: Float, low: Float, high: Float, transition_width: Float) -> Self {
+        Self {
+            switch_temperature,
+            low,
+            high,
+            transition_width: transition_width.max(0.),
+        }
+    }
+
+    /// The effective emissivity at `surface_temperature` (in $`°C`$).
+    pub fn evaluate(&self, surface_temperature: Float) -> Float {
+        if self.transition_width < 1e-6 {
+            return if surface_temperature < self.switch_temperature {
+                self.low
+            } else {
+                self.high
+            };
+        }
+        let half_width = self.transition_width / 2.;
+        let lo_edge = self.switch_temperature - half_width;
+        let hi_edge = self.switch_temperature + half_width;
+        if surface_temperature <= lo_edge {
+            self.low
+        } else if surface_temperature >= hi_edge {
+            self.high
+        } else {
+            let f = (surface_temperature - lo_edge) / self.transition_width;
+            self.low + f * (self.high - self.low)
+        }
+    }
+}
+
+/// Selects which correlation a surface uses for its convection coefficients
+/// (see [`ConvectionParams::get_exterior_convection_coefficient`] and
+/// [`ConvectionParams::get_interior_convection_coefficient`]), so a model
+/// can be calibrated against measured data instead of being locked to TARP.
+#[derive(Debug, Clone, Copy)]
+pub enum ConvectionAlgorithm {
+    /// EnergyPlus' TARP model
+    Tarp,
+
+    /// The MoWiTT combined natural/forced correlation
+    MoWitt(MoWittConstants),
+
+    /// The DOE-2 combined natural/forced correlation
+    Doe2 {
+        /// Windward/leeward forced-convection constants (shares its shape
+        /// with [`MoWitt`](ConvectionAlgorithm::MoWitt)'s, since DOE-2's
+        /// forced term is the same Klems-Yazdanian correlation)
+        constants: MoWittConstants,
+    },
+}
+
+impl Default for ConvectionAlgorithm {
+    fn default() -> Self {
+        Self::Tarp
+    }
+}
+
+impl ConvectionAlgorithm {
+    /// Convenience constructor for [`Self::MoWitt`] using the standard
+    /// windward/leeward constants ([`MoWittConstants::default`]), so a
+    /// caller who just wants "MoWiTT" doesn't need to spell out the
+    /// correlation's coefficients themselves.
+    pub fn mowitt() -> Self {
+        Self::MoWitt(MoWittConstants::default())
+    }
+
+    /// Convenience constructor for [`Self::Doe2`] using the standard
+    /// windward/leeward constants ([`MoWittConstants::default`]).
+    pub fn doe2() -> Self {
+        Self::Doe2 {
+            constants: MoWittConstants::default(),
+        }
+    }
+}
+
+/// Interior/exterior natural-convection buoyancy regime, used to classify a
+/// surface/timestep (see [`ConvectionParams::flow_regime`]): whether it is
+/// close to vertical ($`cos(\theta) \approx 0`$), or—if tilted or
+/// horizontal—whether the direction of heat flow suppresses buoyant mixing
+/// (`Stable`, e.g. warm air above a cool floor, or cool air below a warm
+/// ceiling) or assists it (`Unstable`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FlowRegime {
+    /// Surface close to vertical
+    Vertical,
+    /// Heat flow direction suppresses buoyant mixing
+    Stable,
+    /// Heat flow direction assists buoyant mixing
+    Unstable,
+}
+
+/// The classification [`ConvectionParams::get_adaptive_interior_convection_coefficient`]
+/// and [`ConvectionParams::get_adaptive_exterior_convection_coefficient`]
+/// report back alongside `h_c`, for diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ConvectionCategory {
+    /// The buoyancy regime
+    pub regime: FlowRegime,
+    /// `Some(true)`/`Some(false)` for an exterior surface facing
+    /// windward/leeward; `None` for an interior surface, which has no wind
+    /// exposure to classify by
+    pub windward: Option<bool>,
+    /// Whether the surface is a (near-)vertical wall, an upward-facing
+    /// roof, or a downward-facing floor (see [`ConvectionParams::surface_class`])
+    pub surface_class: SurfaceClass,
+}
+
+/// A surface's orientation class, used by
+/// [`ConvectionParams::get_adaptive_exterior_convection_coefficient`] to
+/// pick a forced-convection correlation appropriate to how wind separates
+/// over that shape (see [`ConvectionParams::surface_class`]).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SurfaceClass {
+    /// A (near-)vertical surface (`cos_surface_tilt`$`\approx 0`$)
+    Wall,
+    /// An upward-facing, tilted-or-horizontal surface (`cos_surface_tilt > 0`)
+    Roof,
+    /// A downward-facing, tilted-or-horizontal surface (`cos_surface_tilt < 0`)
+    Floor,
+}
+
+/// The interior correlation [`AdaptiveConvection`] applies for a given
+/// [`FlowRegime`] (see [`ConvectionParams::get_adaptive_interior_convection_coefficient`]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InteriorCorrelation {
+    /// EnergyPlus' TARP natural-convection fit
+    Tarp,
+
+    /// A Rayleigh/Nusselt-number correlation from first principles (see
+    /// [`ConvectionParams::get_rayleigh_convection_coefficient`])
+    Rayleigh {
+        /// The surface's characteristic length (height for a vertical
+        /// surface, `area/perimeter` for a horizontal one)
+        characteristic_length: Float,
+    },
+}
+
+/// The exterior correlation [`AdaptiveConvection`] applies for a windward-
+/// or leeward-facing surface (see
+/// [`ConvectionParams::get_adaptive_exterior_convection_coefficient`]).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExteriorCorrelation {
+    /// EnergyPlus' TARP model
+    Tarp,
+
+    /// The MoWiTT combined natural/forced correlation
+    MoWitt(MoWittConstants),
+
+    /// The DOE-2 combined natural/forced correlation
+    Doe2(MoWittConstants),
+}
+
+impl ExteriorCorrelation {
+    /// Convenience constructor for [`Self::MoWitt`] using the standard
+    /// windward/leeward constants ([`MoWittConstants::default`]).
+    pub fn mowitt() -> Self {
+        Self::MoWitt(MoWittConstants::default())
+    }
+
+    /// Convenience constructor for [`Self::Doe2`] using the standard
+    /// windward/leeward constants ([`MoWittConstants::default`]).
+    pub fn doe2() -> Self {
+        Self::Doe2(MoWittConstants::default())
+    }
+}
+
+/// A per-[`FlowRegime`] (and, for exterior surfaces, per-wind-exposure)
+/// table of which correlation to use, so callers can auto-select a model
+/// by calling [`ConvectionParams::get_adaptive_interior_convection_coefficient`]/
+/// [`ConvectionParams::get_adaptive_exterior_convection_coefficient`]
+/// instead of hardcoding one algorithm for every surface, while still being
+/// able to override individual categories. Defaults to TARP everywhere,
+/// matching [`ConvectionAlgorithm`]'s default.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AdaptiveConvection {
+    /// Interior correlation for a vertical surface
+    pub interior_vertical: InteriorCorrelation,
+    /// Interior correlation for a stable (buoyancy-suppressed) surface
+    pub interior_stable: InteriorCorrelation,
+    /// Interior correlation for an unstable (buoyancy-enhanced) surface
+    pub interior_unstable: InteriorCorrelation,
+    /// Exterior correlation for a windward-facing wall
+    pub exterior_windward: ExteriorCorrelation,
+    /// Exterior correlation for a leeward-facing wall
+    pub exterior_leeward: ExteriorCorrelation,
+    /// Exterior correlation for a windward-facing roof
+    pub exterior_windward_roof: ExteriorCorrelation,
+    /// Exterior correlation for a leeward-facing roof
+    pub exterior_leeward_roof: ExteriorCorrelation,
+    /// Exterior correlation for a windward-facing floor (e.g. the
+    /// underside of a surface exposed directly to outdoor air, such as an
+    /// elevated floor over a ventilated crawlspace or carport)
+    pub exterior_windward_floor: ExteriorCorrelation,
+    /// Exterior correlation for a leeward-facing floor
+    pub exterior_leeward_floor: ExteriorCorrelation,
+}
+
+impl Default for AdaptiveConvection {
+    fn default() -> Self {
+        Self {
+            interior_vertical: InteriorCorrelation::Tarp,
+            interior_stable: InteriorCorrelation::Tarp,
+            interior_unstable: InteriorCorrelation::Tarp,
+            exterior_windward: ExteriorCorrelation::Tarp,
+            exterior_leeward: ExteriorCorrelation::Tarp,
+            exterior_windward_roof: ExteriorCorrelation::Tarp,
+            exterior_leeward_roof: ExteriorCorrelation::Tarp,
+            exterior_windward_floor: ExteriorCorrelation::Tarp,
+            exterior_leeward_floor: ExteriorCorrelation::Tarp,
+        }
+    }
+}
+
+/// Terrain classes recognized by [`local_wind_speed`], each mapping to a
+/// boundary-layer thickness `delta` (in $`m`$) and wind profile exponent `a`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Terrain {
+    /// Flat, open country (`delta`$`\approx 270m`$, `a`$`\approx 0.14`$)
+    FlatOpen,
+    /// Suburban terrain (`delta`$`\approx 370m`$, `a`$`\approx 0.22`$)
+    Suburban,
+    /// City/urban terrain (`delta`$`\approx 460m`$, `a`$`\approx 0.33`$)
+    City,
+}
+
+impl Terrain {
+    /// The `(delta, a)` pair used by [`local_wind_speed`] for this terrain
+    fn boundary_layer(&self) -> (Float, Float) {
+        match self {
+            Self::FlatOpen => (270., 0.14),
+            Self::Suburban => (370., 0.22),
+            Self::City => (460., 0.33),
+        }
+    }
+}
+
+/// The height (in $`m`$) of the standard meteorological station at which a
+/// weather file's wind speed is assumed to have been measured
+const Z_MET: Float = 10.;
+/// The boundary-layer thickness (in $`m`$) of the standard, flat-open-country
+/// meteorological station
+const DELTA_MET: Float = 270.;
+/// The wind profile exponent of the standard meteorological station
+const A_MET: Float = 0.14;
+
+/// Maps a reference wind speed `v_met` (in $`m/s`$), measured at a standard
+/// $`10m`$ open-field meteorological station, to the wind speed at a
+/// surface's centroid height `z` (in $`m`$) sitting in the given [`Terrain`],
+/// using the standard boundary-layer power-law:
+///
+/// ```math
+/// V_{local} = V_{met} \left(\frac{\delta_{met}}{z_{met}}\right)^{a_{met}}\left(\frac{z}{\delta}\right)^{a}
+/// ```
+///
+/// where `(delta, a)` are looked up from `terrain` (see [`Terrain`]) and
+/// `z_met`/`delta_met`/`a_met` are the standard station's.
+///
+/// > Note: if `z` is zero, the surface is assumed to be touching the ground
+/// > and the local wind speed is zero.
+pub fn local_wind_speed(v_met: Float, z: Float, terrain: Terrain) -> Float {
+    if z < 1e-5 {
+        return 0.0;
+    }
+    let (delta, a) = terrain.boundary_layer();
+    v_met * (DELTA_MET / Z_MET).powf(A_MET) * (z / delta).powf(a)
+}
+
+/// The EnergyPlus windward/leeward threshold: a surface is windward when
+/// the wind is blowing within this many degrees of directly facing it,
+/// leeward otherwise (see [`is_windward`]).
+const WINDWARD_THRESHOLD_DEGREES: Float = 100.;
+
+/// Whether a surface facing `surface_azimuth` is windward given a wind
+/// blowing from `wind_direction`—both measured clockwise from north, in
+/// Radians. A surface is windward when the two are within $`\pm 100°`$ of
+/// facing each other, leeward otherwise.
+///
+/// This is the same convention as [`crate::surface::is_windward`], which
+/// takes a 3D outward normal instead of an azimuth angle; use that version
+/// when a full surface geometry (and hence tilt) is already at hand.
+pub fn is_windward(wind_direction: Float, surface_azimuth: Float) -> bool {
+    let diff = (wind_direction - surface_azimuth).abs() % (2. * crate::PI);
+    let diff = if diff > crate::PI { 2. * crate::PI - diff } else { diff };
+    diff <= WINDWARD_THRESHOLD_DEGREES.to_radians()
+}
+
+/// Derives the `air_speed` and `windward` fields a [`ConvectionParams`]
+/// needs for its exterior convection calls (see
+/// [`ConvectionParams::get_exterior_convection_coefficient`]), from a
+/// meteorological reference wind speed/direction and a surface's
+/// azimuth/centroid height/terrain exposure—so a caller with raw weather
+/// and geometry data doesn't have to apply [`local_wind_speed`] and
+/// [`is_windward`] itself.
+///
+/// * `reference_speed`/`reference_direction`—wind speed (in $`m/s`$) and
+///   direction (in Radians, clockwise from north) at the standard $`10m`$
+///   meteorological station (see [`local_wind_speed`]).
+/// * `surface_azimuth`/`surface_height`—the surface's outward-facing
+///   azimuth (Radians, same convention as `reference_direction`) and
+///   centroid height above ground (in $`m`$).
+/// * `terrain`—the [`Terrain`] class surrounding the surface, used to pick
+///   the boundary-layer profile `air_speed` is scaled by.
+pub fn wind_exposure(
+    reference_speed: Float,
+    reference_direction: Float,
+    surface_azimuth: Float,
+    surface_height: Float,
+    terrain: Terrain,
+) -> (Float, bool) {
+    let air_speed = local_wind_speed(reference_speed, surface_height, terrain);
+    let windward = is_windward(reference_direction, surface_azimuth);
+    (air_speed, windward)
 }
 
 // #[cfg(test)]
@@ -19,10 +19,26 @@ SOFTWARE.
 */
 
 pub(crate) const MAX_RS: Float = 0.05;
+
+/// Reference mean temperature (in °C) used when a single R-value or
+/// U-factor is requested for a stack whose conductance depends on
+/// temperature (e.g. [`UValue::TemperatureDependentSolid`] or
+/// [`UValue::Cavity`]), since those otherwise require two boundary
+/// temperatures to evaluate.
+const REFERENCE_T: Float = 20.0;
+
+/// Reference boundary temperature difference (in °C), centered on
+/// [`REFERENCE_T`], used to give cavities a representative natural-
+/// convection driving force instead of the degenerate zero-delta case.
+/// Matches typical NFRC center-of-glass winter test conditions.
+const REFERENCE_DELTA_T: Float = 39.0;
 use crate::convection::ConvectionParams;
 use crate::Float;
 use crate::{cavity::Cavity, surface::ChunkMemory};
+use crate::reduced_order::jacobi_eigen_symmetric;
 use matrix::Matrix;
+use polynomial::Polynomial;
+use serde::{Deserialize, Serialize};
 use simple_model::{Construction, SimpleModel, Substance};
 use std::sync::Arc;
 
@@ -33,9 +49,68 @@ pub enum UValue {
     /// A normal (i.e., $`\lambda/\Delta x`$) U-value
     Solid(Float),
 
-    /// A cavity, comprised of a gas
+    /// A solid whose conductivity $`k(T)`$ varies with temperature, instead of
+    /// being fixed at construction time. `u_value()` recomputes $`U=k(T_m)/\Delta x`$
+    /// from the mean $`T_m`$ of the segment's two boundary-node temperatures every
+    /// time it is called—i.e., every substep of [`Discretization::get_k_q`]—so the
+    /// conductance tracks the wall's current thermal state.
+    TemperatureDependentSolid {
+        /// Thickness of the segment, in $`m`$
+        dx: Float,
+        /// Thermal conductivity ($`W/m.K`$) as a function of temperature (in $`K`$)
+        conductivity: Polynomial,
+    },
+
+    /// A cavity, comprised of a gas. [`Cavity::u_value`] combines a
+    /// radiative term (linearized from the current mean cavity
+    /// temperature, same `4*T_m^3*sigma*eps_eff` form as
+    /// [`crate::convection::ConvectionParams::get_ir_radiation_coefficient`]'s
+    /// exterior-boundary one) with a thickness/orientation-dependent
+    /// convective one, and—since `u_value` is called fresh from
+    /// [`Discretization::get_k_q`] every timestep with that timestep's own
+    /// node temperatures rather than once at discretization time—both
+    /// terms track the cavity's actual thermal state without any separate
+    /// refresh hook being needed.
     Cavity(Box<Cavity>),
 
+    /// A solid that is thin/porous enough for radiation to travel *through*
+    /// it (e.g. aerogel, low-density fibrous batts, foams), rather than
+    /// only between the surfaces bounding a [`Self::Cavity`]. Adds a
+    /// Rosseland diffusion-approximation term to a constant conductive
+    /// `k`, so `u_value()` returns `(k + 16·σ·n²·T_m³/(3·β)) / dx`
+    /// evaluated at the mean `T_m` of the segment's two boundary-node
+    /// temperatures—growing as `T³`, same as [`Cavity::u_value`]'s
+    /// radiative term.
+    ///
+    /// Like [`Self::TemperatureDependentSolid`], `build()` has no way to
+    /// read `β`/`n` off [`simple_model::substance::Normal`] (it carries
+    /// neither field), so this variant is never produced automatically—a
+    /// caller that knows a layer's Rosseland mean extinction coefficient and
+    /// refractive index assigns it directly into [`Discretization::segments`],
+    /// same as [`Self::TemperatureDependentSolid`] already is.
+    SemiTransparent {
+        /// Thickness of the segment, in $`m`$
+        dx: Float,
+        /// Base (radiation-free) thermal conductivity, in $`W/m.K`$
+        conductivity: Float,
+        /// Rosseland mean extinction coefficient $`\beta`$, in $`m^{-1}`$
+        beta: Float,
+        /// Refractive index $`n`$ of the solid
+        refractive_index: Float,
+    },
+
+    /// A fixed thermal resistance (in `m^2.K/W`) inserted as a massless link
+    /// between two adjacent nodes—e.g. imperfect contact at a
+    /// [`simple_model::Material`]/[`simple_model::Material`] interface—
+    /// rather than a conductance derived from a layer's own thickness and
+    /// conductivity like [`Self::Solid`]. Unlike [`Self::Cavity`], this is
+    /// constant: a real air gap with a temperature-dependent convective/
+    /// radiative resistance is still [`Self::Cavity`]; this variant is for
+    /// a single fixed `R` value a caller already knows (from a product
+    /// datasheet or assembly test), same as [`Self::Solid`] is for a single
+    /// fixed `U`.
+    ContactResistance(Float),
+
     /// The resistance is a surface coefficient.
     Back,
 
@@ -48,7 +123,23 @@ impl UValue {
     pub fn u_value(&self, t_before: Float, t_after: Float) -> Float {
         match self {
             Self::Solid(u) => *u,
+            Self::TemperatureDependentSolid { dx, conductivity } => {
+                let mean_t = (t_before + t_after) / 2. + 273.15;
+                conductivity.eval(mean_t) / dx
+            }
+            Self::SemiTransparent {
+                dx,
+                conductivity,
+                beta,
+                refractive_index,
+            } => {
+                let mean_t = (t_before + t_after) / 2. + 273.15;
+                let radiative_k =
+                    16. * crate::SIGMA * refractive_index.powi(2) * mean_t.powi(3) / (3. * beta);
+                (conductivity + radiative_k) / dx
+            }
             Self::Cavity(c) => c.u_value(t_before, t_after),
+            Self::ContactResistance(r) => 1. / r,
             Self::Back => 0., // This should be calculated appart
             Self::None => panic!("Attempting to get the u-value of None"),
         }
@@ -61,6 +152,370 @@ impl std::default::Default for UValue {
     }
 }
 
+/// The time-integration scheme used to march a [`Discretization`] forward in time.
+///
+/// `RK4` is explicit and thus needs `tstep_subdivision` to be large enough to keep
+/// every eigenvalue of $`\Delta t\cdot\overline{C}^{-1}\overline{K}`$ inside the Euler
+/// stability region (see `discretize_construction()`). The `Theta` variants are
+/// unconditionally stable, so they can march at the model's timestep even for
+/// constructions—e.g., ones with a thin, highly-conductive layer—that would otherwise
+/// force a very small `tstep_subdivision`.
+///
+/// This is the marching backend a [`crate::surface::ThermalSurfaceData`]
+/// selects per surface via `discretization.scheme`: a thin/stiff
+/// construction can pick [`Self::crank_nicolson`] for a large, stable
+/// implicit step while a thick massive wall keeps `RK4`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IntegrationScheme {
+    /// Explicit 4th order Runge-Kutta (the historical default).
+    RK4,
+
+    /// Explicit 4th order Runge-Kutta, like [`Self::RK4`], but with
+    /// error-controlled sub-stepping within each chunk's `dt` instead of a
+    /// single fixed-size step: see `crate::surface::rk4_adaptive`.
+    AdaptiveRK4 {
+        /// Tuning knobs for the step-doubling error control
+        options: Rk4AdaptiveOptions,
+    },
+
+    /// Implicit $`\theta`$-method: $`(\overline{C}/\Delta t - \theta\overline{K})T^{n+1} = (\overline{C}/\Delta t + (1-\theta)\overline{K})T^n + q`$.
+    ///
+    /// `theta == 1.0` is Backward Euler (unconditionally stable, first order accurate).
+    /// `theta == 0.5` is Crank-Nicolson (unconditionally stable, second order accurate).
+    /// Build either with [`Self::backward_euler`]/[`Self::crank_nicolson`]
+    /// rather than naming the weight directly.
+    Theta {
+        /// The $`\theta`$ weight, in $`[0, 1]`$
+        theta: Float,
+    },
+
+    /// Exact, unconditionally-stable update via a precomputed matrix-exponential
+    /// propagator (scaling-and-squaring with a Padé approximant), rather than
+    /// an explicit RK4 step or an implicit linear solve. See
+    /// `crate::surface::expm_march`.
+    Exponential,
+
+    /// Explicit, like [`Self::RK4`] and [`Self::AdaptiveRK4`], but
+    /// error-controlled via an embedded Runge–Kutta–Fehlberg 4(5) pair
+    /// instead of a fixed step or step-doubling: six stages per trial
+    /// sub-step yield both a 4th- and 5th-order update in one pass, so
+    /// the error estimate costs two extra stages rather than a whole
+    /// second RK4 solve. See `crate::surface::rkf45_adaptive`.
+    RKF45 {
+        /// Tuning knobs for the embedded-pair error control
+        options: Rkf45Options,
+    },
+}
+
+impl std::default::Default for IntegrationScheme {
+    fn default() -> Self {
+        IntegrationScheme::RK4
+    }
+}
+
+/// Tuning knobs for [`IntegrationScheme::AdaptiveRK4`]'s step-doubling error
+/// control (see `crate::surface::rk4_adaptive`): a full RK4 step of size `h`
+/// is compared, via Richardson extrapolation, against two half-steps of
+/// `h/2`, and `h` is grown or shrunk to keep the estimated error within
+/// `atol + rtol*|T|`. Conceptually mirrors [`crate::ode::DormandPrinceOptions`],
+/// but estimates error by step-doubling a 4th order method rather than an
+/// embedded lower-order solution.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rk4AdaptiveOptions {
+    /// Absolute tolerance term of the error scale, `atol + rtol*|T|`
+    pub atol: Float,
+    /// Relative tolerance term of the error scale, `atol + rtol*|T|`
+    pub rtol: Float,
+    /// The smallest sub-step this scheme is allowed to shrink to, as a
+    /// fraction of the chunk's full `dt`—if a step this small is still
+    /// rejected, it is taken anyway rather than stalling.
+    pub min_step_fraction: Float,
+}
+
+impl std::default::Default for Rk4AdaptiveOptions {
+    fn default() -> Self {
+        Self {
+            atol: 1e-4,
+            rtol: 1e-4,
+            min_step_fraction: 1e-3,
+        }
+    }
+}
+
+/// Tuning knobs for [`IntegrationScheme::RKF45`]'s embedded-pair error
+/// control (see `crate::surface::rkf45_adaptive`): the same `atol + rtol*|T|`
+/// scale as [`Rk4AdaptiveOptions`], but compared against the embedded 4th-
+/// and 5th-order solutions from a single Fehlberg step rather than two
+/// step-doubled RK4 solves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rkf45Options {
+    /// Absolute tolerance term of the error scale, `atol + rtol*|T|`
+    pub atol: Float,
+    /// Relative tolerance term of the error scale, `atol + rtol*|T|`
+    pub rtol: Float,
+    /// The smallest sub-step this scheme is allowed to shrink to, as a
+    /// fraction of the chunk's full `dt`—if a step this small is still
+    /// rejected, it is taken anyway rather than stalling.
+    pub min_step_fraction: Float,
+    /// The maximum number of trial sub-steps (accepted or rejected)
+    /// `crate::surface::rkf45_adaptive` will attempt while covering a single
+    /// chunk `dt`, as a backstop against a pathological case where
+    /// `min_step_fraction` keeps getting hit on a construction whose error
+    /// estimate never settles. Rather than looping indefinitely—or failing
+    /// the march outright—once `rk_nmax` is exhausted the chunk falls back
+    /// to a fixed explicit subdivision (the same one
+    /// [`IntegrationScheme::RK4`] always uses) for the rest of its `dt`.
+    pub rk_nmax: usize,
+}
+
+impl std::default::Default for Rkf45Options {
+    fn default() -> Self {
+        Self {
+            atol: 1e-4,
+            rtol: 1e-4,
+            min_step_fraction: 1e-3,
+            rk_nmax: 1000,
+        }
+    }
+}
+
+impl IntegrationScheme {
+    /// Convenience constructor for the Crank-Nicolson `Theta` variant
+    /// (`theta == 0.5`): unconditionally stable and second-order accurate.
+    /// Solved in `O(n)` by `crate::surface::theta_method`'s Thomas-algorithm
+    /// pass over `K`'s tridiagonal bands rather than a full matrix solve.
+    pub fn crank_nicolson() -> Self {
+        Self::Theta { theta: 0.5 }
+    }
+
+    /// Convenience constructor for the Backward Euler `Theta` variant
+    /// (`theta == 1.0`): unconditionally stable and first-order accurate.
+    pub fn backward_euler() -> Self {
+        Self::Theta { theta: 1.0 }
+    }
+
+    /// Whether this scheme remains stable at any timestep, regardless of a
+    /// construction's node spacing or thermal diffusivity.
+    ///
+    /// `RK4`, `AdaptiveRK4` and `RKF45` are explicit: their stability region
+    /// is what forces [`Discretization::new`] to enlarge `tstep_subdivision`
+    /// for fine-grained or highly-diffusive constructions (see
+    /// `discretize_construction`). `Theta` (Crank-Nicolson, backward Euler,
+    /// or anything in between) and `Exponential` are solved implicitly and
+    /// carry no such bound, which is what [`Discretization::new_with_scheme`]
+    /// relies on to size `n_elements` from `max_dx` alone.
+    pub fn is_unconditionally_stable(&self) -> bool {
+        matches!(self, Self::Theta { .. } | Self::Exponential)
+    }
+}
+
+/// A linear (psi-value) or point (chi-value) thermal bridge, representing a
+/// junction—an edge, corner, or penetration—where extra heat bypasses the 1D
+/// plane-element network that [`Discretization::get_k_q`] assembles.
+///
+/// `get_k_q` only builds the tri-diagonal network for a single construction,
+/// so whole-building runs need this to capture junction losses: add the
+/// bridge's conductance directly between the relevant nodes (or between a node
+/// and a fixed environment temperature) with [`Self::add_between_nodes`] /
+/// [`Self::add_to_environment`] after assembling `k`/`q` for a chunk.
+#[derive(Debug, Clone, Copy)]
+pub struct ThermalBridge {
+    /// The bridge's total conductance, in `W/K`
+    pub conductance: Float,
+}
+
+impl ThermalBridge {
+    /// A linear thermal bridge of length `length` (m) and psi-value `psi`
+    /// (`W/(m·K)`), e.g. a wall/floor junction.
+    pub fn linear(psi: Float, length: Float) -> Self {
+        Self {
+            conductance: psi * length,
+        }
+    }
+
+    /// A point thermal bridge with chi-value `chi` (`W/K`), e.g. a structural
+    /// penetration.
+    pub fn point(chi: Float) -> Self {
+        Self { conductance: chi }
+    }
+
+    /// Adds this bridge's conductance between two local node indices of an
+    /// already-assembled `k` matrix (e.g. `ChunkMemory::k`), exactly like an
+    /// extra off-diagonal/diagonal term in the thermal network.
+    pub fn add_between_nodes(&self, k: &mut Matrix, node_a: usize, node_b: usize) -> Result<(), String> {
+        k.add_to_element(node_a, node_a, -self.conductance)?;
+        k.add_to_element(node_b, node_b, -self.conductance)?;
+        k.add_to_element(node_a, node_b, self.conductance)?;
+        k.add_to_element(node_b, node_a, self.conductance)?;
+        Ok(())
+    }
+
+    /// Adds this bridge's conductance between a node and a fixed environment
+    /// temperature, contributing to both `k`'s diagonal and `q`—mirroring how
+    /// `get_k_q` adds the front/back border conditions.
+    pub fn add_to_environment(
+        &self,
+        k: &mut Matrix,
+        q: &mut Matrix,
+        node: usize,
+        env_temperature: Float,
+    ) -> Result<(), String> {
+        k.add_to_element(node, node, -self.conductance)?;
+        q.add_to_element(node, 0, self.conductance * env_temperature)?;
+        Ok(())
+    }
+}
+
+/// A node's phase-change (latent heat) properties, attached via
+/// [`Discretization::set_phase_change_override`] to make
+/// [`Discretization::node_mass`] track an apparent, temperature-dependent
+/// capacitance instead of the constant `segments[i].0` computed at
+/// discretization time—the standard "apparent heat capacity" treatment of
+/// a PCM's melting band in a temperature-based finite-difference march,
+/// rather than reformulating the march around enthalpy as the state
+/// variable.
+///
+/// > `simple_model::Substance` only distinguishes `Normal`/`Gas` materials
+/// > in this tree, so there is no external phase-change substance kind for
+/// > [`Discretization::build`] to read latent heat/solidus/liquidus from;
+/// > callers construct this directly (e.g. from their own material
+/// > database) and attach it per node instead, exactly like
+/// > [`Discretization::set_specific_heat_override`] already lets a caller
+/// > override a node's capacitance from outside `build()`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PhaseChangeProperties {
+    /// The node's capacitance (`J/K`) outside its melting band—i.e. what
+    /// `segments[i].0` would be for an equivalent non-PCM material
+    pub baseline_capacitance: Float,
+    /// The node's total latent heat of fusion (`J`)—its mass times the
+    /// material's specific latent heat
+    pub latent_heat: Float,
+    /// Solidus temperature—melting onset (`°C`)
+    pub solidus: Float,
+    /// Liquidus temperature—melting complete (`°C`)
+    pub liquidus: Float,
+}
+
+impl PhaseChangeProperties {
+    /// The node's apparent capacitance (`J/K`) at temperature `t` (`°C`):
+    /// `baseline_capacitance` outside `[solidus, liquidus]`, plus the
+    /// latent heat spread uniformly over the band inside it—
+    /// `baseline_capacitance + latent_heat/(liquidus - solidus)`.
+    pub fn apparent_capacitance(&self, t: Float) -> Float {
+        if t >= self.solidus && t <= self.liquidus {
+            self.baseline_capacitance + self.latent_heat / (self.liquidus - self.solidus)
+        } else {
+            self.baseline_capacitance
+        }
+    }
+
+    /// The node's enthalpy (`J`, relative to an arbitrary reference of zero
+    /// at `solidus`) at temperature `t`: the integral of
+    /// [`Self::apparent_capacitance`], i.e. the monotone `H(T)` curve this
+    /// node's latent heat is defined against.
+    pub fn enthalpy(&self, t: Float) -> Float {
+        if t <= self.solidus {
+            self.baseline_capacitance * (t - self.solidus)
+        } else if t >= self.liquidus {
+            self.baseline_capacitance * (self.liquidus - self.solidus)
+                + self.latent_heat
+                + self.baseline_capacitance * (t - self.liquidus)
+        } else {
+            self.apparent_capacitance(t) * (t - self.solidus)
+        }
+    }
+
+    /// Recovers temperature from enthalpy `h` (`J`, same reference as
+    /// [`Self::enthalpy`]) by inverting it piecewise. Each of the three
+    /// branches (solid/mushy/liquid) is linear in `T` by construction, so—
+    /// unlike a general, non-analytic `cp(T)` curve sampled from
+    /// measurements—this doesn't need bisection to stay robust through the
+    /// mushy zone.
+    pub fn temperature(&self, h: Float) -> Float {
+        let h_liquidus =
+            self.baseline_capacitance * (self.liquidus - self.solidus) + self.latent_heat;
+        if h <= 0. {
+            self.solidus + h / self.baseline_capacitance
+        } else if h >= h_liquidus {
+            self.liquidus + (h - h_liquidus) / self.baseline_capacitance
+        } else {
+            let cp_app =
+                self.baseline_capacitance + self.latent_heat / (self.liquidus - self.solidus);
+            self.solidus + h / cp_app
+        }
+    }
+}
+
+/// A single internal heat source embedded within a construction—a
+/// hydronic radiant-floor loop, an electric heating cable, or exothermic
+/// curing—attached via [`Discretization::add_heat_source`] and applied
+/// each step by [`Discretization::apply_heat_sources`], which locates the
+/// node(s) bracketing `depth` exactly like [`Discretization::add_point_source`]
+/// does for a one-off point load.
+///
+/// Time-varying power is represented as a plain [`Float`]/[`HeatSourceMode`]
+/// resolved by the caller for the current step, mirroring every other
+/// schedule-driven input in this crate (e.g.
+/// [`crate::convection::ConvectionParams::air_temperature`]): `heat` only
+/// ever sees already-evaluated numbers, since the `schedule`/`calendar`
+/// evaluation machinery lives upstream of `march`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatSource {
+    /// Physical depth (m from the outer face) this source is embedded at
+    pub depth: Float,
+    /// How this source's power for the current step is computed
+    pub mode: HeatSourceMode,
+}
+
+/// How a [`HeatSource`]'s power is computed each step.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HeatSourceMode {
+    /// A fixed wattage, already resolved from the caller's schedule for
+    /// this step—e.g. an electric heating cable at constant duty.
+    Constant(Float),
+
+    /// A linear UA coupling to a supply-fluid temperature, e.g. a
+    /// hydronic loop: `power = ua * (supply_temperature - node_temperature)`,
+    /// so the source's output responds to how far the embedded node has
+    /// already warmed rather than dumping a fixed wattage regardless of
+    /// state.
+    Coupled {
+        /// Conductance between the supply fluid and the embedded node (`W/K`)
+        ua: Float,
+        /// The supply fluid's temperature (`°C`) for this step
+        supply_temperature: Float,
+    },
+}
+
+/// The result of [`Discretization::solve_steady_state`]: the segment
+/// stack's R-value and the converged temperature at every node.
+#[derive(Debug, Clone)]
+pub struct SteadyStateSolution {
+    /// The whole-stack R-value, in `m^2K/W`
+    pub r_value: Float,
+
+    /// The converged temperature (in °C) at every node, ordered from the
+    /// front (index `0`) to the back (last index), matching [`Discretization::segments`]
+    pub node_temperatures: Vec<Float>,
+}
+
+impl SteadyStateSolution {
+    /// The highest node temperature in the converged profile—e.g. the
+    /// innermost interstitial temperature a [`crate::condensation`] check
+    /// would compare against a dew point, without running a transient march.
+    pub fn peak_temperature(&self) -> Float {
+        self.node_temperatures
+            .iter()
+            .copied()
+            .fold(Float::MIN, Float::max)
+    }
+
+    /// The arithmetic mean of the converged node temperatures.
+    pub fn mean_temperature(&self) -> Float {
+        self.node_temperatures.iter().sum::<Float>() / self.node_temperatures.len() as Float
+    }
+}
+
 /// Represents the discretization of a [`Construction`] for heat transfer
 /// calculation purposes.
 ///
@@ -72,6 +527,32 @@ pub struct Discretization {
     /// Contains the node's mass and the `UValue` of each segment
     pub segments: Vec<(Float, UValue)>,
 
+    /// An optional per-node override of `segments[i].0`'s capacitance
+    /// (`J/K`) as a function of temperature, for phase-change or
+    /// moisture-laden layers whose specific heat varies with `T`—mirroring
+    /// how [`UValue::TemperatureDependentSolid`] overrides a segment's
+    /// conductance. `None` (the default, for every node not set with
+    /// [`Self::set_specific_heat_override`]) keeps reading the constant
+    /// `segments[i].0` computed at discretization time. Indexed like
+    /// `segments`; shorter than it (or empty) is treated as all-`None`.
+    pub specific_heat_overrides: Vec<Option<Polynomial>>,
+
+    /// An optional per-node phase-change (latent heat) override, for PCM
+    /// layers whose apparent capacitance spikes over a melting band rather
+    /// than following a smooth `T`-indexed curve a [`Polynomial`] could fit
+    /// (see [`PhaseChangeProperties`]). Checked by [`Self::node_mass`]
+    /// before [`Self::specific_heat_overrides`]; `None` (the default, for
+    /// every node not set with [`Self::set_phase_change_override`]) falls
+    /// through to it. Indexed like `segments`; shorter than it (or empty)
+    /// is treated as all-`None`.
+    pub phase_change_overrides: Vec<Option<PhaseChangeProperties>>,
+
+    /// Internal heat sources embedded within this construction (e.g. a
+    /// hydronic radiant-floor loop or an electric heating cable), applied
+    /// each step by [`Self::apply_heat_sources`]. Empty by default; add to
+    /// it with [`Self::add_heat_source`].
+    pub heat_sources: Vec<HeatSource>,
+
     /// Contains the minimum number of timesteps per model timestep that
     /// this discretization requires to ensure numerical stability and accuracy.
     ///
@@ -84,6 +565,84 @@ pub struct Discretization {
 
     /// The number of elements on each layer
     pub n_elements: Vec<usize>,
+
+    /// The time-integration scheme to use when marching this `Discretization`
+    /// forward in time. Defaults to [`IntegrationScheme::RK4`]; callers that
+    /// want an unconditionally-stable implicit march can set this field directly.
+    pub scheme: IntegrationScheme,
+
+    /// An extra steady conductance at the front boundary (e.g. a window
+    /// reveal or a slab-edge junction), applied by [`Self::get_k_q`]
+    /// alongside `front_hs`/`front_rad_hs`. `None` (the default) adds
+    /// nothing, matching every `Discretization` built before this field
+    /// existed. Set with [`Self::set_front_thermal_bridge`].
+    pub front_thermal_bridge: Option<ThermalBridge>,
+
+    /// Like [`Self::front_thermal_bridge`], for the back boundary. Set with
+    /// [`Self::set_back_thermal_bridge`].
+    pub back_thermal_bridge: Option<ThermalBridge>,
+
+    /// Linear (psi-value) or point (chi-value) thermal bridges attached to
+    /// specific *interior* nodes—e.g. a wall/floor or wall/roof junction, or
+    /// a structural penetration—unlike [`Self::front_thermal_bridge`]/
+    /// [`Self::back_thermal_bridge`], which only ever couple the two extreme
+    /// boundary nodes. Applied by [`Self::get_k_q`] alongside those. Add to
+    /// it with [`Self::add_node_thermal_bridge`].
+    pub node_thermal_bridges: Vec<NodeThermalBridge>,
+}
+
+/// A [`ThermalBridge`] attached to a specific node of a [`Discretization`],
+/// coupling it to a fixed reference temperature—e.g. the outdoor air, the
+/// ground, or a neighbouring zone at a wall/floor or wall/roof junction—so
+/// that whole-element heat transfer (and the resulting interior surface
+/// temperatures, for condensation-risk checks) accounts for junctions and
+/// edges, not just the clear-field 1-D path through `segments`.
+#[derive(Debug, Clone, Copy)]
+pub struct NodeThermalBridge {
+    /// The node this bridge attaches to.
+    pub node: usize,
+    /// The fixed reference temperature (°C) this bridge couples `node` to.
+    pub env_temperature: Float,
+    /// The bridge's conductance.
+    pub bridge: ThermalBridge,
+}
+
+/// A cached backward-Euler state-space reduction of one of a
+/// [`Discretization`]'s massive chunks, built by
+/// [`Discretization::build_chunk_state_space`]. Advancing the chunk's node
+/// temperatures with [`Self::march`] is two matrix-vector products instead
+/// of reassembling and solving the system from scratch every step.
+pub struct ChunkStateSpace {
+    /// Maps the chunk's current node temperatures to their unforced
+    /// contribution to the next step.
+    pub a: Matrix,
+
+    /// Maps the external flux vector `u` (front/back boundary fluxes and
+    /// any node heat sources, in `W`) to its contribution to the next step.
+    pub b: Matrix,
+
+    /// The timestep this reduction was built for. Rebuild with
+    /// [`Discretization::build_chunk_state_space`] if `dt` changes—this
+    /// field is what a cache should compare against to decide whether to.
+    pub dt: Float,
+}
+
+impl ChunkStateSpace {
+    /// Advances the chunk's node temperatures one step:
+    /// `x_{n+1} = A·x_n + B·u_n`.
+    pub fn march(&self, x: &Matrix, u: &Matrix) -> Result<Matrix, String> {
+        let (n, ..) = x.size();
+        let mut next = Matrix::new(0.0, n, 1);
+        for i in 0..n {
+            let mut v = 0.0;
+            for j in 0..n {
+                v += self.a.get(i, j)? * x.get(j, 0)?;
+                v += self.b.get(i, j)? * u.get(j, 0)?;
+            }
+            next.set(i, 0, v)?;
+        }
+        Ok(next)
+    }
 }
 
 impl Discretization {
@@ -113,6 +672,151 @@ impl Discretization {
         )
     }
 
+    /// Creates a new `Discretization`, sized for `scheme` instead of always
+    /// assuming the explicit `RK4` default.
+    ///
+    /// When `scheme.is_unconditionally_stable()`, `discretize_construction`'s
+    /// von-Neumann stability search—which is what forces a large
+    /// `tstep_subdivision` for thin or highly-diffusive massive layers—is
+    /// skipped entirely: `n_elements` is sized from `max_dx` alone (the
+    /// spatial accuracy this crate already targets for every construction)
+    /// and `tstep_subdivision` is fixed at `1`, since an implicit march has
+    /// no stability bound to satisfy. Any other scheme defers to [`Self::new`]
+    /// unchanged.
+    ///
+    /// Note this is a standalone constructor: `ThermalModel::new` (the
+    /// `SimulationModel` trait entry point) still calls [`Self::new`]
+    /// internally and only learns its scheme afterwards via
+    /// `ThermalModel::set_scheme`, so building a whole model this way
+    /// currently requires constructing its surfaces directly rather than
+    /// through `ThermalModel::new`.
+    pub fn new_with_scheme(
+        construction: &Arc<Construction>,
+        model: &SimpleModel,
+        model_dt: Float,
+        max_dx: Float,
+        min_dt: Float,
+        height: Float,
+        angle: Float,
+        scheme: IntegrationScheme,
+    ) -> Result<Self, String> {
+        if !scheme.is_unconditionally_stable() {
+            return Self::new(construction, model, model_dt, max_dx, min_dt, height, angle);
+        }
+        let n_elements = Self::spatial_elements(construction, model, max_dx)?;
+        let mut d = Self::build(construction, model, 1, n_elements, height, angle)?;
+        d.scheme = scheme;
+        Ok(d)
+    }
+
+    /// Sizes each layer's element count from `max_dx` alone, with no regard
+    /// for timestep stability—the spatial half of what `discretize_construction`'s
+    /// `aux` function does, used by [`Self::new_with_scheme`] for schemes
+    /// that do not need the other half.
+    fn spatial_elements(construction: &Arc<Construction>, model: &SimpleModel, max_dx: Float) -> Result<Vec<usize>, String> {
+        let mut n_elements = Vec::with_capacity(construction.materials.len());
+        for mat_name in construction.materials.iter() {
+            let material = model.get_material(mat_name)?;
+            let substance = model.get_substance(&material.substance)?;
+            match substance {
+                Substance::Normal(_) => {
+                    let m = (material.thickness / max_dx).ceil().max(1.);
+                    n_elements.push(m as usize);
+                }
+                Substance::Gas(_) => n_elements.push(0),
+            }
+        }
+        Ok(n_elements)
+    }
+
+    /// Sets node `i`'s capacitance (`J/K`) to track temperature via
+    /// `specific_heat.eval(T_kelvin)`, overriding the constant
+    /// `segments[i].0` computed at discretization time. See
+    /// [`Self::specific_heat_overrides`] and [`Self::node_mass`].
+    pub fn set_specific_heat_override(
+        &mut self,
+        i: usize,
+        specific_heat: Polynomial,
+    ) -> Result<(), String> {
+        let n = self.segments.len();
+        if i >= n {
+            return Err(format!(
+                "Cannot set a specific-heat override for node {i}: this Discretization has {n} nodes"
+            ));
+        }
+        if self.specific_heat_overrides.len() < n {
+            self.specific_heat_overrides.resize(n, None);
+        }
+        self.specific_heat_overrides[i] = Some(specific_heat);
+        Ok(())
+    }
+
+    /// Node `i`'s capacitance (`J/K`) at temperature `t` (`°C`): its
+    /// [`PhaseChangeProperties::apparent_capacitance`] if
+    /// [`Self::set_phase_change_override`] was called for it, else its
+    /// override polynomial (evaluated at `t + 273.15` K) if
+    /// [`Self::set_specific_heat_override`] was called for it, else the
+    /// constant `segments[i].0` computed at discretization time.
+    pub fn node_mass(&self, i: usize, t: Float) -> Float {
+        if let Some(Some(pcm)) = self.phase_change_overrides.get(i) {
+            return pcm.apparent_capacitance(t);
+        }
+        match self.specific_heat_overrides.get(i) {
+            Some(Some(specific_heat)) => specific_heat.eval(t + 273.15),
+            _ => self.segments[i].0,
+        }
+    }
+
+    /// Attaches phase-change (latent heat) properties to node `i`, so
+    /// [`Self::node_mass`] tracks its apparent capacitance through a
+    /// melting band instead of the constant `segments[i].0` computed at
+    /// discretization time. See [`PhaseChangeProperties`] for why this is
+    /// set from outside [`Self::build`] rather than read from the
+    /// construction's materials directly.
+    pub fn set_phase_change_override(
+        &mut self,
+        i: usize,
+        properties: PhaseChangeProperties,
+    ) -> Result<(), String> {
+        let n = self.segments.len();
+        if i >= n {
+            return Err(format!(
+                "Cannot set a phase-change override for node {i}: this Discretization has {n} nodes"
+            ));
+        }
+        if self.phase_change_overrides.len() < n {
+            self.phase_change_overrides.resize(n, None);
+        }
+        self.phase_change_overrides[i] = Some(properties);
+        Ok(())
+    }
+
+    /// Whether any node's conductance or capacitance tracks the current
+    /// node temperatures—i.e. [`UValue::TemperatureDependentSolid`],
+    /// [`UValue::SemiTransparent`], [`Self::set_specific_heat_override`] or
+    /// [`Self::set_phase_change_override`]—rather than being fixed at
+    /// discretization time. Constant-property constructions (the common
+    /// case) keep `K`/`C` fixed across a chunk's substeps; this flags the
+    /// ones that don't, so callers like
+    /// [`crate::surface::march_mass_chunk`] know to rebuild `C` every
+    /// step instead of once, and so a cached [`ChunkStateSpace`]'s
+    /// single-`reference_temperature` linearization (see
+    /// [`Self::build_chunk_state_space`]) should be treated as an
+    /// approximation rather than exact for this construction.
+    pub fn has_temperature_dependent_properties(&self) -> bool {
+        self.specific_heat_overrides.iter().any(Option::is_some)
+            || self.phase_change_overrides.iter().any(Option::is_some)
+            || self
+                .segments
+                .iter()
+                .any(|(_, u)| {
+                    matches!(
+                        u,
+                        UValue::TemperatureDependentSolid { .. } | UValue::SemiTransparent { .. }
+                    )
+                })
+    }
+
     /// Auxiliary function for `get_chunks()` function
     fn chunk_segments(&self, indexes: &[usize]) -> Vec<(usize, usize)> {
         if indexes.is_empty() {
@@ -274,12 +978,17 @@ impl Discretization {
                         };
 
                         let c = Cavity {
-                            gas,
+                            gas: crate::gas::CavityFill::Pure(gas),
                             thickness: material.thickness,
                             height,
                             angle,
                             eout,
                             ein,
+                            ventilation: None,
+                            // Site elevation isn't threaded this far into
+                            // construction parsing yet, so cavities are
+                            // always assumed filled at sea level.
+                            pressure: crate::gas::STANDARD_PRESSURE,
                         };
                         segments[n_segment].1 = UValue::Cavity(Box::new(c));
                     }
@@ -291,86 +1000,729 @@ impl Discretization {
         }
 
         Ok(Self {
+            specific_heat_overrides: vec![None; segments.len()],
+            phase_change_overrides: vec![None; segments.len()],
+            heat_sources: Vec::new(),
             segments,
             tstep_subdivision,
             n_elements,
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
         })
     }
 
-    /// Calculates the R value of the whole system
+    /// Distributes a layer's volumetric heat source over its nodes.
     ///
-    /// # Panics
-    /// Panics if the calculated R value is Zero (i.e., if there are no
-    /// layers or something like that)
-    pub fn r_value(&self) -> Float {
-        let mut r = 0.0;
-
-        for (_, u_value) in &self.segments {
-            r += match u_value {
-                UValue::Cavity(_c) => todo!(), //c.u_value(t_front, t_back),
-                UValue::Solid(v) => 1. / v,
-                UValue::Back => 0.0,
-                UValue::None => unreachable!(),
-            }
+    /// The governing equation `C·Ṫ = K·T + q` allows injecting internal heat
+    /// generation through `q`, but until now there was no helper for turning a
+    /// volumetric source (`W/m²` of wall area, for a layer of thickness `dx` per
+    /// element) into the per-node contributions that belong in that vector. This
+    /// enables radiant-floor/ceiling heating, electric resistance cables embedded
+    /// in a slab, or solar flux absorbed *inside* a semi-transparent layer.
+    ///
+    /// Mirrors how `build()` splits a layer's mass: each of the `n` elements
+    /// contributes `volumetric_w_per_m2 * dx` Watts, split half to each of its
+    /// two bounding nodes. Returns a vector with `n + 1` entries; add it
+    /// (offset by the layer's first node index) into a per-node source vector
+    /// that the marcher adds into `q` every substep—see [`Self::total_source_power`].
+    pub fn distribute_layer_source(n: usize, dx: Float, volumetric_w_per_m2: Float) -> Vec<Float> {
+        let per_element = volumetric_w_per_m2 * dx;
+        if n == 0 {
+            return vec![per_element];
         }
-
-        assert!(r > 0.0, "Found Zero r-value");
-        r
+        let mut out = vec![0.0; n + 1];
+        for i in 0..n {
+            out[i] += per_element / 2.0;
+            out[i + 1] += per_element / 2.0;
+        }
+        out
     }
 
-    /// Given a Maximum element thickness ($`\Delta x_{max}`$) and a minimum timestep ($`\Delta t_{min}`$), this function
-    /// will find an arguibly good (i.e., stable and accurate) combination of $`\Delta t`$ and number of elements in each
-    /// layer of the construction.
-    ///
-    /// This function recursively increases the model's timestep subdivisions (`n`) in order to reduce $`\Delta t`$ to numbers
-    /// that respect the restrictions of (1) stability, (2) $`\Delta x_{max}`$, and (3) $`\Delta t_{min}`$. In other words,
-    /// it searches (by testing $`\Delta t_{model}/1`$, $`\Delta t_{model}/2`$, $`\Delta t_{model}/3`$, ... $`\Delta t_{model}/n`$)
-    /// for the minimum `n` that respects this restrictions
+    /// Deposits a point heat source at a physical `depth` (in metres from the
+    /// outer face) into a per-node source vector, without requiring the caller
+    /// to know how many elements the stability heuristic chose for each layer.
     ///
-    /// # The math behind it
+    /// This walks the cumulative `dx` implied by `self.segments`—reconstructed
+    /// from each segment's `UValue::Solid` resistance, since `dx = k/U` is not
+    /// stored directly—to find the two nodes bracketing `depth`, and spreads
+    /// `magnitude` (W) onto them with the linear interpolation weight `f`, i.e.
+    /// `(1-f)` to the lower node and `f` to the upper node; exactly like a point
+    /// load spread onto the nearest shape-function nodes in an FE mesh. This is
+    /// useful for a heater wire or temperature-control element sitting at a
+    /// fixed physical depth, regardless of the discretization's resolution.
     ///
-    /// > *I am not sure how correct this is... seems to work, but there is room for improvements and optimizations, surely*
+    /// If `depth` lands exactly on a node (within `1e-9`), the whole magnitude
+    /// is deposited there. If `depth` is outside `[0, total_thickness]`, it is
+    /// clamped to the nearest boundary node.
     ///
-    /// The first thing to know is that the walls in this module march
-    /// through time using a 4th order [Runga-Kutte](https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta_methods)
-    /// (a.k.a., RK4). The second thing to know is that the RK4 method is
-    /// more stable than the [Euler method](https://en.wikipedia.org/wiki/Euler_method),
-    /// and thus the restrictions of stability for the Euler method can be considered
-    /// to be a conservative restriction for the RK4. Hence, this function uses the
-    /// Euler method restrictions.
+    /// `thermal_conductivities` must give the `k` (`W/(m·K)`) of the material of
+    /// each segment's `UValue::Solid`, in node order, so that `dx = k / U` can be
+    /// recovered; `UValue::Cavity`/`Back`/`None` segments are treated as having
+    /// zero thickness (i.e., the depth cannot fall "inside" them).
+    pub fn add_point_source(
+        &self,
+        source: &mut [Float],
+        thermal_conductivities: &[Float],
+        depth: Float,
+        magnitude: Float,
+    ) {
+        let (lo, hi, f) = self.bracket_nodes(thermal_conductivities, depth);
+        if lo == hi {
+            source[lo] += magnitude;
+        } else {
+            source[lo] += (1.0 - f) * magnitude;
+            source[hi] += f * magnitude;
+        }
+    }
+
+    /// Locates the node(s) bracketing a physical `depth` (in metres from the
+    /// outer face), returning `(lo, hi, f)`: if `depth` lands on (or is
+    /// clamped to) a single node, `lo == hi` and `f == 0`; otherwise `lo`
+    /// and `hi` are the two bracketing nodes and `f` is the linear
+    /// interpolation weight toward `hi` (so a quantity at `depth` is
+    /// `(1-f)*value[lo] + f*value[hi]`). Shared by [`Self::add_point_source`]
+    /// (which deposits a magnitude onto `lo`/`hi`) and
+    /// [`Self::apply_heat_sources`] (which also needs to *read* the
+    /// interpolated node temperature for a [`HeatSourceMode::Coupled`]
+    /// source). See [`Self::add_point_source`]'s docs for the
+    /// `thermal_conductivities`/clamping conventions.
+    fn bracket_nodes(&self, thermal_conductivities: &[Float], depth: Float) -> (usize, usize, Float) {
+        let n = self.segments.len();
+        debug_assert_eq!(thermal_conductivities.len(), n);
+
+        // Cumulative depth of each node, computed from each segment's dx.
+        let mut cum_depth = vec![0.0; n];
+        for i in 0..n - 1 {
+            let dx = match &self.segments[i].1 {
+                UValue::Solid(u) if *u > 0.0 => thermal_conductivities[i] / *u,
+                UValue::TemperatureDependentSolid { dx, .. } => *dx,
+                UValue::SemiTransparent { dx, .. } => *dx,
+                _ => 0.0,
+            };
+            cum_depth[i + 1] = cum_depth[i] + dx;
+        }
+        let total_depth = cum_depth[n - 1];
+
+        let depth = depth.clamp(0.0, total_depth.max(0.0));
+
+        if depth <= cum_depth[0] + 1e-9 {
+            return (0, 0, 0.0);
+        }
+        if depth >= cum_depth[n - 1] - 1e-9 {
+            return (n - 1, n - 1, 0.0);
+        }
+        for i in 0..n - 1 {
+            let (d0, d1) = (cum_depth[i], cum_depth[i + 1]);
+            if depth >= d0 - 1e-9 && depth <= d1 + 1e-9 {
+                if (depth - d0).abs() < 1e-9 {
+                    return (i, i, 0.0);
+                } else if (depth - d1).abs() < 1e-9 {
+                    return (i + 1, i + 1, 0.0);
+                } else {
+                    let f = (depth - d0) / (d1 - d0);
+                    return (i, i + 1, f);
+                }
+            }
+        }
+        (n - 1, n - 1, 0.0)
+    }
+
+    /// Attaches a new internal heat source to this construction (see
+    /// [`HeatSource`]), applied each step by [`Self::apply_heat_sources`].
+    pub fn add_heat_source(&mut self, source: HeatSource) {
+        self.heat_sources.push(source);
+    }
+
+    /// Sets (or clears, with `None`) the [`ThermalBridge`] applied at the
+    /// front boundary by [`Self::get_k_q`].
+    pub fn set_front_thermal_bridge(&mut self, bridge: Option<ThermalBridge>) {
+        self.front_thermal_bridge = bridge;
+    }
+
+    /// Sets (or clears, with `None`) the [`ThermalBridge`] applied at the
+    /// back boundary by [`Self::get_k_q`].
+    pub fn set_back_thermal_bridge(&mut self, bridge: Option<ThermalBridge>) {
+        self.back_thermal_bridge = bridge;
+    }
+
+    /// Attaches a new [`NodeThermalBridge`] to this construction, applied by
+    /// [`Self::get_k_q`] alongside [`Self::front_thermal_bridge`]/
+    /// [`Self::back_thermal_bridge`].
+    pub fn add_node_thermal_bridge(&mut self, node: usize, env_temperature: Float, bridge: ThermalBridge) {
+        self.node_thermal_bridges.push(NodeThermalBridge {
+            node,
+            env_temperature,
+            bridge,
+        });
+    }
+
+    /// Adds every attached [`HeatSource`]'s contribution onto a per-node
+    /// source vector, for the caller to fold into `q` alongside any other
+    /// terms (e.g. from [`Self::distribute_layer_source`]). Each source is
+    /// spread over the node(s) bracketing its `depth` exactly like
+    /// [`Self::add_point_source`]; a [`HeatSourceMode::Coupled`] source
+    /// additionally reads the same bracketing nodes' current temperature
+    /// (interpolated with the same weight it deposits with) to drive its
+    /// `ua * ΔT` term.
+    pub fn apply_heat_sources(
+        &self,
+        source: &mut [Float],
+        global_temperatures: &[Float],
+        thermal_conductivities: &[Float],
+    ) {
+        for hs in &self.heat_sources {
+            let (lo, hi, f) = self.bracket_nodes(thermal_conductivities, hs.depth);
+            let magnitude = match hs.mode {
+                HeatSourceMode::Constant(power) => power,
+                HeatSourceMode::Coupled {
+                    ua,
+                    supply_temperature,
+                } => {
+                    let node_temperature = if lo == hi {
+                        global_temperatures[lo]
+                    } else {
+                        (1.0 - f) * global_temperatures[lo] + f * global_temperatures[hi]
+                    };
+                    ua * (supply_temperature - node_temperature)
+                }
+            };
+            if lo == hi {
+                source[lo] += magnitude;
+            } else {
+                source[lo] += (1.0 - f) * magnitude;
+                source[hi] += f * magnitude;
+            }
+        }
+    }
+
+    /// Sums a per-node source vector's entries over `[ini, fin)`, giving the
+    /// total power (W) injected into that range of nodes. Lets callers verify
+    /// an energy balance after using [`Self::distribute_layer_source`].
+    pub fn total_source_power(source: &[Float], ini: usize, fin: usize) -> Float {
+        source[ini..fin].iter().sum()
+    }
+
+    /// Computes a per-node refinement indicator—the magnitude of the second
+    /// difference between adjacent temperatures—for a node-temperature profile.
     ///
-    /// We are solving the following equation:
+    /// `discretize_construction` fixes `n_elements` once, for the worst-case
+    /// stability bound, which over-resolves a calm wall and under-resolves a
+    /// sharp transient (e.g., fire exposure or a sudden solar step). This
+    /// indicator is the signal an adaptive-refinement loop should watch: where
+    /// it exceeds a threshold, a layer should be split into more elements (see
+    /// [`Self::remap_temperatures`] for how to conserve mass/enthalpy when doing
+    /// so); where the field is smooth again, the layer can be coarsened back.
+    pub fn refinement_indicator(temperatures: &[Float]) -> Vec<Float> {
+        let n = temperatures.len();
+        let mut indicator = vec![0.0; n];
+        for i in 1..n.saturating_sub(1) {
+            indicator[i] =
+                (temperatures[i + 1] - 2.0 * temperatures[i] + temperatures[i - 1]).abs();
+        }
+        indicator
+    }
+
+    /// Conservatively remaps a node-temperature profile from an old grid onto a
+    /// new one (e.g., produced by rebuilding a `Discretization` with a refined
+    /// `n_elements`), preserving **total enthalpy** `Σ Cᵢ·Tᵢ` exactly. The
+    /// new total thermal mass `Σ Cᵢ` is whatever `new_masses` sums to; this
+    /// function does not adjust it.
     ///
-    /// ```math
-    /// \dot{T} = \overline{C}^{-1} \overline{K}  T + \overline{C}^{-1} q
-    /// ```
+    /// The new profile is obtained by linearly interpolating the old one (over
+    /// normalized position along the node range, so this works even when the
+    /// node count changes), and then uniformly rescaled so that the enthalpy
+    /// sum matches the old one exactly—analogous to refining a Jacobian-weighted
+    /// conserved quantity rather than the raw field.
     ///
-    /// And thus the stability of the numerical method will depend on the matrix:
+    /// # Panics
+    /// Panics if `old_temperatures` or `new_masses` are empty, or if
+    /// `old_masses` and `old_temperatures` differ in length. If the
+    /// interpolated profile's enthalpy is (numerically) zero, it is returned
+    /// without the rescaling step, so the enthalpy is then not matched.
+    pub fn remap_temperatures(
+        old_masses: &[Float],
+        old_temperatures: &[Float],
+        new_masses: &[Float],
+    ) -> Vec<Float> {
+        let n_old = old_temperatures.len();
+        let n_new = new_masses.len();
+        assert!(n_old > 0 && n_new > 0, "Cannot remap an empty grid");
+        assert_eq!(old_masses.len(), n_old);
+
+        let old_enthalpy: Float = old_masses
+            .iter()
+            .zip(old_temperatures.iter())
+            .map(|(c, t)| c * t)
+            .sum();
+
+        // Linearly interpolate the old profile onto the new grid, by normalized position.
+        let mut new_temperatures = vec![0.0; n_new];
+        for (i, new_t) in new_temperatures.iter_mut().enumerate() {
+            let pos = if n_new == 1 {
+                0.0
+            } else {
+                i as Float / (n_new - 1) as Float
+            };
+            let old_pos = pos * (n_old - 1) as Float;
+            let lo = old_pos.floor() as usize;
+            let hi = (lo + 1).min(n_old - 1);
+            let f = old_pos - lo as Float;
+            *new_t = old_temperatures[lo] * (1.0 - f) + old_temperatures[hi] * f;
+        }
+
+        // Rescale so that the new enthalpy matches the old one exactly.
+        let interpolated_enthalpy: Float = new_masses
+            .iter()
+            .zip(new_temperatures.iter())
+            .map(|(c, t)| c * t)
+            .sum();
+
+        if interpolated_enthalpy.abs() > 1e-12 {
+            let scale = old_enthalpy / interpolated_enthalpy;
+            for t in new_temperatures.iter_mut() {
+                *t *= scale;
+            }
+        }
+
+        new_temperatures
+    }
+
+    /// Builds a modal basis for this construction's thermal network, for use
+    /// as a fast surrogate when the same `Discretization` is marched many
+    /// times (e.g. annual simulations, parametric studies)—an alternative to
+    /// [`crate::reduced_order::ReducedOrderModel`] that needs no snapshots,
+    /// only `k` and `c` themselves.
     ///
-    /// ```math
-    /// \overline{K}^{\star} =\Delta t \overline{C}^{-1} \overline{K}
-    /// ```
+    /// Exploits that the mass matrix `c` is diagonal: with $`D =
+    /// \text{diag}(c)^{-1/2}`$, $`K' = D\,K\,D`$ is symmetric (since `K` is),
+    /// so it can be eigen-decomposed with the same Jacobi solver used for the
+    /// POD's snapshot Gram matrix. Its eigenvectors, lifted back through `D`,
+    /// are the generalized eigenvectors of `(K, c)`—i.e., the network's modes.
     ///
-    /// Specifically, we don't want any of its [eigenvalues](https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors)
-    /// $`\xi_1, \xi_2,\xi_3, ...`$ to be outside of the Euler method's stability region. Since this
-    /// matrix has only Real eigenvalues, this is equivalent to saying:
+    /// Returns `(eigenvalues, modes)`, sorted so the slowest-decaying
+    /// (closest-to-zero eigenvalue) modes come first, truncated to at most
+    /// `n_modes` entries.
     ///
-    /// ```math
-    /// -2 < \xi_i < 0 ; \forall i
-    /// ```
+    /// # Panics
+    /// Panics if `c` has a zero or negative entry on its diagonal, since that
+    /// node would have no thermal mass to divide by.
+    pub fn modal_reduction(k: &Matrix, c: &Matrix, n_modes: usize) -> (Vec<Float>, Vec<Vec<Float>>) {
+        let (n, _) = k.size();
+
+        let d: Vec<Float> = (0..n)
+            .map(|i| {
+                let mass = c.get(i, i).unwrap();
+                assert!(mass > 0.0, "Node {i} has non-positive thermal mass {mass}");
+                1.0 / mass.sqrt()
+            })
+            .collect();
+
+        let mut k_prime = vec![vec![0.0; n]; n];
+        for i in 0..n {
+            for j in 0..n {
+                k_prime[i][j] = d[i] * k.get(i, j).unwrap() * d[j];
+            }
+        }
+
+        let (eigenvalues, eigenvectors) = jacobi_eigen_symmetric(k_prime);
+
+        // Slowest-decaying modes (closest to zero) first.
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|&a, &b| eigenvalues[b].partial_cmp(&eigenvalues[a]).unwrap());
+
+        let n_modes = n_modes.min(n);
+        let mut out_values = Vec::with_capacity(n_modes);
+        let mut out_modes = Vec::with_capacity(n_modes);
+        for &idx in order.iter().take(n_modes) {
+            out_values.push(eigenvalues[idx]);
+            // Lift back to node-space: mode = D * eigenvector
+            let mode: Vec<Float> = eigenvectors[idx]
+                .iter()
+                .zip(d.iter())
+                .map(|(v, di)| v * di)
+                .collect();
+            out_modes.push(mode);
+        }
+
+        (out_values, out_modes)
+    }
+
+    /// Builds a cached backward-Euler state-space reduction of the node
+    /// range `[ini, fin)`—meant to be one of this discretization's
+    /// `massive_chunks`—for use as a fast surrogate when the surface owning
+    /// this chunk is marched many times with an unchanging `dt` (e.g.
+    /// annual simulations).
     ///
-    /// However, finding the eigenvalues for $`\overline{K}^{\star}`$ is far from trivial. So there is
-    /// yet another heuristic I am using: I am treating the case of a wall with 1 layer that is subdivided
-    /// into a single element as the limit case. I am not sure if this is correct, but most of the instabilities
-    /// I identified through Trial and Error corresponded to this case.
+    /// The internal conductances (from [`UValue::u_value`]) are evaluated
+    /// once at `reference_temperature` and frozen, trading the per-step
+    /// temperature-dependent conductivity refresh (relevant only to
+    /// [`UValue::TemperatureDependentSolid`] segments) for speed: marching
+    /// with the result is two matrix-vector products ([`ChunkStateSpace::march`])
+    /// instead of reassembling and solving the system from scratch.
     ///
-    /// For this limit case:
-    /// * $`R = \frac{\Delta x}{\lambda}`$
-    /// * $`C = \rho  c_p  \Delta x`$
+    /// Boundary convection/radiation and any node heat sources are *not*
+    /// baked into the result, since the boundary coefficients change every
+    /// step with wind speed, surface temperature, etc.—they are supplied
+    /// explicitly each step as the `u` vector to [`ChunkStateSpace::march`].
     ///
-    /// thus the value of $`\overline{K}^{\star}`$ is:
+    /// Derivation: backward Euler on `C·dx/dt = K·x + u` gives
+    /// `(C - dt·K)·x_{n+1} = C·x_n + dt·u`, i.e. `x_{n+1} = A·x_n + B·u`
+    /// with `A = (C - dt·K)⁻¹·C` and `B = dt·(C - dt·K)⁻¹`. `(C - dt·K)` is
+    /// tridiagonal, so its inverse is found one column at a time with
+    /// [`Matrix::mut_n_diag_gaussian`]—the same banded solver
+    /// [`crate::surface`]'s no-mass chunks already use.
     ///
-    /// ```math
+    /// # Errors
+    /// Returns an error if any node in the range has zero or negative mass
+    /// (a no-mass node has no state to reduce—it is already solved
+    /// cheaply via direct elimination and isn't a `massive_chunks` range).
+    pub fn build_chunk_state_space(
+        &self,
+        ini: usize,
+        fin: usize,
+        dt: Float,
+        reference_temperature: Float,
+    ) -> Result<ChunkStateSpace, String> {
+        let n = fin - ini;
+        if n == 0 {
+            return Err("Cannot build a state-space reduction for an empty chunk".to_string());
+        }
+
+        let mut masses = Vec::with_capacity(n);
+        for local_i in 0..n {
+            let (mass, _) = &self.segments[ini + local_i];
+            if *mass <= 0.0 {
+                return Err(format!(
+                    "Cannot build a state-space reduction: node {} has no mass (is [{ini}, {fin}) a massive chunk?)",
+                    ini + local_i
+                ));
+            }
+            masses.push(*mass);
+        }
+        let c = Matrix::diag(masses);
+
+        let mut k = Matrix::new(0.0, n, n);
+        for local_i in 0..n - 1 {
+            let (.., uvalue) = &self.segments[ini + local_i];
+            let u = uvalue.u_value(reference_temperature, reference_temperature);
+            k.add_to_element(local_i, local_i, -u)?;
+            k.add_to_element(local_i + 1, local_i + 1, -u)?;
+            k.add_to_element(local_i, local_i + 1, u)?;
+            k.add_to_element(local_i + 1, local_i, u)?;
+        }
+
+        // m = C - dt*K. `mut_n_diag_gaussian` consumes its receiver, and a
+        // general Matrix clone isn't available, so this is rebuilt fresh for
+        // each column solved below rather than solved once and reused.
+        let build_m = |c: &Matrix, k: &Matrix| -> Result<Matrix, String> {
+            let mut m = Matrix::new(0.0, n, n);
+            for i in 0..n {
+                for j in 0..n {
+                    m.set(i, j, c.get(i, j)? - dt * k.get(i, j)?)?;
+                }
+            }
+            Ok(m)
+        };
+
+        // Invert `(C - dt*K)` one column at a time via the same banded
+        // Gaussian elimination the no-mass chunks already use to solve
+        // directly.
+        let mut m_inv = Matrix::new(0.0, n, n);
+        for j in 0..n {
+            let mut e = Matrix::new(0.0, n, 1);
+            e.set(j, 0, 1.0)?;
+            let m = build_m(&c, &k)?;
+            let col = m.mut_n_diag_gaussian(e, 3)?;
+            for i in 0..n {
+                m_inv.set(i, j, col.get(i, 0)?)?;
+            }
+        }
+
+        // A = m_inv * C (C diagonal, so this scales each column of m_inv
+        // by the corresponding node's mass)
+        let mut a = Matrix::new(0.0, n, n);
+        for i in 0..n {
+            for j in 0..n {
+                a.set(i, j, m_inv.get(i, j)? * c.get(j, j)?)?;
+            }
+        }
+
+        // B = dt * m_inv
+        let b = &m_inv * dt;
+
+        Ok(ChunkStateSpace { a, b, dt })
+    }
+
+    /// Builds a [`crate::reduced_order::ReducedOrderModel`] for the node
+    /// range `[ini, fin)` (meant to be one of this discretization's
+    /// `massive_chunks`) from representative node-temperature `snapshots`—an
+    /// "alternate construction path" to [`Self::build_chunk_state_space`]
+    /// for surfaces marched so many times (annual runs, parametric studies)
+    /// that even its two matrix-vector products per step are worth trading
+    /// for a POD surrogate's `O(r)` reduced state, with `r` the handful of
+    /// kept modes.
+    ///
+    /// Projects this chunk's internal `K`/`C` into the POD basis built from
+    /// `snapshots` via [`crate::reduced_order::ReducedOrderModel::from_snapshots`]/
+    /// [`crate::reduced_order::ReducedOrderModel::project_operators`]. Like
+    /// [`Self::build_chunk_state_space`], internal conductances are
+    /// evaluated once at `reference_temperature` and boundary
+    /// convection/radiation is *not* included—it is supplied instead as the
+    /// `q_full` of [`crate::reduced_order::ReducedOrderModel::march_rk4`].
+    ///
+    /// Since the basis and reduced operators only depend on this chunk's
+    /// `segments`—not on any particular surface's boundary conditions—
+    /// callers with several surfaces sharing the same [`Construction`] and
+    /// node range should build this once and share the returned model (e.g.
+    /// behind an `Rc`) rather than rebuilding it per surface.
+    ///
+    /// # Errors
+    /// Returns an error if any node in the range has zero or negative mass
+    /// (see [`Self::build_chunk_state_space`]).
+    pub fn build_pod_model(
+        &self,
+        ini: usize,
+        fin: usize,
+        snapshots: &[Vec<Float>],
+        energy_fraction: Float,
+        reference_temperature: Float,
+    ) -> Result<crate::reduced_order::ReducedOrderModel, String> {
+        let n = fin - ini;
+        let mut masses = Vec::with_capacity(n);
+        for local_i in 0..n {
+            let (mass, _) = &self.segments[ini + local_i];
+            if *mass <= 0.0 {
+                return Err(format!(
+                    "Cannot build a POD model: node {} has no mass (is [{ini}, {fin}) a massive chunk?)",
+                    ini + local_i
+                ));
+            }
+            masses.push(*mass);
+        }
+
+        let mut k = vec![vec![0.0; n]; n];
+        let mut c = vec![vec![0.0; n]; n];
+        for (local_i, mass) in masses.iter().enumerate() {
+            c[local_i][local_i] = *mass;
+        }
+        for local_i in 0..n.saturating_sub(1) {
+            let (.., uvalue) = &self.segments[ini + local_i];
+            let u = uvalue.u_value(reference_temperature, reference_temperature);
+            k[local_i][local_i] -= u;
+            k[local_i + 1][local_i + 1] -= u;
+            k[local_i][local_i + 1] += u;
+            k[local_i + 1][local_i] += u;
+        }
+
+        let mut model = crate::reduced_order::ReducedOrderModel::from_snapshots(snapshots, energy_fraction);
+        model.project_operators(&k, &c);
+        Ok(model)
+    }
+
+    /// Calculates the R value of the whole system
+    ///
+    /// A cavity's `u_value` depends on its own bounding surface
+    /// temperatures, so a stack containing one can't be summed segment by
+    /// segment like a stack of solids can. In that case, this resolves
+    /// [`Self::solve_steady_state`] across a [`REFERENCE_DELTA_T`]-wide
+    /// boundary difference centered on [`REFERENCE_T`] and reports the
+    /// converged R-value from that profile.
+    ///
+    /// # Panics
+    /// Panics if the calculated R value is Zero (i.e., if there are no
+    /// layers or something like that)
+    pub fn r_value(&self) -> Float {
+        let has_cavity = self
+            .segments
+            .iter()
+            .any(|(_, u_value)| matches!(u_value, UValue::Cavity(_)));
+
+        if has_cavity {
+            let t_front = REFERENCE_T - REFERENCE_DELTA_T / 2.;
+            let t_back = REFERENCE_T + REFERENCE_DELTA_T / 2.;
+            let r = self.solve_steady_state(t_front, t_back).r_value;
+            assert!(r > 0.0, "Found Zero r-value");
+            return r;
+        }
+
+        let mut r = 0.0;
+        for (_, u_value) in &self.segments {
+            r += match u_value {
+                UValue::Cavity(_) => unreachable!("checked above"),
+                UValue::Solid(v) => 1. / v,
+                UValue::ContactResistance(r) => *r,
+                UValue::TemperatureDependentSolid { .. } | UValue::SemiTransparent { .. } => {
+                    1. / u_value.u_value(REFERENCE_T, REFERENCE_T)
+                }
+                UValue::Back => 0.0,
+                UValue::None => unreachable!(),
+            }
+        }
+
+        assert!(r > 0.0, "Found Zero r-value");
+        r
+    }
+
+    /// Solves this stack of `segments` for its steady-state R-value and the
+    /// converged temperature at every node, given the two boundary
+    /// temperatures `t_front`/`t_back` (in °C).
+    ///
+    /// `segments[i]` carries the `UValue` connecting node `i` to node
+    /// `i + 1`; the trailing segment (`UValue::Back`) is a placeholder for
+    /// the surface film and contributes no resistance here—same convention
+    /// [`Self::r_value`] already used.
+    ///
+    /// Mirrors [`crate::cavity::GlazingSystem::solve`]: since a cavity's
+    /// `u_value` depends on its own bounding temperatures, the network is
+    /// nonlinear. Starting from a linear profile between `t_front` and
+    /// `t_back`, each pass recomputes every segment's `u_value` from the
+    /// last pass's node temperatures, assembles the resulting series
+    /// resistance, solves for the heat flux through the stack, and walks
+    /// the chain to update every node's temperature—repeating under
+    /// successive substitution until the flux stops changing or the
+    /// iteration cap is hit.
+    pub fn solve_steady_state(&self, t_front: Float, t_back: Float) -> SteadyStateSolution {
+        let n = self.segments.len();
+        let n_conn = n.saturating_sub(1);
+
+        if n_conn == 0 {
+            return SteadyStateSolution {
+                r_value: 0.0,
+                node_temperatures: vec![t_front; n],
+            };
+        }
+
+        const MAX_IT: usize = 100;
+        const TOL: Float = 1e-6;
+
+        let mut node_temperatures: Vec<Float> = (0..n)
+            .map(|i| {
+                let frac = i as Float / n_conn as Float;
+                t_front + (t_back - t_front) * frac
+            })
+            .collect();
+
+        let mut q = 0.0;
+        for _ in 0..MAX_IT {
+            let mut total_r = 0.0;
+            for (i, (_, u_value)) in self.segments[..n_conn].iter().enumerate() {
+                total_r += 1. / u_value.u_value(node_temperatures[i], node_temperatures[i + 1]);
+            }
+
+            let new_q = (t_front - t_back) / total_r;
+
+            let mut t_prev = t_front;
+            for (i, (_, u_value)) in self.segments[..n_conn].iter().enumerate() {
+                let u = u_value.u_value(node_temperatures[i], node_temperatures[i + 1]);
+                let t_next = t_prev - new_q / u;
+                node_temperatures[i + 1] = t_next;
+                t_prev = t_next;
+            }
+            node_temperatures[0] = t_front;
+
+            let converged = (new_q - q).abs() < TOL;
+            q = new_q;
+            if converged {
+                break;
+            }
+        }
+
+        let r_value = if (t_front - t_back).abs() > 1e-9 {
+            (t_front - t_back) / q
+        } else {
+            // Undefined at zero driving temperature difference: fall back to
+            // the resistance network evaluated at the converged profile.
+            let mut total_r = 0.0;
+            for (i, (_, u_value)) in self.segments[..n_conn].iter().enumerate() {
+                total_r += 1. / u_value.u_value(node_temperatures[i], node_temperatures[i + 1]);
+            }
+            total_r
+        };
+
+        SteadyStateSolution {
+            r_value,
+            node_temperatures,
+        }
+    }
+
+    /// Computes a center-of-glass U-factor (in `W/m^2K`) for this
+    /// discretization, i.e. `1/r_value` resolved via
+    /// [`Self::solve_steady_state`] across a [`REFERENCE_DELTA_T`]-wide
+    /// boundary difference centered on [`REFERENCE_T`]—the same reference
+    /// conditions [`Self::r_value`] uses for cavity-containing stacks. This
+    /// is the thermal counterpart to [`crate::glazing::Glazing::glazing_system_u`]
+    /// for callers that already have a built [`Discretization`] rather than
+    /// a [`Construction`]/[`SimpleModel`] pair.
+    pub fn center_of_glass_u_factor(&self) -> Float {
+        let t_front = REFERENCE_T - REFERENCE_DELTA_T / 2.;
+        let t_back = REFERENCE_T + REFERENCE_DELTA_T / 2.;
+        1. / self.solve_steady_state(t_front, t_back).r_value
+    }
+
+    /// The whole-assembly U-value (in `W/m^2K`), i.e. [`Self::r_value`] plus
+    /// caller-supplied interior/exterior surface (film) resistances `rsi`/
+    /// `rso` (in `m^2K/W`)—the design-time check an engineer runs against a
+    /// code-minimum U-value target without marching a full transient
+    /// simulation.
+    pub fn assembly_u_value(&self, rsi: Float, rso: Float) -> Float {
+        1. / (self.r_value() + rsi + rso)
+    }
+
+    /// Given a Maximum element thickness ($`\Delta x_{max}`$) and a minimum timestep ($`\Delta t_{min}`$), this function
+    /// will find an arguibly good (i.e., stable and accurate) combination of $`\Delta t`$ and number of elements in each
+    /// layer of the construction.
+    ///
+    /// This function recursively increases the model's timestep subdivisions (`n`) in order to reduce $`\Delta t`$ to numbers
+    /// that respect the restrictions of (1) stability, (2) $`\Delta x_{max}`$, and (3) $`\Delta t_{min}`$. In other words,
+    /// it searches (by testing $`\Delta t_{model}/1`$, $`\Delta t_{model}/2`$, $`\Delta t_{model}/3`$, ... $`\Delta t_{model}/n`$)
+    /// for the minimum `n` that respects this restrictions
+    ///
+    /// # The math behind it
+    ///
+    /// > *I am not sure how correct this is... seems to work, but there is room for improvements and optimizations, surely*
+    ///
+    /// The first thing to know is that the walls in this module march
+    /// through time using a 4th order [Runga-Kutte](https://en.wikipedia.org/wiki/Runge%E2%80%93Kutta_methods)
+    /// (a.k.a., RK4). The second thing to know is that the RK4 method is
+    /// more stable than the [Euler method](https://en.wikipedia.org/wiki/Euler_method),
+    /// and thus the restrictions of stability for the Euler method can be considered
+    /// to be a conservative restriction for the RK4. Hence, this function uses the
+    /// Euler method restrictions.
+    ///
+    /// We are solving the following equation:
+    ///
+    /// ```math
+    /// \dot{T} = \overline{C}^{-1} \overline{K}  T + \overline{C}^{-1} q
+    /// ```
+    ///
+    /// And thus the stability of the numerical method will depend on the matrix:
+    ///
+    /// ```math
+    /// \overline{K}^{\star} =\Delta t \overline{C}^{-1} \overline{K}
+    /// ```
+    ///
+    /// Specifically, we don't want any of its [eigenvalues](https://en.wikipedia.org/wiki/Eigenvalues_and_eigenvectors)
+    /// $`\xi_1, \xi_2,\xi_3, ...`$ to be outside of the Euler method's stability region. Since this
+    /// matrix has only Real eigenvalues, this is equivalent to saying:
+    ///
+    /// ```math
+    /// -2 < \xi_i < 0 ; \forall i
+    /// ```
+    ///
+    /// However, finding the eigenvalues for $`\overline{K}^{\star}`$ is far from trivial. So there is
+    /// yet another heuristic I am using: I am treating the case of a wall with 1 layer that is subdivided
+    /// into a single element as the limit case. I am not sure if this is correct, but most of the instabilities
+    /// I identified through Trial and Error corresponded to this case.
+    ///
+    /// For this limit case:
+    /// * $`R = \frac{\Delta x}{\lambda}`$
+    /// * $`C = \rho  c_p  \Delta x`$
+    ///
+    /// thus the value of $`\overline{K}^{\star}`$ is:
+    ///
+    /// ```math
     /// \overline{K}^{\star}=\begin{bmatrix}
     /// -\frac{\Delta t}{C\times R} - \frac{\Delta t}{C\times R_s} & \frac{\Delta t}{C\times R} \\
     ///  \frac{\Delta t}{C\times R} & -\frac{\Delta t}{C\times R} - \frac{\Delta t}{C\times R_s}\\
@@ -591,7 +1943,17 @@ impl Discretization {
     /// $`h_s`$ is the convection coefficient, $`E_{ir}`$ is the incident infrared radiation and $`\epsilon_s`$ is the
     /// emissivity of the surface. On the contrary, if the border condition is a cavity, then a value of $`T_{pane} U_{cavity}`$
     /// should be added. $`T_{pane}`$ is the temperature of the surface before or after.
-    ///         
+    ///
+    /// `front_rad_hs`/`back_rad_hs` are the boundary's linearized radiative
+    /// conductance (e.g. [`ConvectionParams::get_ir_radiation_coefficient`]'s
+    /// `h_r`), driving the surface toward `rad_temperature`. When
+    /// `implicit_radiation` is `true`, this conductance is folded into `K`'s
+    /// diagonal exactly like `front_hs`/`back_hs` already are—a Newton-style
+    /// tangent around the current `temperatures` iterate—so the boundary
+    /// radiative exchange becomes part of the implicit system instead of a
+    /// source term evaluated at a lagged surface temperature. Pass `false`
+    /// to keep the old fully-explicit treatment (`rad_hs * (rad_temperature
+    /// - T_surf)` added to `q` with no matching `K` term) for comparison.
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn get_k_q(
         &self,
@@ -604,6 +1966,7 @@ impl Discretization {
         back_env: &ConvectionParams,
         back_hs: Float,
         back_rad_hs: Float,
+        implicit_radiation: bool,
         memory: &mut ChunkMemory,
     ) -> Result<(), String> {
         let (nrows, ncols) = temperatures.size();
@@ -656,14 +2019,26 @@ impl Discretization {
 
         // Add front border conditions
         let (hs_front, front_q) = if ini == 0 {
-            let ts = temperatures.get(0, 0)?;
             // Solar radiation is added later because it also depends
             // on the solar absorption of different layers.
-
-            let front_q = front_env.air_temperature * front_hs  // convection
-                + front_rad_hs * (front_env.rad_temperature - ts);
-
-            (front_hs, front_q)
+            let (hs_front, front_q) = if implicit_radiation {
+                let front_q = front_env.air_temperature * front_hs // convection
+                    + front_rad_hs * front_env.rad_temperature; // radiation, tangent folded into K below
+                (front_hs + front_rad_hs, front_q)
+            } else {
+                let ts = temperatures.get(0, 0)?;
+                let front_q = front_env.air_temperature * front_hs  // convection
+                    + front_rad_hs * (front_env.rad_temperature - ts); // radiation, lagged at `ts`
+                (front_hs, front_q)
+            };
+            if let Some(bridge) = &self.front_thermal_bridge {
+                (
+                    hs_front + bridge.conductance,
+                    front_q + bridge.conductance * front_env.air_temperature,
+                )
+            } else {
+                (hs_front, front_q)
+            }
         } else {
             let (.., uvalue) = &self.segments[ini - 1];
             let t_before = temperatures.get(ini - 1, 0)?; // this should NEVER fail
@@ -678,13 +2053,26 @@ impl Discretization {
 
         // Add back border conditions
         let (hs_back, back_q) = if fin == nrows {
-            let ts = temperatures.get(fin - 1, 0).unwrap();
             // Solar radiation is added later because it also depends
             // on the solar absorption of different layers.
-            let back_q = back_env.air_temperature * back_hs  // convection
-                + back_rad_hs * (back_env.rad_temperature - ts);
-
-            (back_hs, back_q)
+            let (hs_back, back_q) = if implicit_radiation {
+                let back_q = back_env.air_temperature * back_hs // convection
+                    + back_rad_hs * back_env.rad_temperature; // radiation, tangent folded into K below
+                (back_hs + back_rad_hs, back_q)
+            } else {
+                let ts = temperatures.get(fin - 1, 0).unwrap();
+                let back_q = back_env.air_temperature * back_hs  // convection
+                    + back_rad_hs * (back_env.rad_temperature - ts); // radiation, lagged at `ts`
+                (back_hs, back_q)
+            };
+            if let Some(bridge) = &self.back_thermal_bridge {
+                (
+                    hs_back + bridge.conductance,
+                    back_q + bridge.conductance * back_env.air_temperature,
+                )
+            } else {
+                (hs_back, back_q)
+            }
         } else {
             let (.., uvalue) = &self.segments[fin - 1];
             let t_before = temperatures.get(fin - 1, 0)?; // this should NEVER fail
@@ -696,10 +2084,230 @@ impl Discretization {
         memory.q.add_to_element(nnodes - 1, 0, back_q)?;
         memory.k.add_to_element(nnodes - 1, nnodes - 1, -hs_back)?;
 
+        // Interior psi-/chi-value junctions, each coupling a single node to
+        // its own fixed reference temperature—unlike
+        // `front_thermal_bridge`/`back_thermal_bridge`, these aren't
+        // restricted to the two extreme boundary nodes.
+        for ntb in &self.node_thermal_bridges {
+            if ntb.node >= ini && ntb.node < fin {
+                let local = ntb.node - ini;
+                ntb.bridge
+                    .add_to_environment(&mut memory.k, &mut memory.q, local, ntb.env_temperature)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Builds a [`SolverStateSnapshot`] of `temperatures` (as held by
+    /// [`crate::surface::SurfaceMemory::temperatures`]) and the cached
+    /// propagators of `massive_chunks` (in the same order as the
+    /// `massive_chunks` returned by [`Self::get_chunks`]), ready to be
+    /// written to disk with [`Self::write_state`].
+    pub fn snapshot_state(
+        &self,
+        temperatures: &Matrix,
+        massive_chunks: &[ChunkMemory],
+    ) -> Result<SolverStateSnapshot, String> {
+        let (rows, ..) = temperatures.size();
+        let mut node_temperatures = Vec::with_capacity(rows);
+        for i in 0..rows {
+            node_temperatures.push(temperatures.get(i, 0)?);
+        }
+        let massive_chunk_propagators = massive_chunks
+            .iter()
+            .map(|chunk| chunk.propagator.clone())
+            .collect();
+
+        Ok(SolverStateSnapshot {
+            version: SOLVER_STATE_VERSION,
+            n_segments: self.segments.len(),
+            node_temperatures,
+            massive_chunk_propagators,
+        })
+    }
+
+    /// Writes the solver state—node temperatures and cached massive-chunk
+    /// propagators—to `writer`, so a long simulation can be resumed later
+    /// with [`Self::read_state`] instead of restarting from scratch.
+    pub fn write_state<W: std::io::Write>(
+        &self,
+        temperatures: &Matrix,
+        massive_chunks: &[ChunkMemory],
+        writer: W,
+    ) -> Result<(), String> {
+        let snapshot = self.snapshot_state(temperatures, massive_chunks)?;
+        serde_json::to_writer(writer, &snapshot).map_err(|e| e.to_string())
+    }
+
+    /// Reads back a [`SolverStateSnapshot`] written by [`Self::write_state`],
+    /// restoring `temperatures` and `massive_chunks` in place.
+    ///
+    /// Fails if the snapshot's version tag is unrecognized, or if its segment
+    /// count, node count, or massive-chunk count disagree with `self` and the
+    /// buffers passed in—e.g. because the snapshot was taken from a
+    /// differently-discretized construction.
+    pub fn read_state<R: std::io::Read>(
+        &self,
+        reader: R,
+        temperatures: &mut Matrix,
+        massive_chunks: &mut [ChunkMemory],
+    ) -> Result<(), String> {
+        let snapshot: SolverStateSnapshot =
+            serde_json::from_reader(reader).map_err(|e| e.to_string())?;
+
+        if snapshot.version != SOLVER_STATE_VERSION {
+            return Err(format!(
+                "Cannot load solver state: expected version {}, found {}",
+                SOLVER_STATE_VERSION, snapshot.version
+            ));
+        }
+        if snapshot.n_segments != self.segments.len() {
+            return Err(format!(
+                "Cannot load solver state: Discretization has {} segments, but the snapshot has {}",
+                self.segments.len(),
+                snapshot.n_segments
+            ));
+        }
+        let (rows, ..) = temperatures.size();
+        if snapshot.node_temperatures.len() != rows {
+            return Err(format!(
+                "Cannot load solver state: temperatures matrix has {} rows, but the snapshot has {}",
+                rows,
+                snapshot.node_temperatures.len()
+            ));
+        }
+        if snapshot.massive_chunk_propagators.len() != massive_chunks.len() {
+            return Err(format!(
+                "Cannot load solver state: there are {} massive chunks, but the snapshot has {}",
+                massive_chunks.len(),
+                snapshot.massive_chunk_propagators.len()
+            ));
+        }
+
+        for (i, v) in snapshot.node_temperatures.iter().enumerate() {
+            temperatures.set(i, 0, *v)?;
+        }
+        for (chunk, propagator) in massive_chunks
+            .iter_mut()
+            .zip(snapshot.massive_chunk_propagators)
+        {
+            chunk.propagator = propagator;
+        }
+
+        Ok(())
+    }
+}
+
+/// A single homogeneous-layer construction discretized with a
+/// [`crate::sbp::SbpOperator`] instead of [`Discretization`]'s standard
+/// central-difference stencil, for callers that explicitly want the
+/// higher-order accuracy-per-node [`crate::sbp`] offers (see that module's
+/// doc comment for the underlying math).
+///
+/// This is a standalone alternative, not a mode of [`Discretization`]
+/// itself: `Discretization`'s march solvers (the Thomas-factored theta
+/// method, `expm_march`, RK4) are all built around a tridiagonal `K`
+/// assembled once per layer in [`Discretization::build`], which an SBP
+/// operator's dense boundary closure doesn't fit, and `crate::sbp` doesn't
+/// yet cover the multi-layer interface coupling `Discretization` handles.
+/// Sizing mirrors [`Discretization::spatial_elements`]: `max_dx` bounds the
+/// uniform node spacing, rounding the element count up to the nearest
+/// integer.
+pub struct SbpDiscretization {
+    op: crate::sbp::SbpOperator,
+    /// Thermal diffusivity `k / (rho * cp)` of the single material layer (`m^2/s`).
+    alpha: Float,
+}
+
+impl SbpDiscretization {
+    /// Builds an `SbpDiscretization` for `construction`, which must consist
+    /// of exactly one solid (`Substance::Normal`) layer—the case
+    /// [`crate::sbp`] itself is scoped to. `order` is forwarded to
+    /// [`crate::sbp::SbpOperator::first_derivative`] (currently `2` or `4`).
+    pub fn new(
+        construction: &Arc<Construction>,
+        model: &SimpleModel,
+        max_dx: Float,
+        order: usize,
+    ) -> Result<Self, String> {
+        if construction.materials.len() != 1 {
+            return Err(format!(
+                "Cannot build an SbpDiscretization for '{}': it has {} layers, but the SBP-SAT path only supports a single homogeneous layer",
+                construction.name,
+                construction.materials.len()
+            ));
+        }
+        let mat_name = &construction.materials[0];
+        let material = model.get_material(mat_name)?;
+        let substance = model.get_substance(&material.substance)?;
+        let s = match &substance {
+            Substance::Normal(s) => s,
+            Substance::Gas(_) => {
+                return Err(format!(
+                    "Cannot build an SbpDiscretization for '{}': its single layer is a Gas, not a solid",
+                    construction.name
+                ))
+            }
+        };
+        let alpha = s.thermal_conductivity()? / (s.density()? * s.specific_heat_capacity()?);
+
+        let n_elements = (material.thickness / max_dx).ceil().max(1.0) as usize;
+        let n = n_elements + 1;
+        let dx = material.thickness / n_elements as Float;
+        let op = crate::sbp::SbpOperator::first_derivative(order, n, dx)?;
+
+        Ok(Self { op, alpha })
+    }
+
+    /// Advances `t` (node temperatures, `°C`, one entry per node) one
+    /// explicit-Euler step of `dt` seconds under Dirichlet boundary
+    /// conditions `t_front`/`t_back`, via
+    /// [`crate::sbp::sat_heat_equation_rhs`] (`tau == 2.0`, the module's own
+    /// conservative default). Unlike [`Discretization`]'s implicit/
+    /// exponential march options, this is plain forward Euler—callers
+    /// needing a larger stable `dt` should subdivide it themselves, the way
+    /// [`Discretization::tstep_subdivision`] does for the explicit RK4 path.
+    pub fn march(&self, t: &mut [Float], t_front: Float, t_back: Float, dt: Float) -> Result<(), String> {
+        if t.len() != self.op.n {
+            return Err(format!(
+                "Cannot march this SbpDiscretization: it has {} nodes, but {} temperatures were given",
+                self.op.n,
+                t.len()
+            ));
+        }
+        let rhs = crate::sbp::sat_heat_equation_rhs(&self.op, self.alpha, t, t_front, t_back, 2.0);
+        for (t_i, rhs_i) in t.iter_mut().zip(rhs) {
+            *t_i += dt * rhs_i;
+        }
         Ok(())
     }
 }
 
+/// On-disk schema version of [`SolverStateSnapshot`]. Bump this whenever its
+/// fields change, so [`Discretization::read_state`] can refuse a file written
+/// by an incompatible version instead of misinterpreting its contents.
+const SOLVER_STATE_VERSION: u32 = 1;
+
+/// A serializable snapshot of a surface's solver state: everything needed to
+/// resume marching a [`Discretization`] from exactly where it left off,
+/// instead of re-running a simulation from the start.
+///
+/// This deliberately excludes the per-step scratch matrices in
+/// [`ChunkMemory`] (`k`, `q`, `aux`, `k1..k4`, the tridiagonal bands)—those
+/// are rebuilt from `node_temperatures` on every call to
+/// [`Discretization::get_k_q`]—keeping only the two things that are
+/// expensive or impossible to re-derive: the node temperatures themselves,
+/// and each massive chunk's cached exponential propagator (see
+/// [`ChunkMemory::propagator`]).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SolverStateSnapshot {
+    version: u32,
+    n_segments: usize,
+    node_temperatures: Vec<Float>,
+    massive_chunk_propagators: Vec<Option<Vec<Vec<Float>>>>,
+}
+
 /***********/
 /* TESTING */
 /***********/
@@ -789,6 +2397,69 @@ mod testing {
         assert!(matches!(d.segments[1].1, UValue::Back));
     }
 
+    #[test]
+    fn new_with_scheme_skips_stability_subdivision_for_implicit_schemes() {
+        // A thick, diffusive concrete slab: the `RK4` default needs many
+        // sub-timesteps per hour to stay within its explicit stability
+        // region at this spacing, but an unconditionally-stable scheme
+        // needs none.
+        let thermal_cond = 1.7;
+        let density = 2400.;
+        let cp = 840.;
+        let thickness = 0.3;
+        let model_dt = 60. * 60.;
+        let max_dx = 0.04;
+        let min_dt = 60.;
+
+        let (model, construction) = get_normal(thermal_cond, density, cp, thickness);
+
+        let explicit =
+            Discretization::new(&construction, &model, model_dt, max_dx, min_dt, 1., 0.).unwrap();
+        assert!(
+            explicit.tstep_subdivision > 1,
+            "expected RK4 to need more than one sub-timestep for this slab, got {}",
+            explicit.tstep_subdivision
+        );
+
+        let implicit = Discretization::new_with_scheme(
+            &construction,
+            &model,
+            model_dt,
+            max_dx,
+            min_dt,
+            1.,
+            0.,
+            IntegrationScheme::crank_nicolson(),
+        )
+        .unwrap();
+        assert_eq!(implicit.tstep_subdivision, 1);
+        // Both paths respect the same `max_dx` spatial bound, so they land
+        // on comparable node counts even though `explicit`'s is driven by
+        // the stability search rather than `max_dx` directly.
+        assert!(implicit.segments.len() > 1);
+        assert!(matches!(implicit.scheme, IntegrationScheme::Theta { theta } if theta == 0.5));
+    }
+
+    #[test]
+    fn temperature_dependent_solid_u_value_tracks_mean_temperature() {
+        // k(T) = 1.0 + 0.01*T (T in Kelvin)
+        let conductivity = polynomial::poly![1.0, 0.01];
+        let dx = 0.1;
+        let u_value = UValue::TemperatureDependentSolid { dx, conductivity };
+
+        let u_cold = u_value.u_value(0., 0.); // mean T = 273.15 K
+        let u_hot = u_value.u_value(50., 50.); // mean T = 323.15 K
+        assert!(u_hot > u_cold, "Conductance should grow with temperature");
+
+        let expected_u_cold = (1.0 + 0.01 * 273.15) / dx;
+        assert!((u_cold - expected_u_cold).abs() < 1e-10);
+
+        // U is recomputed from the mean of the two boundary temperatures
+        let u_mean = u_value.u_value(0., 50.);
+        let expected_u_mean = (1.0 + 0.01 * ((0. + 50.) / 2. + 273.15)) / dx;
+        assert!((u_mean - expected_u_mean).abs() < 1e-10);
+    }
+
     #[test]
     fn test_build_normal_no_mass() {
         let thermal_cond = 1.;
@@ -1082,9 +2753,16 @@ mod testing {
         assert_eq!(segments.len(), n + 1);
 
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             segments,
             tstep_subdivision: 1,
             n_elements: vec![n],
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let front_env = ConvectionParams {
@@ -1142,6 +2820,7 @@ mod testing {
             &back_env,
             back_hs,
             back_rad_hs,
+            false, // implicit_radiation: preserve the old explicit formula these assertions check
             &mut memory,
         )
         .unwrap();
@@ -1227,6 +2906,7 @@ mod testing {
             &back_env,
             back_hs,
             back_rad_hs,
+            false, // implicit_radiation: preserve the old explicit formula these assertions check
             &mut memory,
         )
         .unwrap();
@@ -1311,6 +2991,7 @@ mod testing {
             &back_env,
             back_hs,
             back_rad_hs,
+            false, // implicit_radiation: preserve the old explicit formula these assertions check
             &mut memory,
         )
         .unwrap();
@@ -1375,9 +3056,16 @@ mod testing {
         assert_eq!(segments.len(), n + 1);
 
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             segments,
             tstep_subdivision: 1,
             n_elements: vec![n],
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let front_env = ConvectionParams {
@@ -1420,6 +3108,7 @@ mod testing {
             &back_env,
             back_hs,
             back_rad_hs,
+            false, // implicit_radiation: preserve the old explicit formula these assertions check
             &mut memory,
         )
         .unwrap();
@@ -1472,9 +3161,16 @@ mod testing {
     fn test_get_chunks() {
         // Single node, massive
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![(1., UValue::None)],
             n_elements: vec![1], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1484,9 +3180,16 @@ mod testing {
 
         // Single node, no-mass
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![(0., UValue::None)],
             n_elements: vec![1], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1496,9 +3199,16 @@ mod testing {
 
         // Several nodes, massive
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![(1., UValue::None); 10],
             n_elements: vec![1], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1508,9 +3218,16 @@ mod testing {
 
         // Several nodes, no-mass
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![(0., UValue::None); 10],
             n_elements: vec![1], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1520,6 +3237,9 @@ mod testing {
 
         // Mixed 1
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![
                 (0., UValue::None),
@@ -1529,6 +3249,10 @@ mod testing {
                 (0., UValue::None),
             ],
             n_elements: vec![0, 1, 1, 0, 0], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1539,6 +3263,9 @@ mod testing {
 
         // Mixed 2
         let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
             tstep_subdivision: 1,
             segments: vec![
                 (1., UValue::None),
@@ -1548,6 +3275,10 @@ mod testing {
                 (0., UValue::None),
             ],
             n_elements: vec![1, 1, 1, 0, 0], // Does not matter for this test
+        scheme: IntegrationScheme::default(),
+        front_thermal_bridge: None,
+        back_thermal_bridge: None,
+        node_thermal_bridges: Vec::new(),
         };
 
         let (mass_chunks, nomass_chunks) = d.get_chunks();
@@ -1556,4 +3287,882 @@ mod testing {
         assert_eq!(nomass_chunks.len(), 1);
         assert_eq!(nomass_chunks, vec![(3, 5)]);
     }
+
+    #[test]
+    fn test_add_point_source() {
+        // 4 equal-thickness solid segments (dx=0.1, k=1 => U=10), plus a Back node.
+        let u = 10.0;
+        let segments = vec![
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Back),
+        ];
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments,
+            n_elements: vec![4],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+        let k = vec![1.0; 5];
+
+        // Exactly on node 2 (depth = 0.2)
+        let mut source = vec![0.0; 5];
+        d.add_point_source(&mut source, &k, 0.2, 100.0);
+        assert!((source[2] - 100.0).abs() < 1e-6);
+
+        // Halfway between node 1 (0.1) and node 2 (0.2)
+        let mut source = vec![0.0; 5];
+        d.add_point_source(&mut source, &k, 0.15, 100.0);
+        assert!((source[1] - 50.0).abs() < 1e-6);
+        assert!((source[2] - 50.0).abs() < 1e-6);
+        assert!((Discretization::total_source_power(&source, 0, 5) - 100.0).abs() < 1e-6);
+
+        // Out of range, clamps to boundary nodes
+        let mut source = vec![0.0; 5];
+        d.add_point_source(&mut source, &k, -1.0, 100.0);
+        assert!((source[0] - 100.0).abs() < 1e-6);
+
+        let mut source = vec![0.0; 5];
+        d.add_point_source(&mut source, &k, 10.0, 100.0);
+        assert!((source[4] - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_apply_heat_sources() {
+        // Same 4-segment wall as `test_add_point_source`.
+        let u = 10.0;
+        let segments = vec![
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Back),
+        ];
+        let mut d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments,
+            n_elements: vec![4],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+        let k = vec![1.0; 5];
+        let temperatures = vec![20.0, 20.0, 20.0, 20.0, 20.0];
+
+        // A constant-power source, exactly on node 2 (depth = 0.2).
+        d.add_heat_source(HeatSource {
+            depth: 0.2,
+            mode: HeatSourceMode::Constant(100.0),
+        });
+        // A UA-coupled source, halfway between node 1 and node 2, driven by
+        // a 30 C supply against the 20 C nodes above: power = 5*(30-20) = 50.
+        d.add_heat_source(HeatSource {
+            depth: 0.15,
+            mode: HeatSourceMode::Coupled {
+                ua: 5.0,
+                supply_temperature: 30.0,
+            },
+        });
+
+        let mut source = vec![0.0; 5];
+        d.apply_heat_sources(&mut source, &temperatures, &k);
+
+        // Node 2 gets the full constant source (100) plus half the coupled
+        // source (25); node 1 gets the other half of the coupled source.
+        assert!((source[1] - 25.0).abs() < 1e-6);
+        assert!((source[2] - 125.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_remap_temperatures_conserves_enthalpy() {
+        let old_masses = vec![1.0, 2.0, 1.0];
+        let old_temps = vec![10.0, 20.0, 30.0];
+        let old_enthalpy: Float = old_masses
+            .iter()
+            .zip(old_temps.iter())
+            .map(|(c, t)| c * t)
+            .sum();
+
+        let new_masses = vec![1.0, 1.0, 1.0, 1.0, 1.0];
+        let new_temps = Discretization::remap_temperatures(&old_masses, &old_temps, &new_masses);
+        assert_eq!(new_temps.len(), 5);
+
+        let new_enthalpy: Float = new_masses
+            .iter()
+            .zip(new_temps.iter())
+            .map(|(c, t)| c * t)
+            .sum();
+        assert!(
+            (old_enthalpy - new_enthalpy).abs() < 1e-6,
+            "Expected enthalpy to be conserved: old={old_enthalpy}, new={new_enthalpy}"
+        );
+    }
+
+    #[test]
+    fn test_refinement_indicator() {
+        let temps = vec![10.0, 10.0, 10.0, 50.0, 10.0];
+        let indicator = Discretization::refinement_indicator(&temps);
+        assert_eq!(indicator.len(), 5);
+        assert!(indicator[1] < 1e-6);
+        // Node 3 sees a sharp jump on both sides
+        assert!(indicator[3] > 10.0);
+    }
+
+    #[test]
+    fn test_thermal_bridge() {
+        let bridge = ThermalBridge::linear(0.5, 4.0);
+        assert_eq!(bridge.conductance, 2.0);
+
+        let mut k = Matrix::new(0.0, 2, 2);
+        bridge.add_between_nodes(&mut k, 0, 1).unwrap();
+        assert_eq!(k.get(0, 0).unwrap(), -2.0);
+        assert_eq!(k.get(1, 1).unwrap(), -2.0);
+        assert_eq!(k.get(0, 1).unwrap(), 2.0);
+        assert_eq!(k.get(1, 0).unwrap(), 2.0);
+
+        let point_bridge = ThermalBridge::point(1.2);
+        let mut k = Matrix::new(0.0, 1, 1);
+        let mut q = Matrix::new(0.0, 1, 1);
+        point_bridge.add_to_environment(&mut k, &mut q, 0, 20.0).unwrap();
+        assert_eq!(k.get(0, 0).unwrap(), -1.2);
+        assert_eq!(q.get(0, 0).unwrap(), 1.2 * 20.0);
+    }
+
+    #[test]
+    fn test_modal_reduction() {
+        // A 3-node chain with unit conductances and unit masses.
+        let k = Matrix::from_data(
+            3,
+            3,
+            vec![-1., 1., 0., 1., -2., 1., 0., 1., -1.],
+        );
+        let c = Matrix::diag(vec![1., 1., 1.]);
+
+        let (eigenvalues, modes) = Discretization::modal_reduction(&k, &c, 2);
+        assert_eq!(eigenvalues.len(), 2);
+        assert_eq!(modes.len(), 2);
+        assert_eq!(modes[0].len(), 3);
+
+        // All of K's eigenvalues are <= 0 (it is negative semi-definite), and
+        // the kept ones should be the two closest to zero.
+        for &lambda in &eigenvalues {
+            assert!(lambda <= 1e-8, "eigenvalue {lambda} should be <= 0");
+        }
+        assert!(eigenvalues[0] >= eigenvalues[1]);
+    }
+
+    #[test]
+    fn test_chunk_state_space_matches_fresh_solve_every_step() {
+        let n = 5;
+        let mass = 1.5;
+        let u_val = 0.8;
+        let dt = 300.0;
+        let reference_temperature = 20.0;
+
+        let mut segments = Vec::with_capacity(n);
+        for _ in 0..n {
+            segments.push((mass, UValue::Solid(u_val)));
+        }
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            segments,
+            tstep_subdivision: 1,
+            n_elements: vec![n],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        let cache = d
+            .build_chunk_state_space(0, n, dt, reference_temperature)
+            .unwrap();
+
+        let c = Matrix::diag(vec![mass; n]);
+        let mut k = Matrix::new(0.0, n, n);
+        for i in 0..n - 1 {
+            k.add_to_element(i, i, -u_val).unwrap();
+            k.add_to_element(i + 1, i + 1, -u_val).unwrap();
+            k.add_to_element(i, i + 1, u_val).unwrap();
+            k.add_to_element(i + 1, i, u_val).unwrap();
+        }
+
+        // A fixed, asymmetric heat input so the two node-0/node-4 boundary
+        // fluxes don't coincidentally cancel out.
+        let mut u = Matrix::new(0.0, n, 1);
+        u.set(0, 0, 12.0).unwrap();
+        u.set(n - 1, 0, -3.0).unwrap();
+
+        let mut x = Matrix::new(0.0, n, 1);
+        for i in 0..n {
+            x.set(i, 0, 18.0).unwrap();
+        }
+
+        for _ in 0..200 {
+            let reduced_next = cache.march(&x, &u).unwrap();
+
+            // Fresh assemble-and-solve of (C - dt*K)*x_next = C*x + dt*u
+            let mut m = Matrix::new(0.0, n, n);
+            for i in 0..n {
+                for j in 0..n {
+                    m.set(i, j, c.get(i, j).unwrap() - dt * k.get(i, j).unwrap())
+                        .unwrap();
+                }
+            }
+            let mut rhs = Matrix::new(0.0, n, 1);
+            for i in 0..n {
+                let mut v = 0.0;
+                for j in 0..n {
+                    v += c.get(i, j).unwrap() * x.get(j, 0).unwrap();
+                }
+                v += dt * u.get(i, 0).unwrap();
+                rhs.set(i, 0, v).unwrap();
+            }
+            let fresh_next = m.mut_n_diag_gaussian(rhs, 3).unwrap();
+
+            for i in 0..n {
+                let a = reduced_next.get(i, 0).unwrap();
+                let b = fresh_next.get(i, 0).unwrap();
+                assert!(
+                    (a - b).abs() < 1e-6,
+                    "node {i}: reduced = {a}, fresh = {b}"
+                );
+            }
+
+            x = reduced_next;
+        }
+    }
+
+    #[test]
+    fn test_chunk_state_space_rejects_nomass_chunk() {
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            segments: vec![(1.0, UValue::Solid(0.5)), (0.0, UValue::Back)],
+            tstep_subdivision: 1,
+            n_elements: vec![1],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        assert!(d.build_chunk_state_space(0, 2, 300.0, 20.0).is_err());
+    }
+
+    fn dummy_chunk_memory(n: usize) -> ChunkMemory {
+        ChunkMemory {
+            aux: Matrix::new(0.0, n, 1),
+            k: Matrix::new(0.0, n, n),
+            q: Matrix::new(0.0, n, 1),
+            k1: Matrix::new(0.0, n, 1),
+            k2: Matrix::new(0.0, n, 1),
+            k3: Matrix::new(0.0, n, 1),
+            k4: Matrix::new(0.0, n, 1),
+            k5: Matrix::new(0.0, n, 1),
+            k6: Matrix::new(0.0, n, 1),
+            t4: Matrix::new(0.0, n, 1),
+            propagator: None,
+            sub_diag: vec![0.0; n],
+            main_diag: vec![0.0; n],
+            super_diag: vec![0.0; n],
+            rhs: vec![0.0; n],
+            theta_factorization: None,
+            expm_factorization: None,
+        }
+    }
+
+    #[test]
+    fn test_state_snapshot_roundtrip() {
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments: vec![(1., UValue::None); 3],
+            n_elements: vec![1],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        let temperatures = Matrix::from_data(3, 1, vec![18.0, 19.5, 21.0]);
+        let mut massive_chunks = vec![dummy_chunk_memory(3)];
+        massive_chunks[0].propagator = Some(vec![vec![1.0, 0.0], vec![0.0, 1.0]]);
+
+        let mut buf = Vec::new();
+        d.write_state(&temperatures, &massive_chunks, &mut buf)
+            .unwrap();
+
+        let mut restored_temperatures = Matrix::new(0.0, 3, 1);
+        let mut restored_chunks = vec![dummy_chunk_memory(3)];
+        d.read_state(buf.as_slice(), &mut restored_temperatures, &mut restored_chunks)
+            .unwrap();
+
+        for i in 0..3 {
+            assert_eq!(
+                restored_temperatures.get(i, 0).unwrap(),
+                temperatures.get(i, 0).unwrap()
+            );
+        }
+        assert_eq!(restored_chunks[0].propagator, massive_chunks[0].propagator);
+    }
+
+    #[test]
+    fn test_state_snapshot_rejects_mismatched_segments() {
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments: vec![(1., UValue::None); 3],
+            n_elements: vec![1],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+        let other = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments: vec![(1., UValue::None); 5],
+            n_elements: vec![1],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        let temperatures = Matrix::from_data(3, 1, vec![18.0, 19.5, 21.0]);
+        let mut massive_chunks = vec![dummy_chunk_memory(3)];
+
+        let mut buf = Vec::new();
+        d.write_state(&temperatures, &massive_chunks, &mut buf)
+            .unwrap();
+
+        let mut restored_temperatures = Matrix::new(0.0, 3, 1);
+        let err = other
+            .read_state(buf.as_slice(), &mut restored_temperatures, &mut massive_chunks)
+            .unwrap_err();
+        assert!(err.contains("segments"));
+    }
+
+    #[test]
+    fn phase_change_apparent_capacitance_spikes_inside_melting_band() {
+        let pcm = PhaseChangeProperties {
+            baseline_capacitance: 100.,
+            latent_heat: 2000.,
+            solidus: 20.,
+            liquidus: 24.,
+        };
+        assert_eq!(pcm.apparent_capacitance(10.), 100.);
+        assert_eq!(pcm.apparent_capacitance(30.), 100.);
+        assert_eq!(pcm.apparent_capacitance(22.), 100. + 2000. / 4.);
+    }
+
+    #[test]
+    fn phase_change_enthalpy_temperature_round_trip() {
+        let pcm = PhaseChangeProperties {
+            baseline_capacitance: 100.,
+            latent_heat: 2000.,
+            solidus: 20.,
+            liquidus: 24.,
+        };
+        for t in [-10., 0., 19.999, 20., 21., 22., 23., 24., 24.001, 40., 80.] {
+            let h = pcm.enthalpy(t);
+            let recovered = pcm.temperature(h);
+            assert!(
+                (recovered - t).abs() < 1e-6,
+                "expected {t}, got {recovered} (h = {h})"
+            );
+        }
+    }
+
+    #[test]
+    fn phase_change_enthalpy_is_monotonically_increasing() {
+        let pcm = PhaseChangeProperties {
+            baseline_capacitance: 50.,
+            latent_heat: 5000.,
+            solidus: 0.,
+            liquidus: 2.,
+        };
+        let mut prev = pcm.enthalpy(-20.);
+        let mut t = -19.5;
+        while t <= 20. {
+            let h = pcm.enthalpy(t);
+            assert!(h > prev, "enthalpy must strictly increase with T");
+            prev = h;
+            t += 0.5;
+        }
+    }
+
+    #[test]
+    fn node_mass_prefers_phase_change_override_over_specific_heat_override() {
+        let thermal_cond = 1.;
+        let density = 2.1;
+        let cp = 1.312;
+        let thickness = 12.5 / 1000.;
+        let (model, construction) = get_normal(thermal_cond, density, cp, thickness);
+        let mut d = Discretization::build(&construction, &model, 1, vec![1], 1., 0.).unwrap();
+
+        d.set_specific_heat_override(0, Polynomial::new(vec![999.])).unwrap();
+        let pcm = PhaseChangeProperties {
+            baseline_capacitance: 10.,
+            latent_heat: 400.,
+            solidus: 20.,
+            liquidus: 22.,
+        };
+        d.set_phase_change_override(0, pcm).unwrap();
+
+        assert_eq!(d.node_mass(0, 21.), pcm.apparent_capacitance(21.));
+        assert_ne!(d.node_mass(0, 21.), 999.);
+    }
+
+    #[test]
+    fn solve_steady_state_matches_series_sum_for_solids() {
+        // Two equal solid resistances in series: U=10 each => R=0.1 each => 0.2 total.
+        let u = 10.0;
+        let segments = vec![
+            (1., UValue::Solid(u)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Back),
+        ];
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments,
+            n_elements: vec![2],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        let solution = d.solve_steady_state(0., 20.);
+        assert!((solution.r_value - 0.2).abs() < 1e-6);
+        // The middle node should sit exactly halfway, since both resistances match.
+        assert!((solution.node_temperatures[1] - 10.).abs() < 1e-6);
+        assert_eq!(d.r_value(), 1. / u + 1. / u);
+
+        assert!((solution.peak_temperature() - 20.).abs() < 1e-6);
+        assert!((solution.mean_temperature() - 10.).abs() < 1e-6);
+        // With no surface film resistances, the assembly U-value is just 1/R.
+        assert!((d.assembly_u_value(0., 0.) - 1. / 0.2).abs() < 1e-6);
+        assert!((d.assembly_u_value(0.1, 0.04) - 1. / (0.2 + 0.14)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn contact_resistance_adds_to_series_r_value_and_steady_state() {
+        // Same two solids as above, plus a fixed 0.05 m2K/W contact
+        // resistance inserted at their interface.
+        let u = 10.0;
+        let segments = vec![
+            (1., UValue::Solid(u)),
+            (0., UValue::ContactResistance(0.05)),
+            (1., UValue::Solid(u)),
+            (1., UValue::Back),
+        ];
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments,
+            n_elements: vec![3],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        // 0.1 + 0.05 + 0.1 = 0.25
+        assert!((d.r_value() - 0.25).abs() < 1e-6);
+
+        let solution = d.solve_steady_state(0., 25.);
+        assert!((solution.r_value - 0.25).abs() < 1e-6);
+        // Crossing the contact resistance alone should account for a fifth
+        // of the total temperature drop (0.05 / 0.25).
+        assert!((solution.node_temperatures[2] - solution.node_temperatures[1] - 5.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn r_value_resolves_cavity_instead_of_panicking() {
+        let thermal_cond = 1.;
+        let density = 2.1;
+        let cp = 1.312;
+        let thickness = 12.5 / 1000.;
+        let mut model = SimpleModel::default();
+
+        let mut substance = simple_model::substance::Normal::new("the substance");
+        substance
+            .set_thermal_conductivity(thermal_cond)
+            .set_density(density)
+            .set_front_thermal_absorbtance(0.9)
+            .set_back_thermal_absorbtance(0.8)
+            .set_specific_heat_capacity(cp);
+        let substance = substance.wrap();
+        let substance = model.add_substance(substance);
+
+        let normal =
+            simple_model::Material::new("the mat".to_string(), substance.name().clone(), thickness);
+        let normal = model.add_material(normal);
+
+        let mut gas = simple_model::substance::Gas::new("the gas");
+        gas.set_gas(simple_model::substance::gas::GasSpecification::Air);
+        let gas = gas.wrap();
+        let gas = model.add_substance(gas);
+
+        let gas =
+            simple_model::Material::new("the_gas".to_string(), gas.name().clone(), thickness);
+        let gas = model.add_material(gas);
+
+        let mut construction = simple_model::Construction::new("the construction");
+        construction.materials.push(normal.name().clone());
+        construction.materials.push(gas.name().clone());
+        construction.materials.push(normal.name().clone());
+        let construction = model.add_construction(construction);
+
+        let d =
+            Discretization::build(&construction, &model, 1, vec![1, 1, 1], 1., 0.).unwrap();
+
+        // Used to be `todo!()`; must now resolve to a finite, positive R-value.
+        let r = d.r_value();
+        assert!(r.is_finite() && r > 0.);
+
+        // The center-of-glass U-factor is just its reciprocal.
+        let u = d.center_of_glass_u_factor();
+        assert!((u - 1. / r).abs() < 1e-9);
+    }
+
+    #[test]
+    fn semi_transparent_conductance_grows_with_temperature() {
+        let dx = 0.05;
+        let k = 0.02; // e.g. aerogel
+        let beta = 50.0;
+        let n = 1.0;
+        let u_value = UValue::SemiTransparent {
+            dx,
+            conductivity: k,
+            beta,
+            refractive_index: n,
+        };
+
+        let u_cold = u_value.u_value(0., 0.);
+        let u_hot = u_value.u_value(200., 200.);
+        assert!(
+            u_hot > u_cold,
+            "radiative term should grow with T^3: u_cold={u_cold}, u_hot={u_hot}"
+        );
+
+        // Matches the closed-form k_eff = k + 16*SIGMA*n^2*T_m^3/(3*beta).
+        let t_m = 273.15;
+        let expected = (k + 16. * crate::SIGMA * n.powi(2) * t_m.powi(3) / (3. * beta)) / dx;
+        assert!((u_cold - expected).abs() < 1e-9);
+    }
+
+    #[test]
+    fn semi_transparent_segment_is_flagged_temperature_dependent() {
+        let segments = vec![
+            (
+                1.,
+                UValue::SemiTransparent {
+                    dx: 0.05,
+                    conductivity: 0.02,
+                    beta: 50.0,
+                    refractive_index: 1.0,
+                },
+            ),
+            (1., UValue::Back),
+        ];
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            tstep_subdivision: 1,
+            segments,
+            n_elements: vec![1],
+            scheme: IntegrationScheme::default(),
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+        assert!(d.has_temperature_dependent_properties());
+    }
+
+    #[test]
+    fn get_k_q_implicit_radiation_folds_rad_hs_into_diagonal() {
+        let n = 5;
+        let thickness = 0.5;
+        let thermal_cond = 2.12;
+        let dx = thickness / n as Float;
+        let u = thermal_cond / dx;
+        let (d, temperatures, front_env, front_hs, back_env, back_hs) =
+            get_solid_test_system(thickness, thermal_cond);
+
+        let front_rad_hs = 1.0;
+        let back_rad_hs = 1.0;
+        let mut memory = dummy_chunk_memory(n + 1);
+        d.get_k_q(
+            0,
+            n + 1,
+            &temperatures,
+            &front_env,
+            front_hs,
+            front_rad_hs,
+            &back_env,
+            back_hs,
+            back_rad_hs,
+            true, // implicit_radiation
+            &mut memory,
+        )
+        .unwrap();
+
+        // Unlike the explicit path, front/back `rad_hs` now sits on the diagonal too.
+        let front_diag = memory.k.get(0, 0).unwrap();
+        assert!((front_diag - (-front_hs - front_rad_hs - u)).abs() < 1e-10);
+        let back_diag = memory.k.get(n, n).unwrap();
+        assert!((back_diag - (-back_hs - back_rad_hs - u)).abs() < 1e-10);
+
+        // `q` no longer carries a `-rad_hs * T_surf` lagged term: it's
+        // exactly the T-independent environment contribution.
+        let ts_front = temperatures.get(0, 0).unwrap();
+        let front_q = memory.q.get(0, 0).unwrap();
+        let expected_front_q =
+            front_env.air_temperature * front_hs + front_rad_hs * front_env.rad_temperature;
+        assert!((front_q - expected_front_q).abs() < 1e-10);
+        assert!(ts_front != 0.); // sanity: a lagged term would have shown up otherwise
+    }
+
+    #[test]
+    fn get_k_q_adds_thermal_bridge_conductance_to_border_diagonal() {
+        let n = 5;
+        let thickness = 0.5;
+        let thermal_cond = 2.12;
+        let (mut d, temperatures, front_env, front_hs, back_env, back_hs) =
+            get_solid_test_system(thickness, thermal_cond);
+
+        let front_bridge = ThermalBridge::linear(0.5, 4.0); // conductance = 2.0
+        let back_bridge = ThermalBridge::point(1.5);
+        d.set_front_thermal_bridge(Some(front_bridge));
+        d.set_back_thermal_bridge(Some(back_bridge));
+
+        let front_rad_hs = 0.0;
+        let back_rad_hs = 0.0;
+        let mut memory = dummy_chunk_memory(n + 1);
+        let mut baseline_memory = dummy_chunk_memory(n + 1);
+        let mut without_bridges = d.clone();
+        without_bridges.set_front_thermal_bridge(None);
+        without_bridges.set_back_thermal_bridge(None);
+
+        d.get_k_q(
+            0,
+            n + 1,
+            &temperatures,
+            &front_env,
+            front_hs,
+            front_rad_hs,
+            &back_env,
+            back_hs,
+            back_rad_hs,
+            true, // implicit_radiation
+            &mut memory,
+        )
+        .unwrap();
+        without_bridges
+            .get_k_q(
+                0,
+                n + 1,
+                &temperatures,
+                &front_env,
+                front_hs,
+                front_rad_hs,
+                &back_env,
+                back_hs,
+                back_rad_hs,
+                true, // implicit_radiation
+                &mut baseline_memory,
+            )
+            .unwrap();
+
+        let front_diag = memory.k.get(0, 0).unwrap();
+        let baseline_front_diag = baseline_memory.k.get(0, 0).unwrap();
+        assert!((front_diag - (baseline_front_diag - front_bridge.conductance)).abs() < 1e-10);
+
+        let back_diag = memory.k.get(n, n).unwrap();
+        let baseline_back_diag = baseline_memory.k.get(n, n).unwrap();
+        assert!((back_diag - (baseline_back_diag - back_bridge.conductance)).abs() < 1e-10);
+
+        let front_q = memory.q.get(0, 0).unwrap();
+        let baseline_front_q = baseline_memory.q.get(0, 0).unwrap();
+        let expected_front_q = baseline_front_q + front_bridge.conductance * front_env.air_temperature;
+        assert!((front_q - expected_front_q).abs() < 1e-8);
+
+        let back_q = memory.q.get(n, 0).unwrap();
+        let baseline_back_q = baseline_memory.q.get(n, 0).unwrap();
+        let expected_back_q = baseline_back_q + back_bridge.conductance * back_env.air_temperature;
+        assert!((back_q - expected_back_q).abs() < 1e-8);
+    }
+
+    #[test]
+    fn get_k_q_adds_node_thermal_bridge_to_interior_node() {
+        let n = 5;
+        let thickness = 0.5;
+        let thermal_cond = 2.12;
+        let (mut d, temperatures, front_env, front_hs, back_env, back_hs) =
+            get_solid_test_system(thickness, thermal_cond);
+
+        let junction_node = 2;
+        let junction_env_temperature = -3.0;
+        let bridge = ThermalBridge::linear(0.6, 2.5); // conductance = 1.5
+        d.add_node_thermal_bridge(junction_node, junction_env_temperature, bridge);
+
+        let mut memory = dummy_chunk_memory(n + 1);
+        let mut baseline_memory = dummy_chunk_memory(n + 1);
+        let mut without_bridge = d.clone();
+        without_bridge.node_thermal_bridges.clear();
+
+        d.get_k_q(
+            0,
+            n + 1,
+            &temperatures,
+            &front_env,
+            front_hs,
+            0.0,
+            &back_env,
+            back_hs,
+            0.0,
+            true, // implicit_radiation
+            &mut memory,
+        )
+        .unwrap();
+        without_bridge
+            .get_k_q(
+                0,
+                n + 1,
+                &temperatures,
+                &front_env,
+                front_hs,
+                0.0,
+                &back_env,
+                back_hs,
+                0.0,
+                true, // implicit_radiation
+                &mut baseline_memory,
+            )
+            .unwrap();
+
+        let diag = memory.k.get(junction_node, junction_node).unwrap();
+        let baseline_diag = baseline_memory
+            .k
+            .get(junction_node, junction_node)
+            .unwrap();
+        assert!((diag - (baseline_diag - bridge.conductance)).abs() < 1e-10);
+
+        let q = memory.q.get(junction_node, 0).unwrap();
+        let baseline_q = baseline_memory.q.get(junction_node, 0).unwrap();
+        let expected_q = baseline_q + bridge.conductance * junction_env_temperature;
+        assert!((q - expected_q).abs() < 1e-10);
+
+        // Nodes away from the junction are untouched.
+        let other_diag = memory.k.get(0, 0).unwrap();
+        let baseline_other_diag = baseline_memory.k.get(0, 0).unwrap();
+        assert!((other_diag - baseline_other_diag).abs() < 1e-10);
+    }
+
+    #[test]
+    fn sbp_discretization_rejects_multiple_layers() {
+        let mut model = SimpleModel::default();
+        let mut s = simple_model::substance::Normal::new("the substance");
+        s.set_thermal_conductivity(1.0)
+            .set_density(2.1)
+            .set_specific_heat_capacity(1312.0);
+        let s = model.add_substance(s.wrap());
+
+        let mat_a = simple_model::Material::new("mat a".to_string(), s.name().clone(), 0.05);
+        let mat_a = model.add_material(mat_a);
+        let mat_b = simple_model::Material::new("mat b".to_string(), s.name().clone(), 0.05);
+        let mat_b = model.add_material(mat_b);
+
+        let mut construction = Construction::new("two layers");
+        construction.materials.push(mat_a.name().clone());
+        construction.materials.push(mat_b.name().clone());
+        let construction = model.add_construction(construction);
+
+        assert!(SbpDiscretization::new(&construction, &model, 0.01, 4).is_err());
+    }
+
+    #[test]
+    fn sbp_discretization_rejects_a_gas_layer() {
+        let mut model = SimpleModel::default();
+        let mut gas = simple_model::substance::Gas::new("the gas");
+        gas.set_gas(simple_model::substance::gas::GasSpecification::Air);
+        let gas = model.add_substance(gas.wrap());
+        let gas_mat = simple_model::Material::new("the gas mat".to_string(), gas.name().clone(), 0.02);
+        let gas_mat = model.add_material(gas_mat);
+
+        let mut construction = Construction::new("a gas layer");
+        construction.materials.push(gas_mat.name().clone());
+        let construction = model.add_construction(construction);
+
+        assert!(SbpDiscretization::new(&construction, &model, 0.01, 4).is_err());
+    }
+
+    #[test]
+    fn sbp_discretization_holds_a_steady_linear_profile() {
+        let thermal_cond = 1.0;
+        let density = 2.1;
+        let cp = 1312.0;
+        let thickness = 0.1;
+
+        let (model, construction) = get_normal(thermal_cond, density, cp, thickness);
+        let sbp = SbpDiscretization::new(&construction, &model, thickness / 12., 4).unwrap();
+
+        let t_front = 10.0;
+        let t_back = 30.0;
+        let n = 13; // thickness / max_dx == 12 elements -> 13 nodes
+        let mut t: Vec<Float> = (0..n)
+            .map(|i| t_front + (t_back - t_front) * i as Float / (n - 1) as Float)
+            .collect();
+
+        // A small dt, well inside forward Euler's stability bound for this
+        // mesh/diffusivity—the point of this test is that a converged
+        // linear profile is a fixed point of the march, not that the march
+        // is unconditionally stable (it isn't; it's plain explicit Euler).
+        for _ in 0..50 {
+            sbp.march(&mut t, t_front, t_back, 0.01).unwrap();
+        }
+
+        for (i, v) in t.iter().enumerate() {
+            let expected = t_front + (t_back - t_front) * i as Float / (n - 1) as Float;
+            assert!((v - expected).abs() < 1e-6, "node {i}: {v} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn sbp_discretization_rejects_mismatched_temperature_vector() {
+        let (model, construction) = get_normal(1.0, 2.1, 1312.0, 0.1);
+        let sbp = SbpDiscretization::new(&construction, &model, 0.1 / 12., 4).unwrap();
+        let mut t = vec![20.0; 3];
+        assert!(sbp.march(&mut t, 10.0, 30.0, 1.0).is_err());
+    }
 }
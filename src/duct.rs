@@ -0,0 +1,399 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::Float;
+
+/// What surrounds a [`Duct`] along its run, which sets the temperature it
+/// exchanges heat with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuctEnvironment {
+    /// The duct runs through unconditioned/outdoor space (e.g. an attic,
+    /// or outdoors entirely), so it exchanges heat with the outdoor
+    /// dry-bulb temperature.
+    Ambient,
+    /// The duct runs through another conditioned Zone (indexed into
+    /// [`crate::model::ThermalModel::zones`]), so it exchanges heat with
+    /// that Zone's air temperature instead of outdoor conditions.
+    Zone(usize),
+}
+
+/// A length of ductwork carrying mechanically supplied/ventilated air
+/// from its source (outdoors, or a heat-recovery unit) to a Zone,
+/// modelled as a single lumped resistance between the air inside the
+/// duct and its surrounding environment.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Duct {
+    /// Duct length (m)
+    pub length: Float,
+    /// Duct internal diameter (m)
+    pub diameter: Float,
+    /// Combined internal, insulation and external surface resistances
+    /// (m2K/W), i.e. the reciprocal of the overall U-value, referred to
+    /// the duct's internal surface area.
+    pub resistance: Float,
+    /// What surrounds the duct along its run.
+    pub environment: DuctEnvironment,
+}
+
+impl Duct {
+    /// The duct's internal surface area (m2), i.e. `A_duct` in
+    /// [`Self::corrected_supply_temperature`].
+    pub fn area(&self) -> Float {
+        crate::PI * self.diameter * self.length
+    }
+
+    /// Corrects `t_supply` (the air temperature entering the duct) for
+    /// the heat gained or lost to `t_environment` along its run, given
+    /// the air's mass flow `mass_flow` (kg/s) and specific heat `cp`
+    /// (J/kg.K):
+    ///
+    /// ```math
+    /// T_{supply}' = T_{env} + (T_{supply} - T_{env})e^{-UA_{duct}/(\dot{m}Cp)}
+    /// ```
+    ///
+    /// A zero (or negative) `mass_flow` leaves `t_supply` unchanged,
+    /// since there is no air moving through the duct to exchange heat.
+    pub fn corrected_supply_temperature(
+        &self,
+        t_supply: Float,
+        t_environment: Float,
+        mass_flow: Float,
+        cp: Float,
+    ) -> Float {
+        if mass_flow <= 0.0 {
+            return t_supply;
+        }
+        let ua = self.area() / self.resistance;
+        let exponent = -ua / (mass_flow * cp);
+        t_environment + (t_supply - t_environment) * exponent.exp()
+    }
+
+    /// Builds a [`Duct`], deriving [`Self::resistance`] from steady-state
+    /// radial conduction through its wall and insulation `layers` (listed
+    /// from the air-side inward layer outward) in series with an internal
+    /// film coefficient `internal_h` (W/m2.K) and the external film
+    /// coefficient set by `surface_finish`, instead of a caller-supplied
+    /// lumped value.
+    ///
+    /// For [`DuctCrossSection::Circular`], each layer's resistance is the
+    /// true cylindrical radial-conduction resistance referred to the
+    /// duct's internal surface area, `r_inner * ln(r_outer / r_inner) /
+    /// conductivity`; the external film coefficient, which acts over the
+    /// larger outer surface, is referred back to the internal area via the
+    /// inner/outer radius ratio. [`DuctCrossSection::Rectangular`] ducts
+    /// use the flat-wall approximation `thickness / conductivity` instead,
+    /// since there is no single radius to evaluate the cylindrical formula
+    /// against.
+    pub fn from_construction(
+        length: Float,
+        cross_section: DuctCrossSection,
+        layers: &[DuctLayer],
+        internal_h: Float,
+        surface_finish: DuctSurfaceFinish,
+        environment: DuctEnvironment,
+    ) -> Self {
+        let external_h = surface_finish.external_h();
+        let mut resistance = 1.0 / internal_h;
+
+        match cross_section.radius() {
+            Some(r_inner) => {
+                let mut r = r_inner;
+                for layer in layers {
+                    let r_outer = r + layer.thickness;
+                    resistance += r_inner * (r_outer / r).ln() / layer.conductivity;
+                    r = r_outer;
+                }
+                resistance += r_inner / (r * external_h);
+            }
+            None => {
+                for layer in layers {
+                    resistance += layer.thickness / layer.conductivity;
+                }
+                resistance += 1.0 / external_h;
+            }
+        }
+
+        Duct {
+            length,
+            diameter: cross_section.equivalent_diameter(),
+            resistance,
+            environment,
+        }
+    }
+}
+
+/// One layer of a duct's wall (or insulation jacket), listed from the
+/// air-side inward layer outward. Used by [`Duct::from_construction`] to
+/// derive [`Duct::resistance`] from actual wall/insulation geometry
+/// instead of a caller-supplied lumped value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DuctLayer {
+    /// Layer thickness (m).
+    pub thickness: Float,
+    /// Layer thermal conductivity (W/m.K).
+    pub conductivity: Float,
+}
+
+/// A duct's external surface finish, which sets its outside film
+/// coefficient (ASHRAE Fundamentals sheet-metal duct values).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuctSurfaceFinish {
+    /// A low-emissivity reflective (e.g. foil-faced) jacket, which
+    /// suppresses radiative exchange at the outer surface (~5.7 W/m2.K).
+    Reflective,
+    /// A high-emissivity, non-reflective jacket (e.g. painted sheet
+    /// metal, fabric) (~10.0 W/m2.K).
+    NonReflective,
+}
+
+impl DuctSurfaceFinish {
+    /// The combined convective+radiative external film coefficient
+    /// (W/m2.K) for this finish.
+    pub fn external_h(&self) -> Float {
+        match self {
+            Self::Reflective => 5.7,
+            Self::NonReflective => 10.0,
+        }
+    }
+}
+
+/// A duct's cross-sectional shape, used by [`Duct::from_construction`] to
+/// derive its internal perimeter (and hence [`Duct::area`] and the
+/// geometry its wall/insulation resistances are evaluated against).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DuctCrossSection {
+    /// A round duct of the given internal diameter (m).
+    Circular {
+        /// Internal diameter (m).
+        diameter: Float,
+    },
+    /// A rectangular duct of the given internal width and height (m). Its
+    /// wall/insulation conduction is approximated as flat rather than the
+    /// true cylindrical radial case used for [`Self::Circular`], which is
+    /// the standard simplification for non-round ducts.
+    Rectangular {
+        /// Internal width (m).
+        width: Float,
+        /// Internal height (m).
+        height: Float,
+    },
+}
+
+impl DuctCrossSection {
+    /// The internal perimeter (m).
+    pub fn perimeter(&self) -> Float {
+        match self {
+            Self::Circular { diameter } => crate::PI * diameter,
+            Self::Rectangular { width, height } => 2.0 * (width + height),
+        }
+    }
+
+    /// The internal radius (m), for [`Self::Circular`]; `None` for
+    /// [`Self::Rectangular`], which has no single radius to evaluate the
+    /// cylindrical radial-conduction formula against.
+    fn radius(&self) -> Option<Float> {
+        match self {
+            Self::Circular { diameter } => Some(diameter / 2.0),
+            Self::Rectangular { .. } => None,
+        }
+    }
+
+    /// An equivalent diameter (m) with the same perimeter, so
+    /// [`Duct::area`]'s `PI * diameter * length` formula keeps applying to
+    /// non-round ducts too.
+    fn equivalent_diameter(&self) -> Float {
+        self.perimeter() / crate::PI
+    }
+}
+
+/// A sensible heat-recovery unit (e.g. an MVHR) that blends the exhaust
+/// air leaving a Zone with the intake air from outdoors into the supply
+/// stream, before any [`Duct`] losses are applied to it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HeatRecovery {
+    /// Sensible heat-recovery efficiency, in `[0, 1]`: the fraction of
+    /// the outdoor-to-exhaust temperature difference recovered into the
+    /// supply stream.
+    pub efficiency: Float,
+}
+
+impl HeatRecovery {
+    /// Blends `t_intake` (outdoor air entering the unit) with
+    /// `t_exhaust` (air being extracted from the Zone) into the supply
+    /// temperature the unit delivers, before any duct losses.
+    pub fn blended_supply_temperature(&self, t_intake: Float, t_exhaust: Float) -> Float {
+        t_intake + self.efficiency * (t_exhaust - t_intake)
+    }
+}
+
+/// A mechanical ventilation system delivering air to a Zone through a
+/// [`Duct`], optionally passing it through a [`HeatRecovery`] unit first.
+/// Attached to a [`crate::zone::ThermalZone`] via
+/// [`crate::zone::ThermalZone::set_ventilation_duct`] to correct that
+/// Zone's `ventilation_temperature` for distribution losses in
+/// [`crate::model::ThermalModel`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VentilationSystem {
+    /// The ductwork between the source of the supply air and the Zone.
+    pub duct: Duct,
+    /// An optional heat-recovery unit blending the supply with the
+    /// Zone's exhaust air before it enters the duct.
+    pub heat_recovery: Option<HeatRecovery>,
+}
+
+impl VentilationSystem {
+    /// Corrects the scheduled supply-air temperature `t_supply` for heat
+    /// recovery (if any) and then duct losses, given the Zone's current
+    /// exhaust temperature `t_exhaust`, the duct's surrounding
+    /// temperature `t_environment`, and the supply air's mass flow and
+    /// specific heat.
+    pub fn corrected_supply_temperature(
+        &self,
+        t_supply: Float,
+        t_exhaust: Float,
+        t_environment: Float,
+        mass_flow: Float,
+        cp: Float,
+    ) -> Float {
+        let t_supply = match &self.heat_recovery {
+            Some(hr) => hr.blended_supply_temperature(t_supply, t_exhaust),
+            None => t_supply,
+        };
+        self.duct
+            .corrected_supply_temperature(t_supply, t_environment, mass_flow, cp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn duct_with_no_flow_leaves_supply_unchanged() {
+        let duct = Duct {
+            length: 5.0,
+            diameter: 0.2,
+            resistance: 1.0,
+            environment: DuctEnvironment::Ambient,
+        };
+        assert_eq!(duct.corrected_supply_temperature(20.0, -5.0, 0.0, 1000.0), 20.0);
+    }
+
+    #[test]
+    fn duct_pulls_supply_towards_environment() {
+        let duct = Duct {
+            length: 10.0,
+            diameter: 0.3,
+            resistance: 0.5,
+            environment: DuctEnvironment::Ambient,
+        };
+        let corrected = duct.corrected_supply_temperature(20.0, -5.0, 0.05, 1000.0);
+        // Heat is lost towards the colder ambient environment, so the
+        // corrected supply temperature should sit strictly between the
+        // two, closer to ambient than a lossless duct would be.
+        assert!(corrected < 20.0 && corrected > -5.0);
+    }
+
+    #[test]
+    fn heat_recovery_blends_towards_exhaust() {
+        let hr = HeatRecovery { efficiency: 0.8 };
+        let blended = hr.blended_supply_temperature(-5.0, 20.0);
+        assert_eq!(blended, -5.0 + 0.8 * (20.0 - (-5.0)));
+    }
+
+    #[test]
+    fn circular_from_construction_matches_hand_computed_resistance() {
+        let cross_section = DuctCrossSection::Circular { diameter: 0.2 };
+        let layers = [DuctLayer {
+            thickness: 0.025,
+            conductivity: 0.035,
+        }];
+        let duct = Duct::from_construction(
+            5.0,
+            cross_section,
+            &layers,
+            8.0,
+            DuctSurfaceFinish::NonReflective,
+            DuctEnvironment::Ambient,
+        );
+
+        let r_inner: Float = 0.1;
+        let r_outer = r_inner + 0.025;
+        let expected = 1.0 / 8.0
+            + r_inner * (r_outer / r_inner).ln() / 0.035
+            + r_inner / (r_outer * 10.0);
+        assert!((duct.resistance - expected).abs() < 1e-9);
+        assert_eq!(duct.diameter, 0.2);
+    }
+
+    #[test]
+    fn reflective_finish_gives_lower_external_h_and_higher_resistance() {
+        let cross_section = DuctCrossSection::Circular { diameter: 0.2 };
+        let layers = [DuctLayer {
+            thickness: 0.025,
+            conductivity: 0.035,
+        }];
+        let reflective = Duct::from_construction(
+            5.0,
+            cross_section,
+            &layers,
+            8.0,
+            DuctSurfaceFinish::Reflective,
+            DuctEnvironment::Ambient,
+        );
+        let non_reflective = Duct::from_construction(
+            5.0,
+            cross_section,
+            &layers,
+            8.0,
+            DuctSurfaceFinish::NonReflective,
+            DuctEnvironment::Ambient,
+        );
+        // A lower external film coefficient (reflective) means more
+        // resistance to losing heat outward.
+        assert!(reflective.resistance > non_reflective.resistance);
+    }
+
+    #[test]
+    fn rectangular_from_construction_uses_flat_wall_approximation() {
+        let cross_section = DuctCrossSection::Rectangular {
+            width: 0.3,
+            height: 0.2,
+        };
+        let layers = [DuctLayer {
+            thickness: 0.025,
+            conductivity: 0.035,
+        }];
+        let duct = Duct::from_construction(
+            5.0,
+            cross_section,
+            &layers,
+            8.0,
+            DuctSurfaceFinish::NonReflective,
+            DuctEnvironment::Ambient,
+        );
+
+        let expected = 1.0 / 8.0 + 0.025 / 0.035 + 1.0 / 10.0;
+        assert!((duct.resistance - expected).abs() < 1e-9);
+        // area() should still match the rectangular duct's true perimeter.
+        let expected_perimeter = 2.0 * (0.3 + 0.2);
+        assert!((duct.area() - expected_perimeter * 5.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,413 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::heating_cooling::{ThermalHVAC, ThermalHVACMemory};
+use crate::Float;
+
+/// A fuel (or utility) an HVAC element draws on to produce its delivered
+/// heat—used to route its demand to the right account in [`FuelDemand`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fuel {
+    /// Mains or on-site-generated electricity
+    Electricity,
+
+    /// Mains gas (or, more generally, any combusted fuel metered as a heat
+    /// content rather than electrical power)
+    Gas,
+}
+
+/// The aggregated per-timestep demand of every [`crate::heating_cooling::ThermalHVAC`]
+/// in the model, split by [`Fuel`]. Only [`Fuel::Electricity`] is netted
+/// against on-site generation and a battery by [`EnergySupply::settle`];
+/// [`Fuel::Gas`] is reported straight through as imported energy.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct FuelDemand {
+    /// Total electrical power drawn by every HVAC element, in $`W`$
+    pub electricity: Float,
+
+    /// Total gas power drawn by every HVAC element, in $`W`$
+    pub gas: Float,
+}
+
+impl FuelDemand {
+    /// A zero demand, to be accumulated into with [`Self::add`]
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    /// Adds `power` (in $`W`$) to the running total for `fuel`
+    pub fn add(&mut self, fuel: Fuel, power: Float) {
+        match fuel {
+            Fuel::Electricity => self.electricity += power,
+            Fuel::Gas => self.gas += power,
+        }
+    }
+
+    /// Folds a single `hvac`'s fuel demand into this total, given its
+    /// `memory` and the `delivered` heat returned by the same call to
+    /// [`ThermalHVAC::calc_cooling_heating_power`] that produced it—i.e. one
+    /// call per element of [`crate::model::ThermalModel::hvacs`], mirroring
+    /// the loop already used to assemble the zones' heat balance.
+    pub fn accumulate(
+        &mut self,
+        hvac: &ThermalHVAC,
+        memory: &ThermalHVACMemory,
+        delivered: &[(usize, Float)],
+    ) -> Result<(), String> {
+        let power = hvac.fuel_demand(memory, delivered)?;
+        self.add(hvac.fuel(), power);
+        Ok(())
+    }
+}
+
+/// An electric battery buffering on-site generation against electrical
+/// demand. Configuration only—its state of charge lives in [`BatteryMemory`],
+/// following the same split used by [`crate::heating_cooling::ThermalHVAC`]
+/// and [`crate::heating_cooling::ThermalHVACMemory`].
+#[derive(Debug, Clone, Copy)]
+pub struct Battery {
+    /// The battery's usable energy capacity, in $`J`$
+    pub capacity: Float,
+
+    /// Fraction of energy retained when charging (`0-1`): charging the
+    /// battery with `p` Watts for `dt` seconds raises its state of charge by
+    /// `p * charge_efficiency * dt` Joules.
+    pub charge_efficiency: Float,
+
+    /// Fraction of energy retained when discharging (`0-1`): drawing `p`
+    /// Watts from the battery for `dt` seconds lowers its state of charge by
+    /// `p / discharge_efficiency * dt` Joules.
+    pub discharge_efficiency: Float,
+}
+
+/// The mutable state of a [`Battery`], allocated by [`Battery::allocate_memory`]
+/// and updated every step by [`EnergySupply::settle`].
+#[derive(Debug, Clone, Copy)]
+pub struct BatteryMemory {
+    /// The energy currently stored in the battery, in $`J`$
+    pub state_of_charge: Float,
+}
+
+impl Battery {
+    /// Allocates this battery's mutable state, starting from an empty
+    /// battery (`state_of_charge = 0.0`).
+    pub fn allocate_memory(&self) -> BatteryMemory {
+        BatteryMemory {
+            state_of_charge: 0.0,
+        }
+    }
+
+    /// Offers `available` Watts of surplus power to the battery over `dt`
+    /// seconds, returning `(power_absorbed, power_left_over)`—the power
+    /// actually drawn from the surplus (before [`Self::charge_efficiency`]
+    /// losses are applied to what reaches the cells) and whatever the
+    /// battery had no room left to accept.
+    fn charge(&self, available: Float, dt: Float, memory: &mut BatteryMemory) -> (Float, Float) {
+        let room = (self.capacity - memory.state_of_charge).max(0.0);
+        let max_input_power = room / self.charge_efficiency.max(1e-6) / dt;
+        let absorbed = available.clamp(0.0, max_input_power);
+        memory.state_of_charge += absorbed * self.charge_efficiency * dt;
+        (absorbed, available - absorbed)
+    }
+
+    /// Draws up to `requested` Watts of electrical demand from the battery
+    /// over `dt` seconds, returning `(power_supplied, power_left_over)`—the
+    /// power delivered to the demand and whatever the battery could not
+    /// cover.
+    fn discharge(
+        &self,
+        requested: Float,
+        dt: Float,
+        memory: &mut BatteryMemory,
+    ) -> (Float, Float) {
+        let max_output_power = memory.state_of_charge * self.discharge_efficiency / dt;
+        let supplied = requested.clamp(0.0, max_output_power);
+        memory.state_of_charge -= supplied / self.discharge_efficiency.max(1e-6) * dt;
+        memory.state_of_charge = memory.state_of_charge.max(0.0);
+        (supplied, requested - supplied)
+    }
+}
+
+/// A resistive "dump load" (e.g. an immersion heater) that can absorb
+/// electrical surplus left over after self-consumption and battery charging,
+/// turning it into heat in a target space rather than exporting it.
+#[derive(Debug, Clone, Copy)]
+pub struct Diverter {
+    /// The space the diverted heat is dumped into
+    pub target_space_index: usize,
+
+    /// The most power this dump load can absorb, in $`W`$
+    pub max_power: Float,
+}
+
+impl Diverter {
+    /// Diverts up to [`Self::max_power`] Watts out of `available`, returning
+    /// `(power_diverted, power_left_over)`.
+    fn divert(&self, available: Float) -> (Float, Float) {
+        let diverted = available.clamp(0.0, self.max_power);
+        (diverted, available - diverted)
+    }
+}
+
+/// The energy flows settled for a single timestep by [`EnergySupply::settle`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnergySupplySettlement {
+    /// Electricity imported from the grid, in $`W`$
+    pub imported_electricity: Float,
+
+    /// Gas imported to cover [`FuelDemand::gas`], in $`W`$
+    pub imported_gas: Float,
+
+    /// On-site generation exported to the grid (after self-consumption,
+    /// battery charging and diverting), in $`W`$
+    pub exported_electricity: Float,
+
+    /// On-site generation consumed on-site, either directly against demand,
+    /// by charging the battery or by feeding the [`Diverter`], in $`W`$.
+    /// Power later discharged from the battery is not counted again.
+    pub self_consumed_pv: Float,
+
+    /// The battery's state of charge after this step, in $`J`$ (`0.0` if no
+    /// [`Battery`] is configured)
+    pub battery_state_of_charge: Float,
+
+    /// The space and power of heat dumped by the [`Diverter`] this step, if
+    /// one is configured and there was surplus left to divert
+    pub diverted_heat: Option<(usize, Float)>,
+}
+
+/// The mutable state of an [`EnergySupply`], allocated by
+/// [`EnergySupply::allocate_memory`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnergySupplyMemory {
+    /// The state of [`EnergySupply::battery`], if configured
+    pub battery: Option<BatteryMemory>,
+}
+
+/// Accounts for the energy behind the heat that every
+/// [`crate::heating_cooling::ThermalHVAC`] delivers: aggregates their demand
+/// by [`Fuel`], nets the electrical share against on-site PV generation,
+/// routes the surplus/deficit through an optional [`Battery`], diverts
+/// left-over surplus into an optional [`Diverter`] before exporting, and
+/// reports what was actually imported from (and exported to) the grid.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnergySupply {
+    /// The on-site battery buffering electrical surplus/deficit, if any
+    pub battery: Option<Battery>,
+
+    /// A resistive dump load for surplus generation, if any
+    pub diverter: Option<Diverter>,
+}
+
+impl EnergySupply {
+    /// Allocates this subsystem's mutable state (see [`EnergySupplyMemory`])
+    pub fn allocate_memory(&self) -> EnergySupplyMemory {
+        EnergySupplyMemory {
+            battery: self.battery.as_ref().map(Battery::allocate_memory),
+        }
+    }
+
+    /// Settles one timestep's energy accounts, given the aggregated HVAC
+    /// `demand`, the `pv_generation` (in $`W`$) available on site over a step
+    /// of `dt` seconds.
+    ///
+    /// Gas demand is reported straight through as [`EnergySupplySettlement::imported_gas`].
+    /// Electrical demand is first met directly from `pv_generation`
+    /// (self-consumption); any remaining surplus charges the [`Battery`]
+    /// (if configured), then feeds the [`Diverter`] (if configured), with
+    /// whatever is left exported; any remaining deficit is drawn from the
+    /// battery before being imported.
+    pub fn settle(
+        &self,
+        demand: &FuelDemand,
+        pv_generation: Float,
+        dt: Float,
+        memory: &mut EnergySupplyMemory,
+    ) -> Result<EnergySupplySettlement, String> {
+        let mut self_consumed_pv = pv_generation.min(demand.electricity);
+        let mut surplus = (pv_generation - demand.electricity).max(0.0);
+        let mut deficit = (demand.electricity - pv_generation).max(0.0);
+        let mut diverted_heat = None;
+
+        if let Some(battery) = &self.battery {
+            let battery_memory = memory.battery.as_mut().ok_or_else(|| {
+                "EnergySupply has a battery configured, but no battery memory was allocated"
+                    .to_string()
+            })?;
+
+            if surplus > 0.0 {
+                let (absorbed, left_over) = battery.charge(surplus, dt, battery_memory);
+                self_consumed_pv += absorbed;
+                surplus = left_over;
+            } else if deficit > 0.0 {
+                let (_, left_over) = battery.discharge(deficit, dt, battery_memory);
+                deficit = left_over;
+            }
+        }
+
+        if surplus > 0.0 {
+            if let Some(diverter) = &self.diverter {
+                let (diverted, left_over) = diverter.divert(surplus);
+                if diverted > 0.0 {
+                    self_consumed_pv += diverted;
+                    diverted_heat = Some((diverter.target_space_index, diverted));
+                }
+                surplus = left_over;
+            }
+        }
+
+        let battery_state_of_charge = match &memory.battery {
+            Some(battery_memory) => battery_memory.state_of_charge,
+            None => 0.0,
+        };
+
+        Ok(EnergySupplySettlement {
+            imported_electricity: deficit,
+            imported_gas: demand.gas,
+            exported_electricity: surplus,
+            self_consumed_pv,
+            battery_state_of_charge,
+            diverted_heat,
+        })
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::heating_cooling::HeatPump;
+
+    #[test]
+    fn test_fuel_demand_accumulates_by_fuel() {
+        let heat_pump = ThermalHVAC::new_heat_pump(HeatPump {
+            eta: 0.4,
+            rated_capacity: 3000.0,
+            rated_source_temperature: 7.0,
+            capacity_temperature_coefficient: 0.0,
+            defrost_threshold: -100.0,
+            defrost_derating: 1.0,
+            sink_temperature: 35.0,
+            target_space_index: 0,
+        });
+        let mut memory = heat_pump.allocate_memory();
+        if let ThermalHVACMemory::HeatPump {
+            electrical_input, ..
+        } = &mut memory
+        {
+            *electrical_input = 500.0;
+        }
+
+        let mut demand = FuelDemand::zero();
+        demand
+            .accumulate(&heat_pump, &memory, &[(0, 2000.0)])
+            .unwrap();
+
+        // The heat pump's fuel demand is its electrical draw (500 W), not
+        // the heat it delivered (2000 W).
+        assert_eq!(demand.electricity, 500.0);
+        assert_eq!(demand.gas, 0.0);
+    }
+
+    #[test]
+    fn test_settle_without_pv_imports_all_demand() {
+        let supply = EnergySupply::default();
+        let mut memory = supply.allocate_memory();
+        let demand = FuelDemand {
+            electricity: 1000.0,
+            gas: 500.0,
+        };
+        let settlement = supply.settle(&demand, 0.0, 3600.0, &mut memory).unwrap();
+        assert_eq!(settlement.imported_electricity, 1000.0);
+        assert_eq!(settlement.imported_gas, 500.0);
+        assert_eq!(settlement.exported_electricity, 0.0);
+        assert_eq!(settlement.self_consumed_pv, 0.0);
+    }
+
+    #[test]
+    fn test_settle_exports_surplus_without_battery_or_diverter() {
+        let supply = EnergySupply::default();
+        let mut memory = supply.allocate_memory();
+        let demand = FuelDemand {
+            electricity: 200.0,
+            gas: 0.0,
+        };
+        let settlement = supply.settle(&demand, 800.0, 3600.0, &mut memory).unwrap();
+        assert_eq!(settlement.self_consumed_pv, 200.0);
+        assert_eq!(settlement.exported_electricity, 600.0);
+        assert_eq!(settlement.imported_electricity, 0.0);
+    }
+
+    #[test]
+    fn test_settle_charges_and_discharges_battery() {
+        let supply = EnergySupply {
+            battery: Some(Battery {
+                capacity: 3_600_000.0, // 1 kWh
+                charge_efficiency: 0.9,
+                discharge_efficiency: 0.9,
+            }),
+            diverter: None,
+        };
+        let mut memory = supply.allocate_memory();
+
+        // An hour of 500 W surplus should charge the battery.
+        let surplus_demand = FuelDemand {
+            electricity: 0.0,
+            gas: 0.0,
+        };
+        let settlement = supply
+            .settle(&surplus_demand, 500.0, 3600.0, &mut memory)
+            .unwrap();
+        assert_eq!(settlement.exported_electricity, 0.0);
+        assert!(settlement.battery_state_of_charge > 0.0);
+        let charged = settlement.battery_state_of_charge;
+
+        // An hour of 500 W deficit (no PV) should discharge it back down.
+        let deficit_demand = FuelDemand {
+            electricity: 500.0,
+            gas: 0.0,
+        };
+        let settlement = supply
+            .settle(&deficit_demand, 0.0, 3600.0, &mut memory)
+            .unwrap();
+        assert!(settlement.battery_state_of_charge < charged);
+        assert!(settlement.imported_electricity < 500.0);
+    }
+
+    #[test]
+    fn test_settle_diverts_surplus_before_exporting() {
+        let supply = EnergySupply {
+            battery: None,
+            diverter: Some(Diverter {
+                target_space_index: 2,
+                max_power: 300.0,
+            }),
+        };
+        let mut memory = supply.allocate_memory();
+        let demand = FuelDemand {
+            electricity: 100.0,
+            gas: 0.0,
+        };
+        let settlement = supply.settle(&demand, 900.0, 3600.0, &mut memory).unwrap();
+
+        // 800 W surplus: 300 W diverted, 500 W exported.
+        assert_eq!(settlement.diverted_heat, Some((2, 300.0)));
+        assert_eq!(settlement.exported_electricity, 500.0);
+    }
+}
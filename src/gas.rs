@@ -20,6 +20,217 @@ SOFTWARE.
 
 use crate::Float;
 use polynomial::{poly, Polynomial};
+use serde::{Deserialize, Serialize};
+
+/// Standard atmospheric pressure at sea level ($`Pa`$), the default fill
+/// pressure assumed by [`Gas::density`], [`Gas::cavity_convection`] and
+/// their [`GasMixture`] equivalents.
+pub const STANDARD_PRESSURE: Float = 101325.0;
+
+/// The basis a [`GasProperty`] polynomial is expressed in.
+///
+/// `Linear` is accurate close to room temperature, but a single
+/// low-degree polynomial in `T` degrades over wide ranges (e.g. fire or
+/// overheating studies spanning several hundred kelvin). The `Log*`
+/// variants follow the transport-property fitting approach of fitting in
+/// `ln(T)` instead, which holds up over a much wider range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PropertyBasis {
+    /// The stored polynomial is evaluated directly at `T`: $`\sum_k a_k T^k`$
+    Linear,
+
+    /// The stored polynomial is evaluated at `ln(T)` and then
+    /// exponentiated: $`\exp\left(\sum_k a_k (\ln T)^k\right)`$
+    LogExp,
+
+    /// The stored polynomial is evaluated at `ln(T)` directly, with no
+    /// exponentiation: $`\sum_k a_k (\ln T)^k`$
+    Log,
+
+    /// Sutherland's law, the kinetic-theory form fit to dynamic viscosity
+    /// in particular: $`\mu(T) = \mu_0 (T/T_0)^{1.5} (T_0+S)/(T+S)`$. The
+    /// stored polynomial's constant term holds $`\mu_0`$ (built via
+    /// [`GasProperty::sutherland`]); $`T_0`$ and $`S`$ are kept alongside
+    /// it in [`GasProperty`], since they aren't polynomial coefficients.
+    Sutherland,
+}
+
+/// A single temperature-dependent gas property (thermal conductivity,
+/// dynamic viscosity or heat capacity)—a [`Polynomial`] together with the
+/// [`PropertyBasis`] it should be evaluated in.
+#[derive(Debug, Clone)]
+pub struct GasProperty {
+    coefficients: Polynomial,
+    basis: PropertyBasis,
+    /// Sutherland's law's reference temperature `T0` (K) and Sutherland
+    /// constant `S` (K); unused (and left `(0.0, 0.0)`) unless `basis ==
+    /// PropertyBasis::Sutherland`.
+    sutherland_t0_s: (Float, Float),
+}
+
+impl GasProperty {
+    /// Builds a [`GasProperty`] from already-fit `coefficients`, to be
+    /// evaluated in the given `basis`.
+    pub fn new(coefficients: Vec<Float>, basis: PropertyBasis) -> Self {
+        Self {
+            coefficients: Polynomial::new(coefficients),
+            basis,
+            sutherland_t0_s: (0.0, 0.0),
+        }
+    }
+
+    /// Builds a [`GasProperty`] following Sutherland's law (see
+    /// [`PropertyBasis::Sutherland`]), from a reference viscosity `mu0`
+    /// ($`N.s/m^2`$) at reference temperature `t0` (K) and Sutherland
+    /// constant `s` (K)—the usual way dynamic viscosity is fit over a wide
+    /// temperature range when only a single reference point is available,
+    /// rather than [`GasProperty::fit_from_points`]'s multi-point
+    /// polynomial fit.
+    pub fn sutherland(mu0: Float, t0: Float, s: Float) -> Self {
+        Self {
+            coefficients: Polynomial::new(vec![mu0]),
+            basis: PropertyBasis::Sutherland,
+            sutherland_t0_s: (t0, s),
+        }
+    }
+
+    /// Evaluates this property at `temp` (in $`K`$), dispatching on
+    /// [`PropertyBasis`].
+    pub fn eval(&self, temp: Float) -> Float {
+        match self.basis {
+            PropertyBasis::Linear => self.coefficients.eval(temp),
+            PropertyBasis::LogExp => self.coefficients.eval(temp.ln()).exp(),
+            PropertyBasis::Log => self.coefficients.eval(temp.ln()),
+            PropertyBasis::Sutherland => {
+                let mu0 = self.coefficients.eval(0.0);
+                let (t0, s) = self.sutherland_t0_s;
+                mu0 * (temp / t0).powf(1.5) * (t0 + s) / (temp + s)
+            }
+        }
+    }
+
+    /// Least-squares fits a degree-`degree` polynomial through
+    /// `(temps[i], values[i])`, expressed in `basis`—e.g. `basis:
+    /// PropertyBasis::LogExp` fits `ln(values)` against `ln(temps)`, so
+    /// [`Self::eval`] later reconstructs `values` via the matching
+    /// exponentiation.
+    ///
+    /// # Errors
+    /// Returns an error if `temps` and `values` differ in length, if there
+    /// are fewer than `degree + 1` points to fit, or if `basis ==
+    /// PropertyBasis::Sutherland` (a two-parameter physical law, not a
+    /// polynomial fit—build it with [`Self::sutherland`] instead).
+    pub fn fit_from_points(
+        temps: &[Float],
+        values: &[Float],
+        degree: usize,
+        basis: PropertyBasis,
+    ) -> Result<Self, String> {
+        if basis == PropertyBasis::Sutherland {
+            return Err(
+                "fit_from_points cannot fit a PropertyBasis::Sutherland property; use GasProperty::sutherland instead"
+                    .to_string(),
+            );
+        }
+        if temps.len() != values.len() {
+            return Err(format!(
+                "fit_from_points expected the same number of temps and values, got {} and {}",
+                temps.len(),
+                values.len()
+            ));
+        }
+        if temps.len() < degree + 1 {
+            return Err(format!(
+                "fit_from_points needs at least {} points to fit a degree-{degree} polynomial, got {}",
+                degree + 1,
+                temps.len()
+            ));
+        }
+
+        // Fitting in `ln(T)` (and, for `LogExp`, `ln(value)`) turns either
+        // basis into a plain polynomial least-squares fit.
+        let xs: Vec<Float> = match basis {
+            PropertyBasis::Linear => temps.to_vec(),
+            PropertyBasis::LogExp | PropertyBasis::Log => temps.iter().map(|t| t.ln()).collect(),
+            PropertyBasis::Sutherland => unreachable!(),
+        };
+        let ys: Vec<Float> = match basis {
+            PropertyBasis::Linear | PropertyBasis::Log => values.to_vec(),
+            PropertyBasis::LogExp => values.iter().map(|v| v.ln()).collect(),
+            PropertyBasis::Sutherland => unreachable!(),
+        };
+
+        let coefficients = least_squares_fit(&xs, &ys, degree)?;
+        Ok(Self {
+            coefficients: Polynomial::new(coefficients),
+            basis,
+            sutherland_t0_s: (0.0, 0.0),
+        })
+    }
+}
+
+/// Least-squares fits a degree-`degree` polynomial through `(xs[i],
+/// ys[i])`, by solving the normal equations $`A^T A c = A^T y`$ (where
+/// `A`'s columns are powers of `x` from `0` to `degree`) via Gaussian
+/// elimination with partial pivoting. This crate doesn't otherwise depend
+/// on a general linear-least-squares routine, so it's written out here
+/// rather than pulled in from elsewhere.
+fn least_squares_fit(xs: &[Float], ys: &[Float], degree: usize) -> Result<Vec<Float>, String> {
+    let n = degree + 1;
+    let mut ata = vec![vec![0.0 as Float; n]; n];
+    let mut aty = vec![0.0 as Float; n];
+
+    for (x, y) in xs.iter().zip(ys.iter()) {
+        let mut powers = vec![1.0 as Float; n];
+        for k in 1..n {
+            powers[k] = powers[k - 1] * x;
+        }
+        for i in 0..n {
+            aty[i] += powers[i] * y;
+            for j in 0..n {
+                ata[i][j] += powers[i] * powers[j];
+            }
+        }
+    }
+
+    for col in 0..n {
+        let mut pivot_row = col;
+        let mut pivot_val = ata[col][col].abs();
+        for row in (col + 1)..n {
+            if ata[row][col].abs() > pivot_val {
+                pivot_row = row;
+                pivot_val = ata[row][col].abs();
+            }
+        }
+        if pivot_val < 1e-12 {
+            return Err(
+                "fit_from_points: the normal equations are singular (are the sample points too few or collinear?)"
+                    .to_string(),
+            );
+        }
+        ata.swap(col, pivot_row);
+        aty.swap(col, pivot_row);
+
+        for row in (col + 1)..n {
+            let factor = ata[row][col] / ata[col][col];
+            for k in col..n {
+                ata[row][k] -= factor * ata[col][k];
+            }
+            aty[row] -= factor * aty[col];
+        }
+    }
+
+    let mut coefficients = vec![0.0 as Float; n];
+    for row in (0..n).rev() {
+        let mut sum = aty[row];
+        for k in (row + 1)..n {
+            sum -= ata[row][k] * coefficients[k];
+        }
+        coefficients[row] = sum / ata[row][row];
+    }
+
+    Ok(coefficients)
+}
 
 /// A structure containing the data that will describe the thermal
 /// behaviour of a gas.
@@ -27,15 +238,15 @@ use polynomial::{poly, Polynomial};
 pub struct Gas {
     /// The thermal conductivity ($`{W}/{m.K}`$) as a function of the
     /// temperature (in $`K`$)
-    thermal_conductivity: Polynomial,
+    thermal_conductivity: GasProperty,
 
     /// The dynamic viscosity ( $`{N.s}/{m^2}`$) as a function of the
     /// temperature (in $`K`$)
-    dynamic_viscosity: Polynomial,
+    dynamic_viscosity: GasProperty,
 
     /// The specific heat capacity ($`{J}/{kg.K}`$) as a function of the
     /// temperature (in $`K`$)
-    heat_capacity: Polynomial,
+    heat_capacity: GasProperty,
 
     /// THe Molecular Mass ($`{kg}/{Mol}`$)
     mass: Float,
@@ -44,44 +255,64 @@ pub struct Gas {
 
 /// Returns a gas with the properties of Air
 pub const AIR : Gas = Gas {
-    thermal_conductivity: poly![2.873e-3, 7.760e-5],
-    dynamic_viscosity: poly![3.723e-6, 4.94e-8],
-    heat_capacity: poly![1002.7370, 1.2324e-2],
+    thermal_conductivity: GasProperty { coefficients: poly![2.873e-3, 7.760e-5], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    dynamic_viscosity: GasProperty { coefficients: poly![3.723e-6, 4.94e-8], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    heat_capacity: GasProperty { coefficients: poly![1002.7370, 1.2324e-2], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
     mass: 28.97,
 };
 
 /// Returns a gas with the properties of argon
 pub const ARGON : Gas = Gas {
-    thermal_conductivity: poly![2.285e-3, 5.149e-5],
-    dynamic_viscosity: poly![3.379e-6, 6.451e-8],
-    heat_capacity: poly![521.9285],
+    thermal_conductivity: GasProperty { coefficients: poly![2.285e-3, 5.149e-5], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    dynamic_viscosity: GasProperty { coefficients: poly![3.379e-6, 6.451e-8], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    heat_capacity: GasProperty { coefficients: poly![521.9285], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
     mass: 39.948,
 };
 
 
 /// A gas with the properties of krypton
 pub const KRYPTON : Gas = Gas {
-    thermal_conductivity: poly![9.443e-4, 2.826e-5],
-    dynamic_viscosity: poly![2.213e-6, 7.777e-8],
-    heat_capacity: poly![248.0907],
+    thermal_conductivity: GasProperty { coefficients: poly![9.443e-4, 2.826e-5], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    dynamic_viscosity: GasProperty { coefficients: poly![2.213e-6, 7.777e-8], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    heat_capacity: GasProperty { coefficients: poly![248.0907], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
     mass: 83.8,
 };
 
 /// A gas with the properties of xenon
 pub const XENON : Gas = Gas {
-    thermal_conductivity: poly![4.538e-4, 1.723e-5],
-    dynamic_viscosity: poly![1.069e-6, 7.414e-8],
-    heat_capacity: poly![158.3397],
+    thermal_conductivity: GasProperty { coefficients: poly![4.538e-4, 1.723e-5], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    dynamic_viscosity: GasProperty { coefficients: poly![1.069e-6, 7.414e-8], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
+    heat_capacity: GasProperty { coefficients: poly![158.3397], basis: PropertyBasis::Linear , sutherland_t0_s: (0.0, 0.0) },
     mass: 131.30,
 };
 
 impl Gas {
+    /// Calculates the Raleigh number of a [`Gas`] cavity filled at standard
+    /// atmospheric pressure ([`STANDARD_PRESSURE`])—see
+    /// [`Self::raleigh_at_pressure`] for the pressure-aware version.
+    fn raleigh(&self, t_front: Float, t_back: Float, thickness: Float) -> Float {
+        self.raleigh_at_pressure(t_front, t_back, thickness, STANDARD_PRESSURE)
+    }
+
     /// Calculates the Raleigh number of a [`Gas`] cavity based on its
-    /// `thickness` and its temperatures `t_front` and `t_back` (note that, for
-    /// this particular function, these values are interchangeable)
+    /// `thickness`, its fill `pressure` (in $`Pa`$) and its temperatures
+    /// `t_front` and `t_back` (note that, for this particular function,
+    /// these values are interchangeable).
+    ///
+    /// Since $`Ra \propto \rho^2`$ (Eq. 40 below), a cavity filled below
+    /// standard atmospheric pressure (e.g. a partially evacuated IGU, or a
+    /// building at altitude, like [`STANDARD_PRESSURE`] scaled down for
+    /// Denver's ~84 kPa) sees measurably less convective transfer across
+    /// the gap.
     ///
     /// Source: Equation 40 of ISO15099/2003
-    fn raleigh(&self, t_front: Float, t_back: Float, thickness: Float) -> Float {
+    fn raleigh_at_pressure(
+        &self,
+        t_front: Float,
+        t_back: Float,
+        thickness: Float,
+        pressure: Float,
+    ) -> Float {
         const G: Float = 9.81;
 
         if (t_front - t_back).abs() < 1e-10 {
@@ -97,7 +328,7 @@ impl Gas {
         let c_p = self.heat_capacity(temp);
         let mu = self.dynamic_viscosity(temp);
         let lambda = self.thermal_conductivity(temp);
-        let rho = self.density(temp);
+        let rho = self.density_at_pressure(temp, pressure);
 
         // Eq. 40 of iso15099/2003
         rho.powi(2) * thickness.powi(3) * G * beta * c_p * (t_front - t_back).abs() / (mu * lambda)
@@ -124,14 +355,34 @@ impl Gas {
     /// when the calculation is carried out".        
     ///
     /// This conversion is handleded automatically by this function based
-    /// on the inputs given to `t_front` and `t_back`
+    /// on the inputs given to `t_front` and `t_back`.
+    ///
+    /// Assumes the cavity is filled at standard atmospheric pressure
+    /// ([`STANDARD_PRESSURE`])—see [`Self::cavity_convection_at_pressure`]
+    /// for the pressure-aware version.
     pub fn cavity_convection(
+        &self,
+        height: Float,
+        thickness: Float,
+        gamma: Float,
+        t_front: Float,
+        t_back: Float,
+    ) -> Float {
+        self.cavity_convection_at_pressure(height, thickness, gamma, t_front, t_back, STANDARD_PRESSURE)
+    }
+
+    /// Like [`Self::cavity_convection`], but for a cavity filled at
+    /// `pressure` (in $`Pa`$) instead of standard atmospheric pressure—e.g.
+    /// a partially evacuated IGU, or a building at altitude (Denver sits at
+    /// roughly 84 kPa).
+    pub fn cavity_convection_at_pressure(
         &self,
         height: Float,
         thickness: Float,
         mut gamma: Float,
         t_front: Float,
         t_back: Float,
+        pressure: Float,
     ) -> Float {
         debug_assert!(gamma >= 0.0);
         debug_assert!(gamma <= (180. as Float).to_radians());
@@ -143,7 +394,7 @@ impl Gas {
         // Eq. 42
         let a_gi = height / thickness;
 
-        let ra = self.raleigh(t_front, t_back, thickness);
+        let ra = self.raleigh_at_pressure(t_front, t_back, thickness, pressure);
         let nu = nusselt(ra, gamma, a_gi);
 
         let temp = (in_kelvin(t_front) + in_kelvin(t_back)) / 2.;
@@ -173,19 +424,410 @@ impl Gas {
         self.mass
     }
 
-    /// Derives the density based on the temperature (in $`K`$)
+    /// Builds a [`Gas`] from raw coefficient vectors instead of the
+    /// compile-time `poly!` literals [`AIR`]/[`ARGON`]/[`KRYPTON`]/[`XENON`]
+    /// are defined with, so that a gas (SF6, CO2, a proprietary blend, ...)
+    /// can be assembled at runtime from a data file via [`GasRecord`]
+    /// instead of being hardcoded here.
+    pub fn from_coefficients(
+        thermal_conductivity: Vec<Float>,
+        dynamic_viscosity: Vec<Float>,
+        heat_capacity: Vec<Float>,
+        mass: Float,
+    ) -> Self {
+        Self::from_properties(
+            GasProperty::new(thermal_conductivity, PropertyBasis::Linear),
+            GasProperty::new(dynamic_viscosity, PropertyBasis::Linear),
+            GasProperty::new(heat_capacity, PropertyBasis::Linear),
+            mass,
+        )
+    }
+
+    /// Builds a [`Gas`] from already-assembled [`GasProperty`] values, so
+    /// each property can independently use whichever [`PropertyBasis`]
+    /// fits its data best—e.g. a [`GasProperty::fit_from_points`] fit in
+    /// `ln(T)` for a property needed over a wide temperature range,
+    /// alongside others left in the default [`PropertyBasis::Linear`].
+    pub fn from_properties(
+        thermal_conductivity: GasProperty,
+        dynamic_viscosity: GasProperty,
+        heat_capacity: GasProperty,
+        mass: Float,
+    ) -> Self {
+        Self {
+            thermal_conductivity,
+            dynamic_viscosity,
+            heat_capacity,
+            mass,
+        }
+    }
+
+    /// Derives the density based on the temperature (in $`K`$), assuming
+    /// the gas is at standard atmospheric pressure ([`STANDARD_PRESSURE`])
+    /// —see [`Self::density_at_pressure`] for the pressure-aware version.
     pub fn density(&self, temp: Float) -> Float {
+        self.density_at_pressure(temp, STANDARD_PRESSURE)
+    }
+
+    /// Derives the density based on the temperature (in $`K`$) and the
+    /// fill `pressure` (in $`Pa`$), via the ideal gas law (Eq. 55 of
+    /// ISO15099/2003, generalized from standard atmospheric pressure to an
+    /// arbitrary fill pressure).
+    pub fn density_at_pressure(&self, temp: Float, pressure: Float) -> Float {
         const R: Float = 8314.46261815324;
         // Eq. 55 of iso15099/2003
-        101325. * self.mass / (R * temp)
+        pressure * self.mass / (R * temp)
+    }
+
+    /// Derives the Prandtl number ($`Pr = {c_p \mu}/{\lambda}`$) at a
+    /// certain temperature (in $`K`$)—the ratio of momentum diffusivity to
+    /// thermal diffusivity, used below in [`Self::forced_convection`].
+    pub fn prandtl(&self, temp: Float) -> Float {
+        self.heat_capacity(temp) * self.dynamic_viscosity(temp) / self.thermal_conductivity(temp)
+    }
+
+    /// Derives the Reynolds number ($`Re = {\rho v L}/{\mu}`$) at a certain
+    /// temperature (in $`K`$), for a flow of `velocity` (in $`m/s`$) over a
+    /// characteristic `length` (in $`m`$).
+    pub fn reynolds(&self, temp: Float, velocity: Float, length: Float) -> Float {
+        self.density(temp) * velocity * length / self.dynamic_viscosity(temp)
+    }
+
+    /// Derives an exterior (forced, wind-driven) convective heat transfer
+    /// coefficient (in $`W/m^2K`$), for a surface of characteristic
+    /// `length` (in $`m`$) exposed to `wind_speed` (in $`m/s`$) at `temp`
+    /// (in $`K`$).
+    ///
+    /// Uses the flat-plate turbulent-boundary-layer correlation
+    /// $`Nu = 0.037 Re^{0.8} Pr^{1/3}`$, then $`h = Nu \lambda / L`$—this
+    /// complements [`Self::cavity_convection`]'s interior, natural-convection
+    /// coefficient with the exterior, wind-driven one ISO15099/2003 also
+    /// requires for a complete glazing system solve.
+    pub fn forced_convection(&self, temp: Float, wind_speed: Float, length: Float) -> Float {
+        let re = self.reynolds(temp, wind_speed, length);
+        let pr = self.prandtl(temp);
+        let nu = 0.037 * re.powf(0.8) * pr.powf(1. / 3.);
+        nu * self.thermal_conductivity(temp) / length
+    }
+}
+
+/// A serializable, data-file-friendly description of a [`Gas`]—the
+/// coefficient vectors [`Gas::from_coefficients`] expects, plus a `name` so
+/// several of these can be kept together in a gas library file (e.g. a
+/// custom SF6/CO2/proprietary-blend library shipped alongside a building
+/// model, instead of recompiling this crate to add a gas).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GasRecord {
+    /// A human-readable name for this gas (e.g. `"SF6"`)
+    pub name: String,
+
+    /// Coefficients of the thermal conductivity polynomial ($`{W}/{m.K}`$
+    /// as a function of temperature in $`K`$), lowest order first—see
+    /// [`Gas::from_coefficients`]
+    pub thermal_conductivity: Vec<Float>,
+
+    /// Coefficients of the dynamic viscosity polynomial ($`{N.s}/{m^2}`$ as
+    /// a function of temperature in $`K`$), lowest order first
+    pub dynamic_viscosity: Vec<Float>,
+
+    /// Coefficients of the specific heat capacity polynomial ($`{J}/{kg.K}`$
+    /// as a function of temperature in $`K`$), lowest order first
+    pub heat_capacity: Vec<Float>,
+
+    /// The molecular mass ($`{kg}/{Mol}`$)
+    pub mass: Float,
+}
+
+impl GasRecord {
+    /// Builds the [`Gas`] this record describes.
+    pub fn to_gas(&self) -> Gas {
+        Gas::from_coefficients(
+            self.thermal_conductivity.clone(),
+            self.dynamic_viscosity.clone(),
+            self.heat_capacity.clone(),
+            self.mass,
+        )
+    }
+}
+
+/// Reads a gas library—a JSON array of [`GasRecord`]s—from `reader`, in the
+/// same `serde_json`-backed style as
+/// [`crate::discretization::Discretization::read_state`].
+pub fn load_gas_library<R: std::io::Read>(reader: R) -> Result<Vec<GasRecord>, String> {
+    serde_json::from_reader(reader).map_err(|e| e.to_string())
+}
+
+/// Writes `records` out as a JSON gas library `writer` can later be read
+/// back from with [`load_gas_library`], so a [`Gas`] built at runtime (or
+/// edited by hand) can be round-tripped to disk and back.
+pub fn write_gas_library<W: std::io::Write>(records: &[GasRecord], writer: W) -> Result<(), String> {
+    serde_json::to_writer(writer, records).map_err(|e| e.to_string())
+}
+
+
+
+/// A mixture of several [`Gas`]es—e.g. a 90% argon / 10% air fill in an
+/// insulated glazing unit—exposing the same `thermal_conductivity`,
+/// `dynamic_viscosity`, `heat_capacity`, `density`, `mass` and
+/// `cavity_convection` API as [`Gas`] via the ISO15099/Wilke–Mason–Saxena
+/// mixing rules, so it mirrors [`Gas`] closely enough to be dropped into
+/// [`crate::cavity::Cavity`]'s gas-filled cavity path in its place.
+#[derive(Debug, Clone)]
+pub struct GasMixture {
+    /// The mixture's components as `(Gas, mole_fraction)` pairs. Mole
+    /// fractions sum to `1` (checked by [`Self::new`]).
+    components: Vec<(Gas, Float)>,
+}
+
+impl GasMixture {
+    /// Builds a [`GasMixture`] from `(Gas, mole_fraction)` pairs.
+    ///
+    /// # Errors
+    /// Returns an error if `components` is empty or the mole fractions
+    /// don't sum to `1` within `1e-3`.
+    pub fn new(components: Vec<(Gas, Float)>) -> Result<Self, String> {
+        if components.is_empty() {
+            return Err("Cannot build a GasMixture with no components".to_string());
+        }
+        let sum: Float = components.iter().map(|(_, x)| *x).sum();
+        if (sum - 1.0).abs() > 1e-3 {
+            return Err(format!(
+                "GasMixture component mole fractions must sum to 1, got {sum}"
+            ));
+        }
+        Ok(Self { components })
+    }
+
+    /// The mixture's molar mass ($`kg/mol`$), $`M_{mix} = \sum_i x_i M_i`$
+    pub fn mass(&self) -> Float {
+        self.components.iter().map(|(gas, x)| x * gas.mass()).sum()
+    }
+
+    /// The mixture's density at `temp` (in $`K`$) at standard atmospheric
+    /// pressure ([`STANDARD_PRESSURE`])—see [`Self::density_at_pressure`]
+    /// for the pressure-aware version.
+    pub fn density(&self, temp: Float) -> Float {
+        self.density_at_pressure(temp, STANDARD_PRESSURE)
+    }
+
+    /// The mixture's density at `temp` (in $`K`$) and fill `pressure` (in
+    /// $`Pa`$), from the ideal gas law using [`Self::mass`] in place of a
+    /// pure gas's molar mass (Eq. 55 of ISO15099/2003, the same equation
+    /// [`Gas::density_at_pressure`] uses).
+    pub fn density_at_pressure(&self, temp: Float, pressure: Float) -> Float {
+        const R: Float = 8314.46261815324;
+        pressure * self.mass() / (R * temp)
+    }
+
+    /// The mixture's specific heat capacity at `temp` (in $`K`$), on a
+    /// mass-weighted basis: $`cp_{mix} = \sum_i w_i cp_i`$ with mass
+    /// fraction $`w_i = x_i M_i / M_{mix}`$.
+    pub fn heat_capacity(&self, temp: Float) -> Float {
+        let m_mix = self.mass();
+        self.components
+            .iter()
+            .map(|(gas, x)| (x * gas.mass() / m_mix) * gas.heat_capacity(temp))
+            .sum()
+    }
+
+    /// The Wilke/Mason–Saxena interaction factor $`\phi_{ij}`$ between two
+    /// components of molar mass `m_i`/`m_j`, evaluated at a transport
+    /// property (dynamic viscosity or thermal conductivity) `prop_i`/
+    /// `prop_j`—ISO15099/2003 uses the same combining form for both:
+    ///
+    /// ```math
+    /// \phi_{ij} = \frac{\left[1 + \left(\frac{prop_i}{prop_j}\right)^{0.5} \left(\frac{m_j}{m_i}\right)^{0.25}\right]^2}{\sqrt{8} \left(1 + \frac{m_i}{m_j}\right)^{0.5}}
+    /// ```
+    fn phi(prop_i: Float, prop_j: Float, m_i: Float, m_j: Float) -> Float {
+        (1. + (prop_i / prop_j).sqrt() * (m_j / m_i).powf(0.25)).powi(2)
+            / ((8.0 as Float).sqrt() * (1. + m_i / m_j).sqrt())
+    }
+
+    /// Combines a per-component transport property (dynamic viscosity or
+    /// thermal conductivity, via `property`) across the mixture using the
+    /// Wilke/Mason–Saxena rule:
+    /// $`\chi_{mix} = \sum_i x_i \chi_i / \sum_j x_j \phi_{ij}`$.
+    ///
+    /// For a single-component mixture this reduces exactly to that
+    /// component's own `property`, since $`\phi_{ii} = 1`$.
+    fn combine(&self, property: impl Fn(&Gas) -> Float) -> Float {
+        let chi: Vec<Float> = self.components.iter().map(|(gas, _)| property(gas)).collect();
+
+        let mut mixed = 0.0;
+        for (i, (gas_i, x_i)) in self.components.iter().enumerate() {
+            let mut denominator = 0.0;
+            for (j, (gas_j, x_j)) in self.components.iter().enumerate() {
+                denominator += x_j * Self::phi(chi[i], chi[j], gas_i.mass(), gas_j.mass());
+            }
+            mixed += x_i * chi[i] / denominator;
+        }
+        mixed
+    }
+
+    /// The mixture's dynamic viscosity at `temp` (in $`K`$), via the
+    /// Wilke/Mason–Saxena combining rule ISO15099 adopts.
+    pub fn dynamic_viscosity(&self, temp: Float) -> Float {
+        self.combine(|gas| gas.dynamic_viscosity(temp))
+    }
+
+    /// The mixture's thermal conductivity at `temp` (in $`K`$), via the
+    /// same Wilke/Mason–Saxena combining rule as
+    /// [`Self::dynamic_viscosity`].
+    pub fn thermal_conductivity(&self, temp: Float) -> Float {
+        self.combine(|gas| gas.thermal_conductivity(temp))
+    }
+
+    /// Mirrors [`Gas::raleigh`] for a mixture, at standard atmospheric
+    /// pressure—see [`Self::raleigh_at_pressure`] for the pressure-aware
+    /// version.
+    fn raleigh(&self, t_front: Float, t_back: Float, thickness: Float) -> Float {
+        self.raleigh_at_pressure(t_front, t_back, thickness, STANDARD_PRESSURE)
+    }
+
+    /// Mirrors [`Gas::raleigh_at_pressure`] for a mixture: every
+    /// per-component property is combined first via
+    /// [`Self::heat_capacity`], [`Self::dynamic_viscosity`],
+    /// [`Self::thermal_conductivity`] and [`Self::density_at_pressure`].
+    fn raleigh_at_pressure(
+        &self,
+        t_front: Float,
+        t_back: Float,
+        thickness: Float,
+        pressure: Float,
+    ) -> Float {
+        const G: Float = 9.81;
+
+        if (t_front - t_back).abs() < 1e-10 {
+            return 0.0000001;
+        }
+
+        let temp = (in_kelvin(t_front) + in_kelvin(t_back)) / 2.;
+        let beta = 1. / temp;
+
+        let c_p = self.heat_capacity(temp);
+        let mu = self.dynamic_viscosity(temp);
+        let lambda = self.thermal_conductivity(temp);
+        let rho = self.density_at_pressure(temp, pressure);
+
+        rho.powi(2) * thickness.powi(3) * G * beta * c_p * (t_front - t_back).abs() / (mu * lambda)
     }
 
-    
+    /// Calculates the convective heat transfer coefficient within a
+    /// gas-filled cavity at standard atmospheric pressure
+    /// ([`STANDARD_PRESSURE`])—see [`Gas::cavity_convection`] for the
+    /// parameters and derivation, and [`Self::cavity_convection_at_pressure`]
+    /// for the pressure-aware version.
+    pub fn cavity_convection(
+        &self,
+        height: Float,
+        thickness: Float,
+        gamma: Float,
+        t_front: Float,
+        t_back: Float,
+    ) -> Float {
+        self.cavity_convection_at_pressure(height, thickness, gamma, t_front, t_back, STANDARD_PRESSURE)
+    }
+
+    /// Like [`Self::cavity_convection`], but for a mixture filled at
+    /// `pressure` (in $`Pa`$) instead of standard atmospheric pressure—see
+    /// [`Gas::cavity_convection_at_pressure`] for the parameters and
+    /// derivation; this mirrors it exactly, but with every per-component
+    /// property combined across the mixture via ISO15099/Wilke–Mason–Saxena
+    /// first.
+    pub fn cavity_convection_at_pressure(
+        &self,
+        height: Float,
+        thickness: Float,
+        mut gamma: Float,
+        t_front: Float,
+        t_back: Float,
+        pressure: Float,
+    ) -> Float {
+        debug_assert!(gamma >= 0.0);
+        debug_assert!(gamma <= (180. as Float).to_radians());
+
+        if t_front > t_back {
+            gamma = (180. as Float).to_radians() - gamma;
+        }
+
+        let a_gi = height / thickness;
+
+        let ra = self.raleigh_at_pressure(t_front, t_back, thickness, pressure);
+        let nu = nusselt(ra, gamma, a_gi);
+
+        let temp = (in_kelvin(t_front) + in_kelvin(t_back)) / 2.;
+        let lambda = self.thermal_conductivity(temp);
+
+        nu * lambda / thickness
+    }
+}
+
+/// Either a single [`Gas`] or a multi-component [`GasMixture`] filling a
+/// [`crate::cavity::Cavity`]—the common interface [`crate::cavity::Cavity`]
+/// and [`crate::cavity::Ventilation`] drive, so a cavity built from a
+/// `Construction` (always [`Self::Pure`], since `simple_model`'s gas
+/// specification carries no mole fractions) and one a caller assembles by
+/// hand from a [`GasMixture`] (e.g. a 90/10 argon/air IGU fill) go through
+/// the exact same cavity physics.
+#[derive(Debug, Clone)]
+pub enum CavityFill {
+    /// A single pure gas (e.g. [`AIR`]).
+    Pure(Gas),
+    /// A multi-component mixture, combined at the cavity's current mean
+    /// temperature via [`GasMixture`]'s ISO15099/Wilke–Mason–Saxena mixing
+    /// rules.
+    Mixture(GasMixture),
 }
 
+impl CavityFill {
+    /// Mirrors [`Gas::heat_capacity`]/[`GasMixture::heat_capacity`].
+    pub fn heat_capacity(&self, temp: Float) -> Float {
+        match self {
+            Self::Pure(gas) => gas.heat_capacity(temp),
+            Self::Mixture(mix) => mix.heat_capacity(temp),
+        }
+    }
+
+    /// Mirrors [`Gas::density`]/[`GasMixture::density`].
+    pub fn density(&self, temp: Float) -> Float {
+        match self {
+            Self::Pure(gas) => gas.density(temp),
+            Self::Mixture(mix) => mix.density(temp),
+        }
+    }
 
+    /// Mirrors [`Gas::cavity_convection_at_pressure`]/[`GasMixture::cavity_convection_at_pressure`].
+    pub fn cavity_convection_at_pressure(
+        &self,
+        height: Float,
+        thickness: Float,
+        gamma: Float,
+        t_front: Float,
+        t_back: Float,
+        pressure: Float,
+    ) -> Float {
+        match self {
+            Self::Pure(gas) => {
+                gas.cavity_convection_at_pressure(height, thickness, gamma, t_front, t_back, pressure)
+            }
+            Self::Mixture(mix) => {
+                mix.cavity_convection_at_pressure(height, thickness, gamma, t_front, t_back, pressure)
+            }
+        }
+    }
+}
 
+impl From<Gas> for CavityFill {
+    fn from(gas: Gas) -> Self {
+        Self::Pure(gas)
+    }
+}
 
+impl From<GasMixture> for CavityFill {
+    fn from(mix: GasMixture) -> Self {
+        Self::Mixture(mix)
+    }
+}
 
 /// Transforms C into K
 fn in_kelvin(t: Float) -> Float {
@@ -339,6 +981,56 @@ mod testing {
         Ok(())
     }
 
+    #[test]
+    fn test_gas_property_linear_matches_polynomial() {
+        let prop = GasProperty::new(vec![2.873e-3, 7.760e-5], PropertyBasis::Linear);
+        let temp = 293.15;
+        check_value(AIR.thermal_conductivity(temp), prop.eval(temp)).unwrap();
+    }
+
+    #[test]
+    fn test_gas_property_log_exp_round_trips_exact_fit() {
+        // Values that are exactly exp(a + b*ln(T)) should be fit with
+        // (near) zero residual in the LogExp basis.
+        let temps: Vec<Float> = vec![250., 300., 350., 400., 500.];
+        let a = -2.0;
+        let b = 0.8;
+        let values: Vec<Float> = temps.iter().map(|t| (a + b * t.ln()).exp()).collect();
+
+        let prop = GasProperty::fit_from_points(&temps, &values, 1, PropertyBasis::LogExp).unwrap();
+        for (t, v) in temps.iter().zip(values.iter()) {
+            check_value(*v, prop.eval(*t)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_gas_property_fit_from_points_rejects_too_few_points() {
+        let temps = vec![300.0, 350.0];
+        let values = vec![0.02, 0.03];
+        assert!(GasProperty::fit_from_points(&temps, &values, 3, PropertyBasis::Linear).is_err());
+    }
+
+    #[test]
+    fn test_gas_property_sutherland_matches_mu0_at_t0() {
+        // At temp == t0, (T/T0)^1.5 == 1 and (T0+S)/(T+S) == 1, so eval()
+        // should return exactly mu0.
+        let prop = GasProperty::sutherland(1.716e-5, 273.15, 110.4);
+        check_value(1.716e-5, prop.eval(273.15)).unwrap();
+    }
+
+    #[test]
+    fn test_gas_property_sutherland_increases_with_temperature() {
+        let prop = GasProperty::sutherland(1.716e-5, 273.15, 110.4);
+        assert!(prop.eval(373.15) > prop.eval(273.15));
+    }
+
+    #[test]
+    fn test_gas_property_fit_from_points_rejects_sutherland_basis() {
+        let temps = vec![300.0, 350.0, 400.0];
+        let values = vec![0.02, 0.03, 0.04];
+        assert!(GasProperty::fit_from_points(&temps, &values, 1, PropertyBasis::Sutherland).is_err());
+    }
+
     #[test]
     fn test_thermal_conductivity() {
         check_value(
@@ -491,6 +1183,179 @@ mod testing {
         assert!((1.2041 - rho).abs() < 1e-3);
     }
 
+    #[test]
+    fn test_prandtl_and_reynolds() {
+        let gas = crate::gas::AIR;
+        let temp = 293.15;
+
+        let pr = gas.prandtl(temp);
+        // Air's Prandtl number is well known to sit close to 0.7 near room
+        // temperature.
+        assert!((pr - 0.7).abs() < 0.1, "unexpected Prandtl number: {pr}");
+
+        let re = gas.reynolds(temp, 5.0, 1.0);
+        let expected = gas.density(temp) * 5.0 * 1.0 / gas.dynamic_viscosity(temp);
+        check_value(expected, re).unwrap();
+    }
+
+    #[test]
+    fn test_forced_convection_increases_with_wind_speed() {
+        let gas = crate::gas::AIR;
+        let temp = 293.15;
+        let length = 1.5;
+
+        let calm = gas.forced_convection(temp, 1.0, length);
+        let windy = gas.forced_convection(temp, 10.0, length);
+        assert!(
+            windy > calm,
+            "expected higher wind speed to raise the forced-convection coefficient: {calm} vs {windy}"
+        );
+    }
+
+    #[test]
+    fn test_from_coefficients_matches_hardcoded_gas() {
+        // AIR's own coefficients, rebuilt through from_coefficients, should
+        // behave identically to the hardcoded const.
+        let rebuilt = Gas::from_coefficients(
+            vec![2.873e-3, 7.760e-5],
+            vec![3.723e-6, 4.94e-8],
+            vec![1002.7370, 1.2324e-2],
+            28.97,
+        );
+        let temp = 293.15;
+        check_value(AIR.thermal_conductivity(temp), rebuilt.thermal_conductivity(temp)).unwrap();
+        check_value(AIR.dynamic_viscosity(temp), rebuilt.dynamic_viscosity(temp)).unwrap();
+        check_value(AIR.heat_capacity(temp), rebuilt.heat_capacity(temp)).unwrap();
+        check_value(AIR.mass(), rebuilt.mass()).unwrap();
+    }
+
+    #[test]
+    fn test_gas_library_round_trips_through_json() {
+        let records = vec![
+            GasRecord {
+                name: "Air".to_string(),
+                thermal_conductivity: vec![2.873e-3, 7.760e-5],
+                dynamic_viscosity: vec![3.723e-6, 4.94e-8],
+                heat_capacity: vec![1002.7370, 1.2324e-2],
+                mass: 28.97,
+            },
+            GasRecord {
+                name: "SF6".to_string(),
+                thermal_conductivity: vec![1.0e-3],
+                dynamic_viscosity: vec![1.5e-5],
+                heat_capacity: vec![665.0],
+                mass: 146.06,
+            },
+        ];
+
+        let mut buf = Vec::new();
+        write_gas_library(&records, &mut buf).unwrap();
+        let read_back = load_gas_library(buf.as_slice()).unwrap();
+
+        assert_eq!(read_back.len(), records.len());
+        assert_eq!(read_back[1].name, "SF6");
+        check_value(records[1].mass, read_back[1].to_gas().mass()).unwrap();
+        check_value(
+            records[1].to_gas().heat_capacity(300.0),
+            read_back[1].to_gas().heat_capacity(300.0),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_density_scales_linearly_with_pressure() {
+        let gas = crate::gas::AIR;
+        let temp = 293.15;
+        let half = gas.density_at_pressure(temp, STANDARD_PRESSURE / 2.0);
+        check_value(gas.density(temp) / 2.0, half).unwrap();
+    }
+
+    #[test]
+    fn test_lower_pressure_reduces_cavity_convection() {
+        // Ra ∝ rho^2, so a cavity filled below standard atmospheric
+        // pressure (e.g. at altitude) should see a lower Rayleigh number
+        // and, in turn, no more convective transfer than at sea level.
+        let gas = crate::gas::ARGON;
+        let (height, thickness, gamma) = (1.0, 0.012, (90.0 as Float).to_radians());
+
+        let sea_level = gas.cavity_convection(height, thickness, gamma, 15.0, -5.0);
+        let altitude = gas.cavity_convection_at_pressure(
+            height,
+            thickness,
+            gamma,
+            15.0,
+            -5.0,
+            STANDARD_PRESSURE * 0.83, // roughly Denver's elevation
+        );
+        assert!(
+            altitude <= sea_level,
+            "expected reduced-pressure convection ({altitude}) <= sea level ({sea_level})"
+        );
+    }
+
+    #[test]
+    fn test_gas_mixture_rejects_bad_fractions() {
+        assert!(GasMixture::new(vec![(AIR, 0.5), (ARGON, 0.3)]).is_err());
+        assert!(GasMixture::new(Vec::new()).is_err());
+        assert!(GasMixture::new(vec![(AIR, 0.5), (ARGON, 0.5)]).is_ok());
+    }
+
+    #[test]
+    fn test_gas_mixture_single_component_matches_pure_gas() {
+        let mix = GasMixture::new(vec![(ARGON, 1.0)]).unwrap();
+        let temp = 283.15;
+
+        check_value(ARGON.mass(), mix.mass()).unwrap();
+        check_value(ARGON.density(temp), mix.density(temp)).unwrap();
+        check_value(ARGON.heat_capacity(temp), mix.heat_capacity(temp)).unwrap();
+        check_value(ARGON.dynamic_viscosity(temp), mix.dynamic_viscosity(temp)).unwrap();
+        check_value(
+            ARGON.thermal_conductivity(temp),
+            mix.thermal_conductivity(temp),
+        )
+        .unwrap();
+        check_value(
+            ARGON.cavity_convection(1.0, 0.012, (90.0 as Float).to_radians(), 15.0, -5.0),
+            mix.cavity_convection(1.0, 0.012, (90.0 as Float).to_radians(), 15.0, -5.0),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_gas_mixture_properties_between_components() {
+        // 90% argon / 10% air, a common IGU fill.
+        let mix = GasMixture::new(vec![(ARGON, 0.9), (AIR, 0.1)]).unwrap();
+        let temp = 283.15;
+
+        let lo = ARGON.mass().min(AIR.mass());
+        let hi = ARGON.mass().max(AIR.mass());
+        assert!(mix.mass() >= lo && mix.mass() <= hi);
+
+        let lo = ARGON
+            .thermal_conductivity(temp)
+            .min(AIR.thermal_conductivity(temp));
+        let hi = ARGON
+            .thermal_conductivity(temp)
+            .max(AIR.thermal_conductivity(temp));
+        assert!(mix.thermal_conductivity(temp) >= lo && mix.thermal_conductivity(temp) <= hi);
+
+        let lo = ARGON
+            .dynamic_viscosity(temp)
+            .min(AIR.dynamic_viscosity(temp));
+        let hi = ARGON
+            .dynamic_viscosity(temp)
+            .max(AIR.dynamic_viscosity(temp));
+        assert!(mix.dynamic_viscosity(temp) >= lo && mix.dynamic_viscosity(temp) <= hi);
+    }
+
+    #[test]
+    fn test_gas_mixture_density_scales_linearly_with_pressure() {
+        let mix = GasMixture::new(vec![(ARGON, 0.9), (AIR, 0.1)]).unwrap();
+        let temp = 283.15;
+        let half = mix.density_at_pressure(temp, STANDARD_PRESSURE / 2.0);
+        check_value(mix.density(temp) / 2.0, half).unwrap();
+    }
+
     #[test]
     fn test_nusselt() {
         // https://github.com/LBNL-ETA/Windows-CalcEngine/blob/main/src/Tarcog/tst/units/NusseltNumber.unit.cpp
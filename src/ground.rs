@@ -0,0 +1,167 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Undisturbed-ground boundary temperature, via the Kusuda–Achenbach
+//! analytical model.
+
+use crate::Float;
+use calendar::Date;
+
+/// The annual period used by [`GroundTemperatureModel`]: 365 days, in
+/// seconds.
+pub const ANNUAL_PERIOD: Float = 365.0 * 24.0 * 60.0 * 60.0;
+
+/// The undisturbed-ground annual temperature statistics and thermal
+/// properties needed to evaluate the Kusuda–Achenbach model: the annual
+/// mean, amplitude and phase lag of the site's dry-bulb temperature
+/// (see [`Self::from_dry_bulb_series`]), plus the ground's thermal
+/// diffusivity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GroundTemperatureModel {
+    /// Ground thermal diffusivity ($`m^2/s`$)
+    pub diffusivity: Float,
+    /// Annual mean dry-bulb temperature (C)
+    pub t_mean: Float,
+    /// Annual dry-bulb temperature amplitude—half the peak-to-trough
+    /// swing (C)
+    pub t_amp: Float,
+    /// Seconds-of-year at which the coldest dry-bulb temperature occurs
+    /// (the model's phase lag, $`t_{lag}`$)
+    pub t_lag: Float,
+}
+
+impl GroundTemperatureModel {
+    /// Derives [`Self::t_mean`], [`Self::t_amp`] and [`Self::t_lag`] from
+    /// a year-long series of dry-bulb temperatures sampled at even
+    /// intervals over the year (e.g. hourly, from a weather file),
+    /// keeping `diffusivity` as given.
+    pub fn from_dry_bulb_series(dry_bulb: &[Float], diffusivity: Float) -> Self {
+        assert!(
+            !dry_bulb.is_empty(),
+            "Cannot derive a GroundTemperatureModel from an empty dry-bulb series"
+        );
+        let n = dry_bulb.len();
+        let t_mean = dry_bulb.iter().sum::<Float>() / n as Float;
+
+        let mut min_i = 0;
+        let mut t_min = dry_bulb[0];
+        let mut t_max = dry_bulb[0];
+        for (i, &t) in dry_bulb.iter().enumerate() {
+            if t < t_min {
+                t_min = t;
+                min_i = i;
+            }
+            if t > t_max {
+                t_max = t;
+            }
+        }
+        let t_amp = (t_max - t_min) / 2.0;
+        let t_lag = min_i as Float / n as Float * ANNUAL_PERIOD;
+
+        Self {
+            diffusivity,
+            t_mean,
+            t_amp,
+            t_lag,
+        }
+    }
+
+    /// The undisturbed-ground temperature (C) at depth `z` (m) and time
+    /// `t` (seconds elapsed since the start of the year, see
+    /// [`seconds_of_year`]):
+    ///
+    /// ```math
+    /// T(z,t) = T_{mean} - T_{amp}\,e^{-z\sqrt{\pi/(\alpha P)}}\cos\left(\frac{2\pi}{P}\left(t - t_{lag} - \frac{z}{2}\sqrt{\frac{P}{\pi\alpha}}\right)\right)
+    /// ```
+    ///
+    /// where $`P`$ is [`ANNUAL_PERIOD`] and $`\alpha`$ is
+    /// [`Self::diffusivity`].
+    pub fn temperature(&self, z: Float, t: Float) -> Float {
+        let p = ANNUAL_PERIOD;
+        let decay = (-z * (crate::PI / (self.diffusivity * p)).sqrt()).exp();
+        let phase_lag = z / 2.0 * (p / (crate::PI * self.diffusivity)).sqrt();
+        self.t_mean - self.t_amp * decay * (2.0 * crate::PI / p * (t - self.t_lag - phase_lag)).cos()
+    }
+}
+
+impl std::default::Default for GroundTemperatureModel {
+    /// A generic mid-latitude soil, used until
+    /// [`GroundTemperatureModel::from_dry_bulb_series`] is called with the
+    /// actual weather file for the site.
+    fn default() -> Self {
+        Self {
+            diffusivity: 0.5e-6,
+            t_mean: 10.0,
+            t_amp: 10.0,
+            t_lag: 45.0 * 24.0 * 60.0 * 60.0,
+        }
+    }
+}
+
+/// Converts a [`Date`] into seconds elapsed since the start of the year
+/// (Jan 1st, 00:00), i.e. `t` in [`GroundTemperatureModel::temperature`].
+/// Uses a fixed, non-leap 365-day calendar, matching [`ANNUAL_PERIOD`].
+pub fn seconds_of_year(date: &Date) -> Float {
+    const DAYS_BEFORE_MONTH: [Float; 12] = [
+        0., 31., 59., 90., 120., 151., 181., 212., 243., 273., 304., 334.,
+    ];
+    let month_index = (date.month as usize).saturating_sub(1).min(11);
+    let day_of_year = DAYS_BEFORE_MONTH[month_index] + date.day as Float - 1.0;
+    day_of_year * 24.0 * 60.0 * 60.0 + date.hour as Float * 60.0 * 60.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_dry_bulb_series_matches_a_synthetic_sine() {
+        // A perfect sine wave with known mean/amplitude/phase, sampled
+        // hourly over a year, should be recovered (up to the sampling
+        // resolution) by `from_dry_bulb_series`.
+        let mean = 10.0;
+        let amp = 8.0;
+        let lag = 45.0 * 24.0 * 60.0 * 60.0; // coldest 45 days into the year
+        let n = 8760;
+        let series: Vec<Float> = (0..n)
+            .map(|i| {
+                let t = i as Float / n as Float * ANNUAL_PERIOD;
+                mean - amp * (2.0 * crate::PI / ANNUAL_PERIOD * (t - lag)).cos()
+            })
+            .collect();
+        let model = GroundTemperatureModel::from_dry_bulb_series(&series, 0.05e-5);
+        assert!((model.t_mean - mean).abs() < 0.01);
+        assert!((model.t_amp - amp).abs() < 0.01);
+        assert!((model.t_lag - lag).abs() < ANNUAL_PERIOD / n as Float);
+    }
+
+    #[test]
+    fn deep_ground_temperature_tends_to_the_mean() {
+        let model = GroundTemperatureModel {
+            diffusivity: 0.05e-5,
+            t_mean: 12.0,
+            t_amp: 10.0,
+            t_lag: 0.0,
+        };
+        let shallow = model.temperature(0.1, 0.0);
+        let deep = model.temperature(50.0, 0.0);
+        assert!((deep - model.t_mean).abs() < (shallow - model.t_mean).abs());
+    }
+}
@@ -18,29 +18,464 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::energy_supply::Fuel;
 use crate::Float;
 use simple_model::hvac::{ElectricHeater, IdealHeaterCooler, HVAC};
 use simple_model::{SimpleModel, SimulationState};
 
+/// The physical characteristics of a wet (hydronic) heat emitter—e.g. a
+/// radiator or an underfloor loop—fed by a central heat source.
+///
+/// Unlike [`IdealHeaterCooler`] and [`ElectricHeater`], whose output is
+/// injected into the space instantaneously, a wet emitter has its own
+/// thermal mass and a non-linear output characteristic (EN 442), so the heat
+/// it actually delivers to the room lags the heat supplied to it by the
+/// heat source.
+#[derive(Debug, Clone, Copy)]
+pub struct WetEmitter {
+    /// The emitter's thermal capacity (water + metal), in $`J/K`$
+    pub c: Float,
+
+    /// The emitter's nominal heat output, in $`W`$, produced at a
+    /// temperature excess of [`Self::dt_nom`]
+    pub q_nom: Float,
+
+    /// The temperature excess $`T_e - T_{room}`$, in $`K`$, at which the
+    /// emitter produces [`Self::q_nom`]
+    pub dt_nom: Float,
+
+    /// The emitter's output exponent. About `1.3` for typical panel
+    /// radiators, closer to `1.1` for underfloor loops.
+    pub n: Float,
+
+    /// The fraction of [`Self::q_nom`]-scaled output delivered to the room
+    /// by convection, with the remainder delivered as long-wave radiant
+    /// exchange with the zone's interior surfaces. Typically around `0.7`
+    /// for panel radiators and `0.3` for underfloor loops, which radiate a
+    /// much larger share of their output.
+    pub frac_convective: Float,
+
+    /// The space this emitter heats
+    pub target_space_index: usize,
+}
+
+impl WetEmitter {
+    /// The emitter's characteristic constant, so that
+    /// $`Q_{out} = K (T_e-T_{room})^n`$
+    fn k(&self) -> Float {
+        self.q_nom / self.dt_nom.powf(self.n)
+    }
+
+    /// The local sensitivity of convective output to room temperature,
+    /// $`-\frac{\partial}{\partial T_{room}}\left(frac_{conv}\cdot K(T_e-T_{room})^n\right) = frac_{conv}\cdot n\cdot K(T_e-T_{room})^{n-1}`$,
+    /// linearized around the emitter's current temperature `t_e` and the
+    /// zone's current `t_room`. Used to fold the emitter's feedback on the
+    /// room it heats into the zone's own `b[i]` term, rather than treating
+    /// this step's delivered output as independent of the zone's (not yet
+    /// known) future temperature. Zero once the emitter is no warmer than
+    /// the room, matching [`Self::derivative`]'s clamp.
+    fn convective_feedback_conductance(&self, t_e: Float, t_room: Float) -> Float {
+        let excess = (t_e - t_room).max(0.0);
+        if excess <= 0.0 {
+            return 0.0;
+        }
+        self.frac_convective * self.n * self.k() * excess.powf(self.n - 1.0)
+    }
+
+    /// The emitter's heat balance $`dT_e/dt = (Q_{in}-Q_{out})/c`$ at a given
+    /// state, returning `(dT_e/dt, Q_out)`. The temperature excess is
+    /// clamped to zero before being raised to the (possibly fractional)
+    /// power `n`, so the emitter never "absorbs" heat from a room that is
+    /// warmer than it is.
+    fn derivative(&self, q_in: Float, t_room: Float, t_e: Float) -> (Float, Float) {
+        let excess = (t_e - t_room).max(0.0);
+        let q_out = self.k() * excess.powf(self.n);
+        ((q_in - q_out) / self.c, q_out)
+    }
+
+    /// A single classic Runge-Kutta-4 step of [`Self::derivative`], returning
+    /// the emitter temperature and the average `Q_out` over the step.
+    fn rk4_step(&self, q_in: Float, t_room: Float, h: Float, t_e: Float) -> (Float, Float) {
+        let (k1, q1) = self.derivative(q_in, t_room, t_e);
+        let (k2, q2) = self.derivative(q_in, t_room, t_e + h / 2. * k1);
+        let (k3, q3) = self.derivative(q_in, t_room, t_e + h / 2. * k2);
+        let (k4, q4) = self.derivative(q_in, t_room, t_e + h * k3);
+
+        let next_t_e = t_e + h / 6. * (k1 + 2. * k2 + 2. * k3 + k4);
+        let avg_q_out = (q1 + 2. * q2 + 2. * q3 + q4) / 6.;
+        (next_t_e, avg_q_out)
+    }
+
+    /// Integrates the emitter's temperature `t_e` (in place) over `dt`
+    /// seconds, given a heat input `q_in` (in $`W`$, assumed constant over
+    /// the step) and the room temperature `t_room` (also assumed constant),
+    /// and returns the heat actually delivered to the space—the integral of
+    /// `Q_out` over the step, divided by `dt` to give an average `W`.
+    ///
+    /// The ramp-up and cool-down regions of this ODE are stiff relative to a
+    /// single building-simulation timestep, so `dt` is subdivided
+    /// adaptively: each candidate substep is taken once as a whole RK4 step
+    /// and once as two half RK4 steps, and is only accepted once the two
+    /// estimates agree within tolerance (step-doubling, as in an embedded
+    /// Runge-Kutta-Fehlberg scheme).
+    fn march(&self, q_in: Float, t_room: Float, dt: Float, t_e: &mut Float) -> Float {
+        const TOL: Float = 1e-4;
+        const MIN_H: Float = 1e-3;
+        const MAX_SUBSTEPS: usize = 2048;
+
+        let mut remaining = dt;
+        let mut h = dt;
+        let mut delivered_energy = 0.0;
+        let mut substeps = 0;
+
+        while remaining > 1e-9 && substeps < MAX_SUBSTEPS {
+            let step = h.min(remaining);
+
+            let (whole_t_e, _) = self.rk4_step(q_in, t_room, step, *t_e);
+            let (half_t_e, half_q1) = self.rk4_step(q_in, t_room, step / 2., *t_e);
+            let (half_t_e2, half_q2) = self.rk4_step(q_in, t_room, step / 2., half_t_e);
+
+            let error = (whole_t_e - half_t_e2).abs();
+
+            if error <= TOL || step <= MIN_H {
+                *t_e = half_t_e2;
+                delivered_energy += (half_q1 + half_q2) / 2. * step;
+                remaining -= step;
+                substeps += 1;
+                if error <= TOL / 8. {
+                    // Comfortably within tolerance: try a bigger step next time.
+                    h = (step * 1.5).min(dt);
+                }
+            } else {
+                h = step / 2.;
+            }
+        }
+
+        delivered_energy / dt
+    }
+}
+
+/// The physical characteristics of a temperature-dependent heat pump: a
+/// heat source/sink whose delivered power and electrical draw depend on the
+/// instantaneous source and sink temperatures, rather than being fixed like
+/// [`ElectricHeater`].
+#[derive(Debug, Clone, Copy)]
+pub struct HeatPump {
+    /// Efficiency factor applied to the Carnot-bound COP, roughly `0.4-0.5`
+    /// for real equipment.
+    pub eta: Float,
+
+    /// Rated thermal output, in $`W`$, at [`Self::rated_source_temperature`]
+    pub rated_capacity: Float,
+
+    /// The source temperature, in $`C`$, at which [`Self::rated_capacity`] applies
+    pub rated_source_temperature: Float,
+
+    /// Fractional change in capacity per degree the source temperature is
+    /// away from [`Self::rated_source_temperature`] (e.g. `-0.02` means
+    /// capacity drops `2%` per degree the source cools below the rated point)
+    pub capacity_temperature_coefficient: Float,
+
+    /// Below this source temperature (in $`C`$), frost forms on the outdoor
+    /// coil and the unit periodically defrosts, derating its usable output
+    /// by [`Self::defrost_derating`]
+    pub defrost_threshold: Float,
+
+    /// The fraction of the temperature-scaled capacity that remains
+    /// available while defrosting (e.g. `0.7`)
+    pub defrost_derating: Float,
+
+    /// The sink (supply) temperature this heat pump delivers to, in $`C`$
+    /// (e.g. a radiator flow temperature, or the room air for an
+    /// air-to-air unit)
+    pub sink_temperature: Float,
+
+    /// The space this heat pump heats
+    pub target_space_index: usize,
+}
+
+impl HeatPump {
+    /// Caps the Carnot-fraction COP so it stays finite as `T_sink` and
+    /// `T_source` converge.
+    const MAX_COP: Float = 10.0;
+
+    /// The smallest `T_sink - T_source` (in $`K`$) used in the COP
+    /// denominator, guarding against division by (near) zero.
+    const MIN_DT: Float = 0.5;
+
+    /// The Carnot-fraction coefficient of performance
+    /// $`COP = \eta \cdot T_{sink}/(T_{sink}-T_{source})`$, with both
+    /// temperatures in Kelvin, capped at [`Self::MAX_COP`].
+    fn cop(&self, t_source: Float, t_sink: Float) -> Float {
+        let t_source_k = t_source + 273.15;
+        let t_sink_k = t_sink + 273.15;
+        let dt = (t_sink_k - t_source_k).max(Self::MIN_DT);
+        (self.eta * t_sink_k / dt).min(Self::MAX_COP)
+    }
+
+    /// This unit's thermal capacity at `t_source`: [`Self::rated_capacity`]
+    /// scaled by [`Self::capacity_temperature_coefficient`] around
+    /// [`Self::rated_source_temperature`], then derated by
+    /// [`Self::defrost_derating`] if `t_source` is below
+    /// [`Self::defrost_threshold`].
+    fn capacity(&self, t_source: Float) -> Float {
+        let scaled = self.rated_capacity
+            * (1.0
+                + self.capacity_temperature_coefficient
+                    * (t_source - self.rated_source_temperature));
+        let scaled = scaled.max(0.0);
+        if t_source < self.defrost_threshold {
+            scaled * self.defrost_derating
+        } else {
+            scaled
+        }
+    }
+
+    /// Returns `(heat_delivered, electrical_input)`, both in $`W`$, for a
+    /// requested thermal demand `q_demand` and source temperature
+    /// `t_source` (both in the same units as the fields above). Delivered
+    /// heat is capped at this unit's temperature- (and possibly defrost-)
+    /// derated [`Self::capacity`]; the electrical input is the delivered
+    /// heat divided by the instantaneous [`Self::cop`].
+    fn output(&self, q_demand: Float, t_source: Float) -> (Float, Float) {
+        let capacity = self.capacity(t_source);
+        let heat_delivered = q_demand.clamp(0.0, capacity);
+        let cop = self.cop(t_source, self.sink_temperature);
+        let electrical_input = heat_delivered / cop;
+        (heat_delivered, electrical_input)
+    }
+}
+
+/// The physical characteristics of a direct evaporative cooler: supply air
+/// is drawn across a wetted media, dropping its dry-bulb temperature towards
+/// the (outdoor) wet-bulb temperature at some saturation effectiveness.
+///
+/// This crate's `SimulationState` has no zone humidity state, and
+/// [`simple_model`]'s `SyntheticWeather` carries no humidity data either
+/// (only [`crate::psychrometrics`]'s dry-bulb-driven helpers are available),
+/// so this cannot track the moisture this unit actually adds to the zone air
+/// or read back a simulated zone relative humidity. Instead, the zone
+/// relative humidity its RH-driven control responds to is supplied by the
+/// caller through [`ThermalHVACMemory::EvaporativeCooling::rh_room`]—the
+/// same pattern [`ThermalHVACMemory::HeatPump::q_demand`] uses for a
+/// demand a caller updates out of band—rather than being derived from any
+/// state this crate owns.
+#[derive(Debug, Clone, Copy)]
+pub struct EvaporativeCooler {
+    /// Saturation effectiveness $`\eta`$: the fraction of the
+    /// dry-bulb/wet-bulb gap the unit closes,
+    /// $`T_{supply} = T_{in} - \eta (T_{in}-T_{wb})`$. Typically `0.6-0.8`
+    /// for a rigid-media direct evaporative cooler.
+    pub effectiveness: Float,
+
+    /// The design (assumed, since no live humidity data is available)
+    /// outdoor relative humidity, `0-100`, used with the live outdoor
+    /// dry-bulb temperature to derive a wet-bulb temperature via
+    /// [`crate::psychrometrics::wet_bulb_temperature`].
+    pub design_outdoor_rh: Float,
+
+    /// This unit's cooling output, in $`W`$, when active and unthrottled.
+    pub rated_capacity: Float,
+
+    /// The zone dry-bulb temperature, in $`C`$, above which the unit turns
+    /// on.
+    pub dry_bulb_setpoint: Float,
+
+    /// The zone relative humidity, `0-100`, at or above which the unit is
+    /// staged off entirely, regardless of dry-bulb temperature—evaporative
+    /// cooling adds moisture, so it's counter-productive once the zone is
+    /// already humid enough.
+    pub rh_limit: Float,
+
+    /// The band of relative humidity, below [`Self::rh_limit`], over which
+    /// the unit ramps its output down to zero rather than switching off
+    /// abruptly. Zero means an on/off (non-modulating) unit.
+    pub rh_throttle_band: Float,
+
+    /// The space this cooler serves
+    pub target_space_index: usize,
+}
+
+impl EvaporativeCooler {
+    /// The supply air temperature delivered for an entering (outdoor) air
+    /// temperature `t_in` and wet-bulb temperature `t_wb`:
+    /// $`T_{supply} = T_{in} - \eta (T_{in}-T_{wb})`$.
+    pub fn supply_temperature(&self, t_in: Float, t_wb: Float) -> Float {
+        t_in - self.effectiveness * (t_in - t_wb)
+    }
+
+    /// The fraction (`0-1`) of [`Self::rated_capacity`] the unit delivers
+    /// for a given zone dry-bulb temperature `t_room` and relative humidity
+    /// `rh_room`: zero below [`Self::dry_bulb_setpoint`] or at/above
+    /// [`Self::rh_limit`], ramping linearly to full output over the last
+    /// [`Self::rh_throttle_band`] of headroom below the limit.
+    pub fn modulation_fraction(&self, t_room: Float, rh_room: Float) -> Float {
+        if t_room <= self.dry_bulb_setpoint || rh_room >= self.rh_limit {
+            return 0.0;
+        }
+        if self.rh_throttle_band <= 0.0 {
+            return 1.0;
+        }
+        let headroom = self.rh_limit - rh_room;
+        (headroom / self.rh_throttle_band).clamp(0.0, 1.0)
+    }
+
+    /// The cooling power delivered to [`Self::target_space_index`], in
+    /// $`W`$ (negative, i.e. a cooling effect on the zone air balance), for
+    /// outdoor dry-bulb temperature `t_out`, zone dry-bulb temperature
+    /// `t_room` and zone relative humidity `rh_room`. Zero whenever the
+    /// unit's modulation fraction is zero, or whenever the wet-bulb-limited
+    /// supply temperature it could achieve is not actually below the room
+    /// (e.g. very humid outdoor air), since running the unit would then do
+    /// nothing useful.
+    pub fn cooling_output(&self, t_room: Float, t_out: Float, rh_room: Float) -> Float {
+        let fraction = self.modulation_fraction(t_room, rh_room);
+        if fraction <= 0.0 {
+            return 0.0;
+        }
+        let t_wb = crate::psychrometrics::wet_bulb_temperature(t_out, self.design_outdoor_rh);
+        let t_supply = self.supply_temperature(t_out, t_wb);
+        if t_supply >= t_room {
+            return 0.0;
+        }
+        -fraction * self.rated_capacity
+    }
+}
+
 /// An HVAC element from the point of view of the thermal
 /// model.
 pub enum ThermalHVAC {
     /// An ideal heater cooler
     IdealHeaterCooler{
         /// The parent HVAC
-        parent: IdealHeaterCooler, 
-        
+        parent: IdealHeaterCooler,
+
         /// The space this HVAC is heating/cooling
         target_spaces: Vec<usize>},
 
     /// Electric heater.
     ElectricHeater{
         /// The parent HVAC
-        parent: ElectricHeater, 
+        parent: ElectricHeater,
 
         /// The space this heater is heating
         target_space_index: usize
     },
+
+    /// A hydronic (wet) distribution emitter—e.g. a radiator fed by a
+    /// boiler—with its own thermal mass, whose output lags the heat
+    /// supplied to it (see [`WetEmitter`]).
+    ///
+    /// `simple_model`'s `HVAC` enum has no corresponding kind yet, so—unlike
+    /// the other variants—this one cannot be built through [`Self::from`];
+    /// use [`Self::new_wet_distribution`] instead.
+    WetDistribution {
+        /// The emitter's physical characteristics and target space
+        emitter: WetEmitter,
+    },
+
+    /// A temperature-dependent heat pump (see [`HeatPump`]), whose delivered
+    /// power and electrical consumption depend on the source and sink
+    /// temperatures rather than being fixed.
+    ///
+    /// `simple_model`'s `HVAC` enum has no corresponding kind yet, so—like
+    /// [`Self::WetDistribution`]—this one cannot be built through
+    /// [`Self::from`]; use [`Self::new_heat_pump`] instead.
+    HeatPump {
+        /// The heat pump's physical characteristics and target space
+        heat_pump: HeatPump,
+    },
+
+    /// A direct evaporative cooler (see [`EvaporativeCooler`]).
+    ///
+    /// `simple_model`'s `HVAC` enum has no corresponding kind yet, so—like
+    /// [`Self::WetDistribution`] and [`Self::HeatPump`]—this one cannot be
+    /// built through [`Self::from`]; use [`Self::new_evaporative_cooling`]
+    /// instead.
+    EvaporativeCooling {
+        /// The cooler's physical characteristics and target space
+        cooler: EvaporativeCooler,
+    },
+
+    /// An arbitrary, caller-supplied time/temperature-driven heat source
+    /// (e.g. a scripted setpoint ramp or an internal-gain profile) rather
+    /// than one of this module's named control types.
+    ///
+    /// `simple_model`'s `HVAC` enum has no corresponding kind, so—like
+    /// [`Self::WetDistribution`]—this one cannot be built through
+    /// [`Self::from`]; use [`Self::new_function_source`] instead.
+    FunctionSource {
+        /// `(time_seconds, node_temperature) -> power (W)`, evaluated once
+        /// per call to [`Self::calc_cooling_heating_power`] against that
+        /// step's `time_seconds` and the target space's current
+        /// temperature, so ramps/step changes in the profile land on the
+        /// step they're scheduled for rather than being interpolated
+        /// mid-step. Positive is heating, negative is cooling.
+        power: Box<dyn Fn(Float, Float) -> Float>,
+        /// The space this source feeds.
+        target_space_index: usize,
+        /// The maximum magnitude (W) this source may inject in either
+        /// direction: `power`'s output is clamped to
+        /// `[-max_capacity, max_capacity]` before being delivered, so it
+        /// composes with this module's other capacity-limited elements
+        /// (e.g. [`HeatPump::rated_capacity`]) instead of letting an
+        /// arbitrary function drive unlimited power into a space.
+        max_capacity: Float,
+    },
+}
+
+/// The per-instance mutable state of a [`ThermalHVAC`], allocated once by
+/// [`crate::model::ThermalModel::allocate_memory`] and threaded through
+/// [`ThermalHVAC::calc_cooling_heating_power`] on every step.
+pub enum ThermalHVACMemory {
+    /// No state to keep: [`ThermalHVAC::IdealHeaterCooler`] and
+    /// [`ThermalHVAC::ElectricHeater`] inject their power instantaneously.
+    None,
+
+    /// The state of a [`ThermalHVAC::WetDistribution`]
+    WetDistribution {
+        /// The emitter's current temperature, in $`C`$
+        t_e: Float,
+
+        /// The heat currently being supplied to the emitter by its heat
+        /// source, in $`W`$. Defaults to `q_nom` (i.e., full output) on
+        /// allocation; a caller driving an actual modulating heat source
+        /// should update this before marching.
+        q_in: Float,
+
+        /// The radiant share (`1 - frac_convective`) of the heat delivered
+        /// on the last call to [`ThermalHVAC::calc_cooling_heating_power`],
+        /// in $`W`$—not yet consumed by the zone air term, but kept here so
+        /// an interior radiant-exchange pass (see the companion MRT
+        /// request) can pick it up.
+        radiant_output: Float,
+    },
+
+    /// The state of a [`ThermalHVAC::HeatPump`]
+    HeatPump {
+        /// The thermal demand requested from the heat pump, in $`W`$.
+        /// Defaults to the unit's rated capacity (i.e., full output) on
+        /// allocation; a caller driving an actual thermostat/controller
+        /// should update this before marching.
+        q_demand: Float,
+
+        /// The electrical input drawn to produce the heat delivered on the
+        /// last call to [`ThermalHVAC::calc_cooling_heating_power`], in
+        /// $`W`$—kept here so downstream energy accounting can separate
+        /// delivered heat (returned directly) from purchased electricity
+        /// (read from this field).
+        electrical_input: Float,
+    },
+
+    /// The state of a [`ThermalHVAC::EvaporativeCooling`]
+    EvaporativeCooling {
+        /// The zone relative humidity (`0-100`) its RH-driven control
+        /// responds to. Defaults to `0.0` (i.e. unconstrained by humidity)
+        /// on allocation, since this crate has no zone humidity state of
+        /// its own to initialize it from; a caller driving the unit's
+        /// RH-limiting control should update this before marching.
+        rh_room: Float,
+    },
 }
 
 
@@ -81,12 +516,189 @@ impl ThermalHVAC {
         }
     }
 
-    /// Retrieves a `Vec<(usize, Float)>` containing the amount of heat (the `Float` in W) going into
-    /// each space (of index `usize`)
+    /// Builds a [`ThermalHVAC::WetDistribution`] directly from a [`WetEmitter`].
+    ///
+    /// `simple_model`'s `HVAC` enum has no wet-distribution kind yet, so—unlike
+    /// [`Self::from`]—this cannot be driven from a `SimpleModel`; it exists so
+    /// the emitter physics can be exercised ahead of that upstream support
+    /// landing.
+    pub fn new_wet_distribution(emitter: WetEmitter) -> Self {
+        Self::WetDistribution { emitter }
+    }
+
+    /// Builds a [`ThermalHVAC::HeatPump`] directly from a [`HeatPump`].
+    ///
+    /// `simple_model`'s `HVAC` enum has no heat-pump kind yet, so—like
+    /// [`Self::new_wet_distribution`]—this cannot be driven from a
+    /// `SimpleModel`; it exists so the COP/capacity model can be exercised
+    /// ahead of that upstream support landing.
+    pub fn new_heat_pump(heat_pump: HeatPump) -> Self {
+        Self::HeatPump { heat_pump }
+    }
+
+    /// Builds a [`ThermalHVAC::EvaporativeCooling`] directly from an
+    /// [`EvaporativeCooler`].
+    ///
+    /// `simple_model`'s `HVAC` enum has no evaporative-cooling kind yet,
+    /// so—like [`Self::new_wet_distribution`] and [`Self::new_heat_pump`]—this
+    /// cannot be driven from a `SimpleModel`; it exists so the unit's
+    /// physics can be exercised ahead of that upstream support landing.
+    pub fn new_evaporative_cooling(cooler: EvaporativeCooler) -> Self {
+        Self::EvaporativeCooling { cooler }
+    }
+
+    /// Builds a [`ThermalHVAC::FunctionSource`] directly from a
+    /// time/temperature power function, its target space and its maximum
+    /// capacity (W).
+    ///
+    /// `simple_model`'s `HVAC` enum has no corresponding kind, so—like
+    /// [`Self::new_wet_distribution`]—this cannot be driven from a
+    /// `SimpleModel`; it exists for scripted setpoints/gain profiles that
+    /// don't warrant a new named control type of their own.
+    pub fn new_function_source(
+        power: Box<dyn Fn(Float, Float) -> Float>,
+        target_space_index: usize,
+        max_capacity: Float,
+    ) -> Self {
+        Self::FunctionSource {
+            power,
+            target_space_index,
+            max_capacity,
+        }
+    }
+
+    /// The fuel this HVAC element draws on to produce its output—used by
+    /// [`crate::energy_supply::EnergySupply`] to route its demand into the
+    /// right account.
+    ///
+    /// [`Self::IdealHeaterCooler`], [`Self::ElectricHeater`],
+    /// [`Self::HeatPump`], [`Self::EvaporativeCooling`] and
+    /// [`Self::FunctionSource`] all draw [`Fuel::Electricity`];
+    /// [`Self::WetDistribution`] is assumed fed by a gas boiler, drawing
+    /// [`Fuel::Gas`].
+    pub fn fuel(&self) -> Fuel {
+        match self {
+            Self::IdealHeaterCooler { .. }
+            | Self::ElectricHeater { .. }
+            | Self::HeatPump { .. }
+            | Self::EvaporativeCooling { .. }
+            | Self::FunctionSource { .. } => Fuel::Electricity,
+            Self::WetDistribution { .. } => Fuel::Gas,
+        }
+    }
+
+    /// The power (in $`W`$) this HVAC element drew from [`Self::fuel`] to
+    /// produce the heat returned by the last call to
+    /// [`Self::calc_cooling_heating_power`] (which must have been given this
+    /// same `memory`), given the `(target_space_index, heat)` pairs of that
+    /// call's return value as `delivered`.
+    ///
+    /// [`Self::IdealHeaterCooler`] and [`Self::ElectricHeater`] are treated
+    /// as resistive (COP = 1), so their demand equals the heat they
+    /// delivered; [`Self::HeatPump`]'s electrical draw is tracked separately
+    /// in [`ThermalHVACMemory::HeatPump`] since its COP generally isn't 1;
+    /// and [`Self::WetDistribution`]'s gas demand is the heat supplied to
+    /// its emitter (`q_in`), not the (lagged) heat it delivered to the room.
+    pub fn fuel_demand(
+        &self,
+        memory: &ThermalHVACMemory,
+        delivered: &[(usize, Float)],
+    ) -> Result<Float, String> {
+        match self {
+            Self::IdealHeaterCooler { .. }
+            | Self::ElectricHeater { .. }
+            | Self::FunctionSource { .. } => {
+                Ok(delivered.iter().map(|(_, power)| power).sum())
+            }
+            // Treated as resistive (COP = 1) like the electric devices
+            // above; `delivered` is negative (a cooling effect), so its
+            // magnitude is the electrical draw.
+            Self::EvaporativeCooling { .. } => {
+                Ok(delivered.iter().map(|(_, power)| power.abs()).sum())
+            }
+            Self::WetDistribution { .. } => match memory {
+                ThermalHVACMemory::WetDistribution { q_in, .. } => Ok(*q_in),
+                _ => Err(
+                    "Tried to compute the fuel demand of a WetDistribution HVAC with memory allocated for a different HVAC kind".to_string()
+                ),
+            },
+            Self::HeatPump { .. } => match memory {
+                ThermalHVACMemory::HeatPump { electrical_input, .. } => Ok(*electrical_input),
+                _ => Err(
+                    "Tried to compute the fuel demand of a HeatPump HVAC with memory allocated for a different HVAC kind".to_string()
+                ),
+            },
+        }
+    }
+
+    /// The radiant share (in $`W`$) of this HVAC element's last delivered
+    /// output that has not yet been fed into the zone air term, together
+    /// with the space it should be distributed onto—the companion to the
+    /// convective share already returned by
+    /// [`Self::calc_cooling_heating_power`]. Only [`Self::WetDistribution`]
+    /// currently splits its output this way; every other variant delivers
+    /// its output 100% convectively, so this returns `None` for them.
+    pub fn radiant_gain(&self, memory: &ThermalHVACMemory) -> Option<(usize, Float)> {
+        match (self, memory) {
+            (
+                Self::WetDistribution { emitter },
+                ThermalHVACMemory::WetDistribution { radiant_output, .. },
+            ) => Some((emitter.target_space_index, *radiant_output)),
+            _ => None,
+        }
+    }
+
+    /// Allocates this HVAC's per-instance mutable state (see [`ThermalHVACMemory`])
+    pub fn allocate_memory(&self) -> ThermalHVACMemory {
+        match self {
+            Self::WetDistribution { emitter } => ThermalHVACMemory::WetDistribution {
+                t_e: 20.0,
+                q_in: emitter.q_nom,
+                radiant_output: 0.0,
+            },
+            Self::HeatPump { heat_pump } => ThermalHVACMemory::HeatPump {
+                q_demand: heat_pump.rated_capacity,
+                electrical_input: 0.0,
+            },
+            Self::EvaporativeCooling { .. } => ThermalHVACMemory::EvaporativeCooling { rh_room: 0.0 },
+            Self::IdealHeaterCooler { .. }
+            | Self::ElectricHeater { .. }
+            | Self::FunctionSource { .. } => ThermalHVACMemory::None,
+        }
+    }
+
+    /// Retrieves a `Vec<(usize, Float, Float)>` containing, for each space
+    /// (of index `usize`) this HVAC feeds: the amount of heat (`Float`, in
+    /// W) going into it this step, and the local conductance (`Float`, in
+    /// W/K) of that heat's feedback on the space's own future temperature—
+    /// zero for every variant except [`Self::WetDistribution`], whose
+    /// convective output falls off as the room warms (see
+    /// [`WetEmitter::convective_feedback_conductance`]); the caller folds
+    /// this into the zone's own `b[i]` rather than treating the emitter's
+    /// output as independent of the (not yet known) future room
+    /// temperature. `dt` is the length, in seconds, of the step being
+    /// marched, `t_room` holds the current dry bulb temperature of every
+    /// zone in the model (indexed like
+    /// [`crate::model::ThermalModel::zones`]), and `t_out` is the current
+    /// outdoor dry bulb temperature. `t_room` is used by
+    /// [`Self::WetDistribution`], which (unlike the other variants) needs to
+    /// integrate its emitter's temperature over the step, and by
+    /// [`Self::EvaporativeCooling`] and [`Self::FunctionSource`]; `t_out` is
+    /// used by [`Self::HeatPump`], as the source temperature of its COP and
+    /// capacity model, and by [`Self::EvaporativeCooling`], as its entering
+    /// air temperature. `time_seconds` (seconds since the simulation's
+    /// start) is only used by [`Self::FunctionSource`], evaluated once at
+    /// the start of the step against its current value rather than
+    /// interpolated within it.
     pub fn calc_cooling_heating_power(
-        &self,                
+        &self,
         state: &SimulationState,
-    ) -> Result<Vec<(usize, Float)>, String> {
+        dt: Float,
+        t_room: &[Float],
+        t_out: Float,
+        time_seconds: Float,
+        memory: &mut ThermalHVACMemory,
+    ) -> Result<Vec<(usize, Float, Float)>, String> {
         match self {
             Self::IdealHeaterCooler{parent, target_spaces} => {
                 let mut ret = Vec::with_capacity(target_spaces.len());
@@ -99,7 +711,7 @@ impl ThermalHVAC {
                             Some(v)=>v,
                             None=> return Err(format!("Could not get Heating/Cooling consumption if IdealHeaterCooler called '{}'", parent.name()))
                         };
-                    ret.push((*index, consumption_power));
+                    ret.push((*index, consumption_power, 0.0));
                 }
                 Ok(ret)
             }
@@ -107,16 +719,156 @@ impl ThermalHVAC {
                 // let a = &**system;
                 // let system = cast_hvac::<ElectricHeater>(a).unwrap();
                 let mut ret = Vec::with_capacity(1);
-                if let Ok(_space) = parent.target_space() {                    
+                if let Ok(_space) = parent.target_space() {
                     let consumption_power = match parent
                         .heating_cooling_consumption(state){
                             Some(v)=>v,
                             None => return Err(format!("Could not get Heating consumption if ElectricHeater called '{}'", parent.name()))
-                        };                        
-                    ret.push((*target_space_index, consumption_power))
+                        };
+                    ret.push((*target_space_index, consumption_power, 0.0))
                 }
                 Ok(ret)
             }
+            Self::WetDistribution { emitter } => {
+                let (t_e, q_in, radiant_output) = match memory {
+                    ThermalHVACMemory::WetDistribution { t_e, q_in, radiant_output } => {
+                        (t_e, *q_in, radiant_output)
+                    }
+                    _ => return Err(
+                        "Tried to march a WetDistribution HVAC with memory allocated for a different HVAC kind".to_string()
+                    ),
+                };
+                let t_room = *t_room.get(emitter.target_space_index).ok_or_else(|| {
+                    format!(
+                        "WetDistribution HVAC targets space {}, but only {} zone temperatures were provided",
+                        emitter.target_space_index,
+                        t_room.len()
+                    )
+                })?;
+                let delivered = emitter.march(q_in, t_room, dt, t_e);
+                let convective = delivered * emitter.frac_convective;
+                *radiant_output = delivered - convective;
+                let feedback = emitter.convective_feedback_conductance(*t_e, t_room);
+                Ok(vec![(emitter.target_space_index, convective, feedback)])
+            }
+            Self::HeatPump { heat_pump } => {
+                let (q_demand, electrical_input) = match memory {
+                    ThermalHVACMemory::HeatPump { q_demand, electrical_input } => {
+                        (*q_demand, electrical_input)
+                    }
+                    _ => return Err(
+                        "Tried to march a HeatPump HVAC with memory allocated for a different HVAC kind".to_string()
+                    ),
+                };
+                let (heat_delivered, new_electrical_input) = heat_pump.output(q_demand, t_out);
+                *electrical_input = new_electrical_input;
+                Ok(vec![(heat_pump.target_space_index, heat_delivered, 0.0)])
+            }
+            Self::EvaporativeCooling { cooler } => {
+                let rh_room = match memory {
+                    ThermalHVACMemory::EvaporativeCooling { rh_room } => *rh_room,
+                    _ => return Err(
+                        "Tried to march an EvaporativeCooling HVAC with memory allocated for a different HVAC kind".to_string()
+                    ),
+                };
+                let t_room = *t_room.get(cooler.target_space_index).ok_or_else(|| {
+                    format!(
+                        "EvaporativeCooling HVAC targets space {}, but only {} zone temperatures were provided",
+                        cooler.target_space_index,
+                        t_room.len()
+                    )
+                })?;
+                let delivered = cooler.cooling_output(t_room, t_out, rh_room);
+                Ok(vec![(cooler.target_space_index, delivered, 0.0)])
+            }
+            Self::FunctionSource {
+                power,
+                target_space_index,
+                max_capacity,
+            } => {
+                let t_room = *t_room.get(*target_space_index).ok_or_else(|| {
+                    format!(
+                        "FunctionSource HVAC targets space {}, but only {} zone temperatures were provided",
+                        target_space_index,
+                        t_room.len()
+                    )
+                })?;
+                let delivered = power(time_seconds, t_room).clamp(-*max_capacity, *max_capacity);
+                Ok(vec![(*target_space_index, delivered, 0.0)])
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// [`WetEmitter::march`]'s warm-up transient, checked against a closed
+    /// solution. With `n = 1` the EN 442 law `Q_out = K*(T_e-T_room)` is
+    /// linear, so for a constant `q_in` and `t_room` the emitter's balance
+    /// `c*dT_e/dt = q_in - K*(T_e-T_room)` is the same RC-lag ODE used
+    /// elsewhere in this crate's validation tests, with closed solution
+    /// `T_e(t) = t_room + q_in/K + (T_e0 - t_room - q_in/K)*exp(-K*t/c)`.
+    #[test]
+    fn wet_emitter_warm_up_matches_closed_solution() {
+        let emitter = WetEmitter {
+            c: 8500.,
+            q_nom: 1500.,
+            dt_nom: 50.,
+            n: 1.0,
+            frac_convective: 0.6,
+            target_space_index: 0,
+        };
+        let k = emitter.k();
+        let q_in = 900.;
+        let t_room = 20.;
+        let t_e0 = 20.;
+
+        let closed = |t: Float| -> Float {
+            t_room + q_in / k + (t_e0 - t_room - q_in / k) * (-k * t / emitter.c).exp()
+        };
+
+        let dt = 300.; // 5 minute steps
+        let n_steps = 40;
+        let mut t_e = t_e0;
+        let mut t = 0.;
+        for _ in 0..n_steps {
+            emitter.march(q_in, t_room, dt, &mut t_e);
+            t += dt;
+            let expected = closed(t);
+            assert!(
+                (t_e - expected).abs() < 1e-3,
+                "t={t}: expected T_e={expected}, found T_e={t_e}"
+            );
         }
     }
+
+    /// [`ThermalHVAC::FunctionSource`] evaluates its power function against
+    /// the step's `time_seconds`/node temperature and clamps it to
+    /// `max_capacity`, rather than delivering it unbounded.
+    #[test]
+    fn function_source_evaluates_and_clamps_its_power_function() {
+        use simple_model::SimulationStateHeader;
+
+        let state = SimulationStateHeader::new().take_values().unwrap();
+        let hvac = ThermalHVAC::new_function_source(
+            Box::new(|time_seconds, _t_room| if time_seconds < 3600. { 500. } else { 2000. }),
+            0,
+            1000.,
+        );
+        let mut memory = hvac.allocate_memory();
+
+        let before_ramp =
+            hvac.calc_cooling_heating_power(&state, 300., &[20.0], 5.0, 0.0, &mut memory)
+                .unwrap();
+        assert_eq!(before_ramp, vec![(0, 500.0, 0.0)]);
+
+        // The function alone would request 2000W once the ramp lands, but
+        // the source's own capacity should clamp it.
+        let after_ramp =
+            hvac.calc_cooling_heating_power(&state, 300., &[20.0], 5.0, 7200.0, &mut memory)
+                .unwrap();
+        assert_eq!(after_ramp, vec![(0, 1000.0, 0.0)]);
+    }
 }
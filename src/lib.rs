@@ -24,6 +24,35 @@ SOFTWARE.
 //!
 //! It uses finite differences for marching forward in time and also
 //! for calculating the heat transfer through walls.
+//!
+//! # `no_std` support (in progress, not yet usable)
+//!
+//! Building without `std` is an eventual goal, for embedded building
+//! controllers or WASM targets without a standard library, but the crate
+//! does not support it yet: building with `--no-default-features
+//! --features libm` does not currently compile. What exists so far is
+//! only the call-site pattern one module has adopted in anticipation of
+//! that flag: with `std` disabled, transcendental math (`sqrt`, `exp`,
+//! `ln`, `powf`, trig, ...) is no longer available as inherent
+//! `f32`/`f64` methods—those are implemented in `std` by calling out to
+//! the platform's libm—so [`psychrometrics`] brings
+//! [`num_traits::Float`](https://docs.rs/num-traits) into scope (backed
+//! by the pure-Rust `libm` crate via `num-traits`' own `"libm"` feature)
+//! under `#[cfg(all(not(feature = "std"), feature = "libm"))]`; since
+//! that trait exposes the same method names as the inherent `std` ones,
+//! its call sites don't otherwise change. [`crate::Float`] already
+//! centralizes the `f32` vs `f64` precision choice, which is what makes
+//! this swap call-site-transparent.
+//!
+//! Every other module (`model`, `convection`, `cavity`, `discretization`,
+//! `gas`, `sky`, `ground`, and the rest) still calls `std`'s inherent
+//! methods unconditionally, and several modules (`material_library`,
+//! `energy_supply`, ...) also depend on heap collections (`String`,
+//! `Vec`, `HashMap`) that would need routing through `alloc` instead of
+//! `std`'s prelude. None of that is done here, and crucially the crate
+//! does not yet have a `#![no_std]` attribute at all—adding one is
+//! future work, to be done once enough of the above has actually been
+//! migrated that disabling `std` leaves something that builds.
 
 /// The kind of Floating point number used in the
 /// library... the `"float"` feature means it becomes `f32`
@@ -59,6 +88,10 @@ pub mod cavity;
 /// For creating thermal networks for heat-transfering surfaces.
 pub mod discretization;
 
+/// A projection-based (POD) reduced-order surrogate for repeatedly marching
+/// the same [`discretization::Discretization`].
+pub mod reduced_order;
+
 /// For calculating convection coefficients under different
 /// surface conditions.
 pub mod convection;
@@ -66,12 +99,26 @@ pub mod convection;
 /// Definitions for the thermal properties of gases.
 pub mod gas;
 
+/// A data-file-friendly library of opaque/translucent material property
+/// records, for sharing validated `Substance` definitions (with literature
+/// provenance) across models instead of hand-constructing them in code.
+pub mod material_library;
+
+/// For splitting the lumped incident infrared irradiance surfaces read
+/// from state into sky, ground and air components by tilt.
+pub mod sky;
+
 /// Glazing layer abstracted to only their optical properties.
 pub mod glazing;
 
 /// For HVAC-related calculations.
 pub mod heating_cooling;
 
+/// For accounting the energy (electricity and gas) behind the heat that
+/// HVAC elements deliver, netting it against on-site PV generation and an
+/// optional battery.
+pub mod energy_supply;
+
 /// For calculating heat transfer through all kinds of surfaces.
 pub mod surface;
 mod surface_trait;
@@ -80,3 +127,90 @@ mod surface_trait;
 pub mod zone;
 
 mod luminaire;
+
+/// An air-mass-flow link between two Zones (e.g. an open door or transfer
+/// air), coupling their heat balances.
+mod zone_mixing;
+
+/// Ductwork heat loss on mechanically supplied/ventilated air, and
+/// optional sensible heat recovery (e.g. an MVHR) blending the intake
+/// with the Zone's exhaust stream beforehand.
+pub mod duct;
+
+/// Undisturbed-ground boundary temperature, via the Kusuda–Achenbach
+/// analytical model.
+pub mod ground;
+
+/// Fixed-air-change-rate ventilation/infiltration elements coupling a
+/// Zone's air to the outdoors.
+pub mod ventilation;
+
+/// An adaptive Dormand–Prince (RK45) integrator, for ODEs whose
+/// right-hand side is nonlinear in the state.
+pub mod ode;
+
+/// Blends simulated node temperatures with sparse field measurements
+/// (sensor data assimilation), via the Parameterized-Background Data-Weak
+/// method.
+pub mod assimilation;
+
+/// A multi-node stratified hot-water storage tank, for hydronic heaters
+/// and emitters that draw from stored water rather than an idealized
+/// constant-power source.
+pub mod storage_tank;
+
+/// Beam/diffuse solar splitting and a per-surface absorb/reflect optical
+/// response, for callers that already have a sun vector and surface
+/// geometry/adjacency and want to distribute shortwave energy accordingly.
+pub mod solar_distribution;
+
+/// Moist-air property calculations (a wet-bulb temperature approximation
+/// and the saturation vapor pressure of water) that callers can drive with
+/// their own humidity data,
+/// since [`crate::zone`]'s Zone air nodes don't carry a humidity state.
+pub mod psychrometrics;
+
+/// A standalone multi-layer, buoyancy-coupled vertical air-temperature
+/// model for tall zones, for callers with their own per-layer elevation
+/// and heat-gain assignment and their own per-zone state storage—see
+/// [`stratification::StratifiedZone`]'s doc comment for why automatic single-node-vs-stratified
+/// mode selection in [`crate::model::ThermalModel::new`] isn't
+/// implemented.
+pub mod stratification;
+
+/// Interstitial (Glaser-method) condensation risk, computed from a
+/// converged node-temperature profile and caller-supplied vapor-diffusion
+/// resistances.
+pub mod condensation;
+
+/// A unit-safe `Temperature`/`TempDelta` pair, for callers that want to
+/// avoid the bare Celsius/Kelvin `Float` juggling the solver's internals
+/// still use (see the module doc comment for why that internal usage
+/// itself isn't migrated).
+pub mod temperature;
+
+/// Conduction Transfer Function (CTF) coefficients for a massive
+/// [`discretization::Discretization`] chunk, as a history-convolution
+/// alternative to stepping [`discretization::Discretization::get_k_q`]
+/// node-by-node every timestep.
+pub mod ctf;
+
+/// Fanger's Predicted Mean Vote (PMV) and Predicted Percentage of
+/// Dissatisfied (PPD) thermal comfort indices, computed from caller-supplied
+/// air/mean-radiant temperature rather than anything wired into
+/// [`zone::ThermalZone`] directly—see the module's own doc comment.
+pub mod comfort;
+
+/// Summation-by-parts finite-difference operators with simultaneous-
+/// approximation-term boundary penalties, as an optional high-order
+/// alternative to [`discretization::Discretization`]'s own node stencil for
+/// deep, highly-resolved single-layer assemblies—wired into
+/// [`discretization::SbpDiscretization`] for that single-layer case; see
+/// the module's own doc comment for what is (and is not yet) covered.
+pub mod sbp;
+
+/// A pre-simulation pass that walks a model's constructions for
+/// physically-implausible or missing material properties, so bad input
+/// data is reported up front instead of discovered through `NaN`s or
+/// diverging temperatures mid-`march`.
+pub mod validation;
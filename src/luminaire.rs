@@ -18,6 +18,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
+use crate::Float;
 use simple_model::{Luminaire, SimpleModel};
 use std::rc::Rc;
 
@@ -32,6 +33,15 @@ pub struct ThermalLuminaire {
 }
 
 impl ThermalLuminaire {
+    /// The fraction of a luminaire's power consumption delivered to its
+    /// Zone as long-wave radiant exchange with the Zone's interior
+    /// surfaces (to be distributed by area), with the remainder delivered
+    /// straight into the zone air term by convection. A typical value for
+    /// general lighting fixtures; `simple_model`'s `Luminaire` has no field
+    /// for this yet, so it is a shared constant rather than a per-instance
+    /// property.
+    pub const RADIANT_FRACTION: Float = 0.4;
+
     /// Builds a new [`ThermalHVAC`] from an HVAC and its location
     pub fn from(lum: &Rc<Luminaire>, model: &SimpleModel) -> Result<Self, String> {
         let parent = (**lum).clone();
@@ -0,0 +1,166 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A data-file-friendly material library for `simple_model::substance::Normal`
+//! substances, mirroring [`crate::gas::GasRecord`]/[`crate::gas::load_gas_library`]
+//! for gases: validated density/specific-heat/conductivity triples (plus
+//! optional literature provenance) kept in one shared file instead of
+//! hand-constructed in code every time a [`simple_model::Construction`]
+//! needs a new [`simple_model::Material`].
+//!
+//! `simple_model::Substance` is a foreign type with no generic "build me by
+//! name" constructor, so there is no way to add an inherent
+//! `Substance::from_library` the way a first pass at this might imagine;
+//! [`find`] is the free-function equivalent. Likewise, `serde_yaml` is not a
+//! dependency anywhere else in this crate—only `serde`/`serde_json` are
+//! (e.g. [`crate::gas::load_gas_library`],
+//! [`crate::discretization::Discretization::write_state`])—so this follows
+//! that same JSON-backed convention rather than introducing an unverified
+//! new external crate for YAML specifically.
+
+use crate::Float;
+use serde::{Deserialize, Serialize};
+use simple_model::substance::Normal;
+use simple_model::Substance;
+
+/// A serializable, library-friendly description of a `Normal` substance:
+/// the physical fields `Substance::Normal` needs, plus a `name` to look it
+/// up by (see [`find`]) and optional literature `references` for
+/// provenance.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubstanceRecord {
+    /// A human-readable name for this substance (e.g. `"red_brick"`)
+    pub name: String,
+
+    /// Density, in `kg/m3`
+    pub density: Float,
+
+    /// Specific heat capacity, in `J/kg.K`
+    pub specific_heat_capacity: Float,
+
+    /// Thermal conductivity, in `W/m.K`
+    pub thermal_conductivity: Float,
+
+    /// Front-face thermal absorptance (emissivity), in `[0,1]`. `None`
+    /// leaves it unset on the built substance, same as a hand-built
+    /// `Normal` that never calls `set_front_thermal_absorbtance`.
+    #[serde(default)]
+    pub front_thermal_absorbtance: Option<Float>,
+
+    /// Like [`Self::front_thermal_absorbtance`], for the back face.
+    #[serde(default)]
+    pub back_thermal_absorbtance: Option<Float>,
+
+    /// Literature citations/DOIs this entry's numbers were taken from, e.g.
+    /// `["ASHRAE Fundamentals 2017, Ch. 26, Table 4"]`. Purely
+    /// informational—never read by [`Self::to_substance`].
+    #[serde(default)]
+    pub references: Vec<String>,
+}
+
+impl SubstanceRecord {
+    /// Builds the `Substance::Normal` this record describes.
+    pub fn to_substance(&self) -> Substance {
+        let mut s = Normal::new(self.name.clone());
+        s.set_density(self.density);
+        s.set_specific_heat_capacity(self.specific_heat_capacity);
+        s.set_thermal_conductivity(self.thermal_conductivity);
+        if let Some(e) = self.front_thermal_absorbtance {
+            s.set_front_thermal_absorbtance(e);
+        }
+        if let Some(e) = self.back_thermal_absorbtance {
+            s.set_back_thermal_absorbtance(e);
+        }
+        s.wrap()
+    }
+}
+
+/// Reads a material library—a JSON array of [`SubstanceRecord`]s—from
+/// `reader`, in the same `serde_json`-backed style as
+/// [`crate::gas::load_gas_library`]. Required fields missing from an entry
+/// (e.g. no `thermal_conductivity`) fail the deserialization itself, rather
+/// than needing a separate validation pass.
+pub fn load_material_library<R: std::io::Read>(reader: R) -> Result<Vec<SubstanceRecord>, String> {
+    serde_json::from_reader(reader).map_err(|e| e.to_string())
+}
+
+/// Writes `records` out as a JSON material library `reader` can later be
+/// read back from with [`load_material_library`].
+pub fn write_material_library<W: std::io::Write>(
+    records: &[SubstanceRecord],
+    writer: W,
+) -> Result<(), String> {
+    serde_json::to_writer(writer, records).map_err(|e| e.to_string())
+}
+
+/// Looks up a [`SubstanceRecord`] by name within an already-loaded
+/// library—the free-function equivalent of `Substance::from_library(name)`,
+/// since [`Substance`] is a foreign type with no such inherent constructor.
+pub fn find<'a>(records: &'a [SubstanceRecord], name: &str) -> Option<&'a SubstanceRecord> {
+    records.iter().find(|r| r.name == name)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_material_library_round_trips_through_json() {
+        let records = vec![
+            SubstanceRecord {
+                name: "red_brick".to_string(),
+                density: 1700.0,
+                specific_heat_capacity: 800.0,
+                thermal_conductivity: 0.816,
+                front_thermal_absorbtance: Some(0.9),
+                back_thermal_absorbtance: Some(0.9),
+                references: vec!["ASHRAE Fundamentals 2017, Ch. 26, Table 4".to_string()],
+            },
+            SubstanceRecord {
+                name: "eps_foam".to_string(),
+                density: 17.5,
+                specific_heat_capacity: 2400.0,
+                thermal_conductivity: 0.0252,
+                front_thermal_absorbtance: None,
+                back_thermal_absorbtance: None,
+                references: Vec::new(),
+            },
+        ];
+
+        let mut buf = Vec::new();
+        write_material_library(&records, &mut buf).unwrap();
+        let read_back = load_material_library(buf.as_slice()).unwrap();
+
+        assert_eq!(read_back.len(), records.len());
+
+        let found = find(&read_back, "eps_foam").unwrap();
+        assert!((found.thermal_conductivity - 0.0252).abs() < 1e-10);
+        assert!(find(&read_back, "does_not_exist").is_none());
+
+        let brick = find(&read_back, "red_brick").unwrap();
+        assert_eq!(brick.references.len(), 1);
+        match brick.to_substance() {
+            Substance::Normal(s) => {
+                assert!((*s.density().unwrap() - 1700.0).abs() < 1e-10);
+            }
+            _ => panic!("expected a Normal substance"),
+        }
+    }
+}
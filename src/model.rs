@@ -28,26 +28,461 @@ use weather::Weather;
 use crate::surface::{SurfaceMemory, ThermalFenestration, ThermalSurface, ThermalSurfaceData};
 use crate::surface_trait::SurfaceTrait;
 
-use crate::heating_cooling::ThermalHVAC;
+use crate::heating_cooling::{ThermalHVAC, ThermalHVACMemory};
 use crate::luminaire::ThermalLuminaire;
+use crate::zone_mixing::ThermalZoneMixing;
 
+use crate::ground::GroundTemperatureModel;
+use crate::ventilation::VentilationElement;
 use crate::zone::ThermalZone;
 use simple_model::{Boundary, SimpleModel, SimulationState, SimulationStateHeader};
 use std::borrow::Borrow;
 
-// #[cfg(feature="parallel")]
-// use rayon::prelude::*;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
 
 /// The module name. For debugging purposes
 pub(crate) const MODULE_NAME: &str = "Thermal model";
 
+/// Finds the front/back air temperatures bounding `surf`, reading the
+/// relevant [`simple_model::Space`] or fixed ambient temperature from
+/// `state`, or evaluating `ground` (at `surf.ground_depth` and
+/// `t_seconds`, i.e. seconds into the year—see
+/// [`crate::ground::seconds_of_year`]) for a [`Boundary::Ground`] side.
+/// Shared by the surface and fenestration marching loops.
+#[allow(clippy::too_many_arguments)]
+fn surface_boundary_temperatures<T: SurfaceTrait>(
+    surf: &ThermalSurfaceData<T>,
+    model: &SimpleModel,
+    state: &SimulationState,
+    t_out: Float,
+    ground: &GroundTemperatureModel,
+    t_seconds: Float,
+) -> Result<(Float, Float), String> {
+    let t_front = match &surf.front_boundary {
+        Some(b) => match b {
+            Boundary::Space { space } => {
+                let space = model.get_space(space)?;
+                space
+                    .dry_bulb_temperature(state)
+                    .expect("Space in front of surface has no temperature!")
+            }
+            Boundary::AmbientTemperature { temperature } => *temperature,
+            Boundary::Ground => ground.temperature(surf.ground_depth, t_seconds),
+        },
+        None => t_out,
+    };
+    let t_back = match &surf.back_boundary {
+        Some(b) => match b {
+            Boundary::Space { space } => {
+                let space = model.get_space(space)?;
+                space
+                    .dry_bulb_temperature(state)
+                    .expect("Space at the back of surface has no temperature!")
+            }
+            Boundary::Ground => ground.temperature(surf.ground_depth, t_seconds),
+            Boundary::AmbientTemperature { temperature } => *temperature,
+        },
+        None => t_out,
+    };
+    Ok((t_front, t_back))
+}
+
+/// The mean radiant temperature "seen" by one side of a surface: the
+/// owning Zone's [`ThermalModelMemory::zone_mean_radiant_temperature`] for a
+/// [`Boundary::Space`] side, or `fallback` (the side's own air/ambient
+/// temperature) for anything else, matching the previous behaviour.
+fn boundary_mrt(
+    boundary: &Option<Boundary>,
+    space_index: Option<usize>,
+    zone_mrt: &[Float],
+    fallback: Float,
+) -> Float {
+    match boundary {
+        Some(Boundary::Space { .. }) => space_index.map(|z| zone_mrt[z]).unwrap_or(fallback),
+        _ => fallback,
+    }
+}
+
+/// Drives every exterior-facing ([`Boundary::Space`]-less) side of
+/// `surfaces`' incident IR irradiance from a [`crate::sky::SkyModel`] built
+/// from the current outdoor dry-bulb temperature and `ground`, promoting
+/// the "caller pokes `set_front_ir_irradiance` by hand every timestep"
+/// idiom into an automatic part of [`ThermalModel::march`] when
+/// [`ThermalModel::sky_clearness`] is set. Writing both sides
+/// unconditionally is harmless for `Boundary::Space`/`AmbientTemperature`/
+/// `Boundary::Ground` sides: their long-wave exchange already comes from
+/// `front_mrt`/`back_mrt` in [`ThermalSurfaceData::march`], not from the
+/// IR irradiance stored in `state`.
+fn apply_sky_ir_irradiance<T: SurfaceTrait>(
+    surfaces: &[ThermalSurfaceData<T>],
+    state: &mut SimulationState,
+    t_out: Float,
+    ground: &GroundTemperatureModel,
+    t_seconds: Float,
+    sky_clearness: Float,
+) -> Result<(), String> {
+    for surf in surfaces {
+        let ground_temperature = ground.temperature(surf.ground_depth, t_seconds);
+        let sky = crate::sky::SkyModel {
+            sky_temperature: t_out,
+            ground_temperature,
+            air_temperature: t_out,
+            sky_clearness,
+        };
+        surf.set_sky_ir_irradiance(state, &sky, &sky)?;
+    }
+    Ok(())
+}
+
+/// Like [`surface_boundary_temperatures`], but for a
+/// [`ZoneCouplingScheme::Coupled`] fixed-point iteration: any side whose
+/// boundary is a [`Boundary::Space`] is driven by `zone_guess` (this
+/// iteration's not-yet-committed zone-air estimate) instead of the zone's
+/// last-committed `state`; every other boundary is unaffected, since it
+/// doesn't depend on the guess.
+#[allow(clippy::too_many_arguments)]
+fn surface_coupled_boundary_temperatures<T: SurfaceTrait>(
+    surf: &ThermalSurfaceData<T>,
+    model: &SimpleModel,
+    state: &SimulationState,
+    t_out: Float,
+    ground: &GroundTemperatureModel,
+    t_seconds: Float,
+    zone_guess: &[Float],
+) -> Result<(Float, Float), String> {
+    let (mut t_front, mut t_back) =
+        surface_boundary_temperatures(surf, model, state, t_out, ground, t_seconds)?;
+    if let (Some(Boundary::Space { .. }), Some(z)) = (&surf.front_boundary, surf.front_space_index) {
+        t_front = zone_guess[z];
+    }
+    if let (Some(Boundary::Space { .. }), Some(z)) = (&surf.back_boundary, surf.back_space_index) {
+        t_back = zone_guess[z];
+    }
+    Ok((t_front, t_back))
+}
+
+/// One surface's not-yet-committed result from
+/// [`ThermalSurfaceData::march_readonly`] during a
+/// [`ZoneCouplingScheme::Coupled`] fixed-point iteration: what
+/// [`accumulate_surface_abc`] needs to fold into a zone's `a`/`b`
+/// coefficients, read from `memory`/the convection coefficients rather than
+/// from `state` (which isn't updated until the iteration converges).
+struct PendingSurface {
+    front_temperature: Float,
+    back_temperature: Float,
+    front_hs: Float,
+    back_hs: Float,
+}
+
+/// Runs [`ThermalSurfaceData::march_readonly`] for one surface at
+/// `zone_guess`, returning the [`PendingSurface`] a
+/// [`ZoneCouplingScheme::Coupled`] iteration needs.
+#[allow(clippy::too_many_arguments)]
+fn march_surface_readonly<T: SurfaceTrait>(
+    surf: &ThermalSurfaceData<T>,
+    model: &SimpleModel,
+    state: &SimulationState,
+    t_out: Float,
+    ground: &GroundTemperatureModel,
+    t_seconds: Float,
+    zone_guess: &[Float],
+    zone_mrt: &[Float],
+    wind_direction: Float,
+    wind_speed: Float,
+    dt: Float,
+    memory: &mut SurfaceMemory,
+) -> Result<PendingSurface, String> {
+    let (t_front, t_back) = surface_coupled_boundary_temperatures(
+        surf, model, state, t_out, ground, t_seconds, zone_guess,
+    )?;
+    let front_mrt = boundary_mrt(&surf.front_boundary, surf.front_space_index, zone_mrt, t_front);
+    let back_mrt = boundary_mrt(&surf.back_boundary, surf.back_space_index, zone_mrt, t_back);
+
+    let (front_hs, back_hs) = surf.march_readonly(
+        state,
+        t_front,
+        t_back,
+        front_mrt,
+        back_mrt,
+        wind_direction,
+        wind_speed,
+        dt,
+        memory,
+        None,
+    )?;
+    let (rows, ..) = memory.temperatures.size();
+    Ok(PendingSurface {
+        front_temperature: memory.temperatures.get(0, 0)?,
+        back_temperature: memory.temperatures.get(rows - 1, 0)?,
+        front_hs,
+        back_hs,
+    })
+}
+
+/// Folds `pending`'s (not-yet-committed) surface temperatures and
+/// convection coefficients into `a`/`b`, exactly the way
+/// [`ThermalModel::calculate_zones_abc`]'s surface pass does from `state`—
+/// the [`ZoneCouplingScheme::Coupled`] counterpart to that function's
+/// `iterate_surfaces`.
+fn accumulate_surface_abc<T: SurfaceTrait>(
+    surfaces: &[ThermalSurfaceData<T>],
+    pending: &[PendingSurface],
+    a: &mut [Float],
+    b: &mut [Float],
+) {
+    for (surface, p) in surfaces.iter().zip(pending.iter()) {
+        let ai = surface.area;
+        if let (Some(Boundary::Space { .. }), Some(z)) =
+            (&surface.front_boundary, surface.front_space_index)
+        {
+            a[z] += p.front_hs * ai * p.front_temperature;
+            b[z] += p.front_hs * ai;
+        }
+        if let (Some(Boundary::Space { .. }), Some(z)) =
+            (&surface.back_boundary, surface.back_space_index)
+        {
+            a[z] += p.back_hs * ai * p.back_temperature;
+            b[z] += p.back_hs * ai;
+        }
+    }
+}
+
+/// Folds `surfaces`' already-committed `state` (temperature and convection
+/// coefficient) into `a`/`b`, one Zone term per interior-facing side—the
+/// surface pass of [`ThermalModel::calculate_zones_abc`], shared by every
+/// [`ZoneCouplingScheme::Staged`] sub-step.
+fn iterate_surfaces<T: SurfaceTrait>(
+    surfaces: &[ThermalSurfaceData<T>],
+    state: &SimulationState,
+    a: &mut [Float],
+    b: &mut [Float],
+) -> Result<(), String> {
+    for surface in surfaces {
+        let parent = &surface.parent;
+        let h_front = parent.front_convection_coefficient(state).unwrap();
+        let h_back = parent.back_convection_coefficient(state).unwrap();
+
+        let ai = surface.area;
+        // if front leads to a Zone
+        if let Some(Boundary::Space { .. }) = &surface.front_boundary {
+            let z_index = surface.front_space_index.unwrap(); // Should have one of these if boundary is Space
+
+            let temp = surface.parent.front_temperature(state);
+            a[z_index] += h_front * ai * temp;
+            b[z_index] += h_front * ai;
+        }
+
+        // if back leads to a Zone
+        if let Some(Boundary::Space { .. }) = &surface.back_boundary {
+            let z_index = surface.back_space_index.unwrap(); // Should have one of these if boundary is Space
+
+            let temp = surface.parent.back_temperature(state);
+            a[z_index] += h_back * ai * temp;
+            b[z_index] += h_back * ai;
+        }
+    }
+    Ok(())
+}
+
+/// Accumulates, per Zone, the area and the area-weighted surface
+/// temperature of `surfaces`' interior-facing ([`Boundary::Space`]) sides,
+/// for the "radiant star node" mean-radiant-temperature computation in
+/// [`ThermalModel::update_zones_radiant_terms`].
+fn accumulate_interior_terms<T: SurfaceTrait>(
+    surfaces: &[ThermalSurfaceData<T>],
+    state: &SimulationState,
+    weighted_temperature: &mut [Float],
+    area: &mut [Float],
+) {
+    for surface in surfaces {
+        let ai = surface.area;
+        if let Some(Boundary::Space { .. }) = &surface.front_boundary {
+            let z = surface.front_space_index.unwrap();
+            weighted_temperature[z] += ai * surface.parent.front_temperature(state);
+            area[z] += ai;
+        }
+        if let Some(Boundary::Space { .. }) = &surface.back_boundary {
+            let z = surface.back_space_index.unwrap();
+            weighted_temperature[z] += ai * surface.parent.back_temperature(state);
+            area[z] += ai;
+        }
+    }
+}
+
+/// Distributes `zone_radiant_gain` (W, per Zone) onto `surfaces`'
+/// interior-facing sides as a node heat source, proportional to each
+/// surface's share of `zone_interior_area` (so that a Zone's radiant
+/// internal gains—luminaires, wet emitters—warm its surfaces before its
+/// air, instead of being injected straight into the zone air term).
+fn distribute_radiant_gains<T: SurfaceTrait>(
+    surfaces: &[ThermalSurfaceData<T>],
+    memories: &mut [SurfaceMemory],
+    zone_interior_area: &[Float],
+    zone_radiant_gain: &[Float],
+) -> Result<(), String> {
+    for (surface, memory) in surfaces.iter().zip(memories.iter_mut()) {
+        if let Some(Boundary::Space { .. }) = &surface.front_boundary {
+            let z = surface.front_space_index.unwrap();
+            let flux = if zone_interior_area[z] > 1e-9 {
+                zone_radiant_gain[z] / zone_interior_area[z]
+            } else {
+                0.0
+            };
+            surface.set_node_heat_source(memory, 0, flux * surface.area)?;
+        }
+        if let Some(Boundary::Space { .. }) = &surface.back_boundary {
+            let z = surface.back_space_index.unwrap();
+            let flux = if zone_interior_area[z] > 1e-9 {
+                zone_radiant_gain[z] / zone_interior_area[z]
+            } else {
+                0.0
+            };
+            let (n_nodes, ..) = memory.node_heat_sources.size();
+            surface.set_node_heat_source(memory, n_nodes - 1, flux * surface.area)?;
+        }
+    }
+    Ok(())
+}
+
 /// The memory that this module requires, so we can allocate only once.
 pub struct ThermalModelMemory {
-    
+
     surfaces: Vec<SurfaceMemory>,
     fenestrations: Vec<SurfaceMemory>,
+    hvacs: Vec<ThermalHVACMemory>,
+
+    /// Each Zone's mean radiant temperature (°C), as last computed by
+    /// [`ThermalModel::update_zones_radiant_terms`] (see
+    /// [`Self::zone_mean_radiant_temperature`]).
+    zone_mrt: Vec<Float>,
 }
 
+impl ThermalModelMemory {
+    /// The mean radiant temperature (°C) of the Zone at `zone_index`, i.e.
+    /// the area-weighted mean temperature of its interior-facing surfaces
+    /// (the "radiant star node" simplification), as last computed during
+    /// [`ThermalModel::march`]. Exposed for comfort calculations (e.g.
+    /// operative temperature) that need more than the zone air temperature.
+    pub fn zone_mean_radiant_temperature(&self, zone_index: usize) -> Float {
+        self.zone_mrt[zone_index]
+    }
+
+    /// The per-instance mutable state (see [`ThermalHVACMemory`]) of the
+    /// HVAC at `index` in [`ThermalModel::hvacs`], for a caller that needs
+    /// to drive a demand-modulating variant (e.g. setting
+    /// [`ThermalHVACMemory::HeatPump`]'s `q_demand`, or
+    /// [`ThermalHVACMemory::WetDistribution`]'s `q_in` from an external heat
+    /// source such as a [`crate::storage_tank::StorageTank`]) before the
+    /// next [`ThermalModel::march`] call.
+    pub fn hvac_memory_mut(&mut self, index: usize) -> &mut ThermalHVACMemory {
+        &mut self.hvacs[index]
+    }
+
+    /// Read-only counterpart to [`Self::hvac_memory_mut`].
+    pub fn hvac_memory(&self, index: usize) -> &ThermalHVACMemory {
+        &self.hvacs[index]
+    }
+}
+
+
+/// How surface and zone-air temperatures are advanced within a sub-step,
+/// passed as [`ThermalModel`]'s [`SimulationModel::OptionType`].
+///
+/// The historical [`Self::Staged`] scheme marches every surface holding the
+/// zone air temperature fixed at last sub-step's value, then solves the
+/// zone air analytically from the surfaces' resulting temperatures—a
+/// one-step lag between surface and air that is exactly what forces
+/// [`ThermalModel::new`]'s `SAFETY` timestep inflation. [`Self::Coupled`]
+/// removes that lag by iterating the two against each other within the
+/// same sub-step until they agree.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ZoneCouplingScheme {
+    /// March surfaces against last sub-step's zone air temperature, then
+    /// solve the zone air analytically (see [`ThermalModel::calculate_zones_abc`]
+    /// and [`ThermalModel::estimate_zones_future_temperatures`]). Cheap,
+    /// but needs `SAFETY` to stay stable.
+    Staged,
+
+    /// Fixed-point-iterate [`ThermalSurfaceData::march_readonly`] and the
+    /// zone-air analytical solve against each other—surfaces aren't
+    /// committed to `state` until the pair agrees—until no zone air
+    /// estimate moves by more than `tolerance` between iterations, or
+    /// `max_iterations` is reached. Unconditionally stable with respect to
+    /// the surface/air coupling, so [`ThermalModel::new`] skips `SAFETY`
+    /// for this scheme.
+    Coupled {
+        /// Maximum fixed-point iterations per sub-step.
+        max_iterations: usize,
+        /// Convergence threshold on zone air temperature, in °C, between
+        /// consecutive iterations.
+        tolerance: Float,
+    },
+
+    /// Like [`Self::Staged`]—surfaces are marched against last sub-step's
+    /// zone air temperature, then the zone air is advanced holding `a`/`b`/`c`
+    /// fixed over the sub-step—except the zone air's affine ODE
+    /// `dT/dt = (a - b·T)/c` is advanced with the adaptive embedded
+    /// Dormand–Prince (RK45) scheme ([`ThermalModel::estimate_zones_future_temperatures_adaptive`])
+    /// rather than its analytical exponential solution, sub-stepping within
+    /// the sub-step under local error control and always landing exactly on
+    /// its end.
+    ///
+    /// `a`/`b` are NOT re-evaluated at the stage's trial temperatures:
+    /// recomputing them for real (re-running HVAC/luminaire/infiltration
+    /// terms) would re-trigger side effects on `hvac_memory` multiple times
+    /// per sub-step (the same constraint that shaped
+    /// [`ThermalModel::calculate_zones_non_surface_abc`]'s factoring for
+    /// [`Self::Coupled`]), and the surface-side terms are already frozen
+    /// (marched against last sub-step's zone air before this solve runs).
+    /// So this scheme buys local error control over the ODE step itself—
+    /// useful as a cross-check against [`Self::Staged`]'s closed-form
+    /// update, or headroom for a future `recompute_ab` that folds in
+    /// genuinely temperature-dependent `a`/`b` without re-running anything
+    /// with side effects—rather than capturing additional non-linearity
+    /// today. Not applicable when [`ThermalModel::zone_mixing`] links are
+    /// present, since those couple zones' ODEs together rather than leaving
+    /// each zone's temperature a function of its own trial value alone.
+    Adaptive {
+        /// Tuning knobs for the embedded RK45 (tolerances, step bounds).
+        options: crate::ode::DormandPrinceOptions,
+    },
+}
+
+impl std::default::Default for ZoneCouplingScheme {
+    fn default() -> Self {
+        ZoneCouplingScheme::Staged
+    }
+}
+
+impl ZoneCouplingScheme {
+    /// Convenience constructor for [`Self::Coupled`] with sensible defaults
+    /// (10 iterations, 1e-4°C).
+    pub fn coupled() -> Self {
+        Self::Coupled {
+            max_iterations: 10,
+            tolerance: 1e-4,
+        }
+    }
+
+    /// Convenience constructor for [`Self::Adaptive`], so a caller can pick
+    /// the embedded RK45's tolerances and step bounds directly instead of
+    /// building a [`crate::ode::DormandPrinceOptions`] by hand. Note this
+    /// only relaxes how the zone air's own ODE is stepped—[`ThermalModel::new`]'s
+    /// `n` still fixes the surface conduction sub-stepping (sized for
+    /// [`crate::discretization::IntegrationScheme::RK4`]'s explicit
+    /// stability limit) regardless of the tolerances chosen here.
+    pub fn adaptive(rtol: Float, atol: Float, min_step: Float, max_step: Float) -> Self {
+        Self::Adaptive {
+            options: crate::ode::DormandPrinceOptions {
+                rtol,
+                atol,
+                min_step,
+                max_step,
+            },
+        }
+    }
+}
 
 /// A structure containing all the thermal representation of the whole
 /// [`SimpleModel`]
@@ -67,6 +502,9 @@ pub struct ThermalModel {
     /// Luminaires
     pub luminaires: Vec<ThermalLuminaire>,
 
+    /// Air-mass-flow links between Zones (e.g. open doors or transfer air)
+    pub zone_mixing: Vec<ThermalZoneMixing>,
+
     // / contains all the HVACs
     // pub hvacs: Vec<Float>,
     /// The number of steps that this model needs
@@ -76,6 +514,37 @@ pub struct ThermalModel {
 
     /// The model's dt (i.e., main_dt / self.dt_subdivisions)
     pub dt: Float,
+
+    /// How surface and zone-air temperatures are advanced within each
+    /// sub-step. See [`ZoneCouplingScheme`].
+    pub scheme: ZoneCouplingScheme,
+
+    /// The undisturbed-ground temperature model used for any surface or
+    /// fenestration with a [`Boundary::Ground`] side. Defaults to a
+    /// generic mid-latitude soil; set with
+    /// [`Self::set_ground_temperature_model`] to calibrate against the
+    /// actual weather file, via
+    /// [`GroundTemperatureModel::from_dry_bulb_series`].
+    pub ground: GroundTemperatureModel,
+
+    /// Fixed-air-change-rate ventilation/infiltration elements, each
+    /// coupling one Zone's air to the outdoors. These are additional to
+    /// (and independent of) any `infiltration`/`ventilation` schedules
+    /// already set on a Zone's `Space`; see [`VentilationElement`].
+    pub ventilation: Vec<VentilationElement>,
+
+    /// When set, every exterior-facing ([`Boundary::Space`]-less) surface
+    /// and fenestration has its incident IR irradiance computed
+    /// automatically each sub-step from a [`crate::sky::SkyModel`] built
+    /// from the current outdoor dry-bulb temperature, the
+    /// [`Self::ground`] model, and this clearness fraction (see
+    /// [`crate::sky::SkyModel`]'s `sky_clearness` field), instead of
+    /// requiring a caller to poke
+    /// `set_front_ir_irradiance`/`set_back_ir_irradiance`
+    /// by hand. `None` (the default) leaves that IR irradiance exactly as
+    /// the caller last set it, preserving previous behaviour. Set with
+    /// [`Self::set_sky_clearness`].
+    pub sky_clearness: Option<Float>,
 }
 
 impl ErrorHandling for ThermalModel {
@@ -88,7 +557,7 @@ impl ErrorHandling for ThermalModel {
 
 impl SimulationModel for ThermalModel {
     type OutputType = Self;
-    type OptionType = (); // No options
+    type OptionType = ZoneCouplingScheme;
     type AllocType = ThermalModelMemory;
 
     fn allocate_memory(&self)->Result<Self::AllocType, String>{
@@ -101,9 +570,13 @@ impl SimulationModel for ThermalModel {
             s.allocate_memory()
         }).collect();
 
-        let ret = ThermalModelMemory { 
+        let hvacs = self.hvacs.iter().map(|h| h.allocate_memory()).collect();
+
+        let ret = ThermalModelMemory {
             surfaces,
             fenestrations,
+            hvacs,
+            zone_mrt: vec![22.0; self.zones.len()],
         };
         Ok(ret)
     }
@@ -116,7 +589,7 @@ impl SimulationModel for ThermalModel {
     /// * n: the number of timesteps per hour taken by the main simulation.
     fn new<M: Borrow<SimpleModel>>(
         _meta_options: &MetaOptions,
-        _options: Self::OptionType,
+        options: Self::OptionType,
         model: M,
         state: &mut SimulationStateHeader,
         n: usize,
@@ -234,10 +707,15 @@ impl SimulationModel for ThermalModel {
         // This is the model's dt now. When marching
         let mut dt = 60. * 60. / (n as Float * dt_subdivisions as Float);
 
-        // safety.
-        const SAFETY: usize = 2;
-        dt /= SAFETY as Float;
-        dt_subdivisions *= SAFETY;
+        // `ZoneCouplingScheme::Staged` marches surfaces and zone air one
+        // sub-step out of phase with each other, so it needs a safety
+        // margin to stay stable. `ZoneCouplingScheme::Coupled` iterates the
+        // two to agreement within a sub-step instead, so it doesn't.
+        if matches!(options, ZoneCouplingScheme::Staged) {
+            const SAFETY: usize = 2;
+            dt /= SAFETY as Float;
+            dt_subdivisions *= SAFETY;
+        }
 
         let mut hvacs: Vec<ThermalHVAC> = Vec::with_capacity(model.hvacs.len());
         for hvac in model.hvacs.iter() {
@@ -251,14 +729,25 @@ impl SimulationModel for ThermalModel {
             luminaires.push(l)
         }
 
+        let mut zone_mixing: Vec<ThermalZoneMixing> = Vec::with_capacity(model.zone_mixing.len());
+        for mix in model.zone_mixing.iter() {
+            let m = ThermalZoneMixing::from(mix, model)?;
+            zone_mixing.push(m)
+        }
+
         Ok(ThermalModel {
             zones,
             surfaces,
             luminaires,
+            zone_mixing,
             fenestrations,
             dt_subdivisions,
             hvacs,
             dt,
+            scheme: options,
+            ground: GroundTemperatureModel::default(),
+            ventilation: Vec::new(),
+            sky_clearness: None,
         })
     }
 
@@ -292,99 +781,77 @@ impl SimulationModel for ThermalModel {
 
             // Gather spaces temperatures
             let t_current = self.get_current_zones_temperatures(state);
+            let t_seconds = crate::ground::seconds_of_year(&date);
+
+            /* INTERIOR RADIANT EXCHANGE */
+            // Compute each Zone's mean radiant temperature (from last step's
+            // surface temperatures) and the radiant share of its internal
+            // gains, then spread that gain onto its interior surfaces before
+            // marching them, proportional to area.
+            let mut zone_radiant_gain = vec![0.0; self.zones.len()];
+            let zone_interior_area = self.update_zones_radiant_terms(
+                state,
+                &t_current,
+                &alloc.hvacs,
+                &mut alloc.zone_mrt,
+                &mut zone_radiant_gain,
+            );
+            distribute_radiant_gains(
+                &self.surfaces,
+                &mut alloc.surfaces,
+                &zone_interior_area,
+                &zone_radiant_gain,
+            )?;
+            distribute_radiant_gains(
+                &self.fenestrations,
+                &mut alloc.fenestrations,
+                &zone_interior_area,
+                &zone_radiant_gain,
+            )?;
 
-            
-
-            /* UPDATE SURFACE'S TEMPERATURES */
-            for ((solar_surf, model_surf), memory) in self.surfaces.iter().zip(model.surfaces.iter()).zip(alloc.surfaces.iter_mut()) {
-                // find t_in and t_out of surface.
-                let t_front = match &solar_surf.front_boundary {
-                    Some(b) => match b {
-                        Boundary::Space { space } => {
-                            let space = model.get_space(space)?;
-                            space
-                                .dry_bulb_temperature(state)
-                                .expect("Space in front of surface has no temperature!")
-                        }
-                        Boundary::AmbientTemperature { temperature } => *temperature,
-                        Boundary::Ground => unimplemented!(),
-                    },
-                    None => t_out,
-                };
-                let t_back = match &solar_surf.back_boundary {
-                    Some(b) => match b {
-                        Boundary::Space { space } => {
-                            let space = model.get_space(space)?;
-                            space
-                                .dry_bulb_temperature(state)
-                                .expect("Space at the back of surface has no temperature!")
-                        }
-                        Boundary::Ground => unimplemented!(),
-                        Boundary::AmbientTemperature { temperature } => *temperature,
-                    },
-                    None => t_out,
-                };
-
-                // Update temperatures
-                let (q_front, q_back) =
-                    solar_surf.march(state, t_front, t_back, wind_direction, wind_speed, self.dt, memory)?;
-                model_surf.set_front_convective_heat_flow(state, q_front)?;
-                model_surf.set_back_convective_heat_flow(state, q_back)?;
-            } // end of iterating surface
-
-            // What  if they are open???
-            // for i in 0..self.fenestrations.len() {
-            for ((solar_surf, model_surf), memory) in
-                self.fenestrations.iter().zip(model.fenestrations.iter()).zip(alloc.fenestrations.iter_mut())
-            {
-                // find t_in and t_out of surface.
-                let t_front = match &solar_surf.front_boundary {
-                    Some(b) => match b {
-                        Boundary::Space { space } => {
-                            let space = model.get_space(space)?;
-                            space
-                                .dry_bulb_temperature(state)
-                                .expect("Space in front of fenestration has no temperature!")
-                        }
-                        Boundary::Ground => unimplemented!(),
-                        Boundary::AmbientTemperature { temperature } => *temperature,
-                    },
-                    None => t_out,
-                };
-                let t_back = match &solar_surf.back_boundary {
-                    Some(b) => match b {
-                        Boundary::Space { space } => {
-                            let space = model.get_space(space)?;
-                            space
-                                .dry_bulb_temperature(state)
-                                .expect("Space at the back of fenestration has no temperature!")
-                        }
-                        Boundary::Ground => unimplemented!(),
-                        Boundary::AmbientTemperature { temperature } => *temperature,
-                    },
-                    None => t_out,
-                };
-
-                // Update temperatures
-                let (q_front, q_back) =
-                    solar_surf.march(state, t_front, t_back, wind_direction, wind_speed, self.dt, memory)?;
-                model_surf.set_front_convective_heat_flow(state, q_front)?;
-                model_surf.set_back_convective_heat_flow(state, q_back)?;
-            } // end of iterating surface
-
-            /* UPDATE ZONES' TEMPERATURE */
-            // This is done analytically.
-            let (a, b, c) = self.calculate_zones_abc(model, state)?;
-
-            let future_temperatures =
-                self.estimate_zones_future_temperatures(&t_current, &a, &b, &c, self.dt);
-            for (i, zone) in self.zones.iter().enumerate() {
-                assert!(
-                    !future_temperatures[i].is_nan(),
-                    "Future temperatures is NaN"
-                );
-                zone.reference_space
-                    .set_dry_bulb_temperature(state, future_temperatures[i])?;
+            match self.scheme {
+                ZoneCouplingScheme::Staged => {
+                    self.march_staged_substep(
+                        model,
+                        state,
+                        &t_current,
+                        t_out,
+                        t_seconds,
+                        wind_direction,
+                        wind_speed,
+                        alloc,
+                    )?;
+                }
+                ZoneCouplingScheme::Coupled {
+                    max_iterations,
+                    tolerance,
+                } => {
+                    self.march_coupled_substep(
+                        model,
+                        state,
+                        &t_current,
+                        t_out,
+                        t_seconds,
+                        wind_direction,
+                        wind_speed,
+                        alloc,
+                        max_iterations,
+                        tolerance,
+                    )?;
+                }
+                ZoneCouplingScheme::Adaptive { options } => {
+                    self.march_adaptive_substep(
+                        model,
+                        state,
+                        &t_current,
+                        t_out,
+                        t_seconds,
+                        wind_direction,
+                        wind_speed,
+                        alloc,
+                        &options,
+                    )?;
+                }
             }
         } // End of 'in each sub-timestep-subdivision'
 
@@ -393,6 +860,468 @@ impl SimulationModel for ThermalModel {
 }
 
 impl ThermalModel {
+    /// The [`ZoneCouplingScheme::Staged`] sub-step: march every surface
+    /// holding the zone air temperature fixed at `t_current`, then solve the
+    /// zone air analytically from the surfaces' resulting temperatures.
+    #[allow(clippy::too_many_arguments)]
+    fn march_staged_substep(
+        &self,
+        model: &SimpleModel,
+        state: &mut SimulationState,
+        t_current: &[Float],
+        t_out: Float,
+        t_seconds: Float,
+        wind_direction: Float,
+        wind_speed: Float,
+        alloc: &mut ThermalModelMemory,
+    ) -> Result<(), String> {
+        if let Some(sky_clearness) = self.sky_clearness {
+            apply_sky_ir_irradiance(&self.surfaces, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+            apply_sky_ir_irradiance(&self.fenestrations, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+        }
+
+        /* UPDATE SURFACE'S TEMPERATURES */
+        for ((solar_surf, model_surf), memory) in self
+            .surfaces
+            .iter()
+            .zip(model.surfaces.iter())
+            .zip(alloc.surfaces.iter_mut())
+        {
+            let (t_front, t_back) = surface_boundary_temperatures(
+                solar_surf, model, state, t_out, &self.ground, t_seconds,
+            )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+
+            // Update temperatures
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        } // end of iterating surface
+
+        // What  if they are open???
+        for ((solar_surf, model_surf), memory) in self
+            .fenestrations
+            .iter()
+            .zip(model.fenestrations.iter())
+            .zip(alloc.fenestrations.iter_mut())
+        {
+            let (t_front, t_back) = surface_boundary_temperatures(
+                solar_surf, model, state, t_out, &self.ground, t_seconds,
+            )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+
+            // Update temperatures
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        } // end of iterating surface
+
+        /* UPDATE ZONES' TEMPERATURE */
+        // This is done analytically.
+        let (a, b, c) = self.calculate_zones_abc(model, state, t_current, t_out, t_seconds, &mut alloc.hvacs)?;
+
+        let future_temperatures =
+            self.estimate_zones_future_temperatures_multizone(state, t_current, &a, &b, &c, self.dt);
+        for (i, zone) in self.zones.iter().enumerate() {
+            assert!(
+                !future_temperatures[i].is_nan(),
+                "Future temperatures is NaN"
+            );
+            zone.reference_space
+                .set_dry_bulb_temperature(state, future_temperatures[i])?;
+        }
+
+        Ok(())
+    }
+
+    /// The [`ZoneCouplingScheme::Adaptive`] sub-step: identical to
+    /// [`Self::march_staged_substep`] except the zone air's affine ODE is
+    /// advanced with [`Self::estimate_zones_future_temperatures_adaptive`]
+    /// instead of the closed-form exponential update. See
+    /// [`ZoneCouplingScheme::Adaptive`] for why `a`/`b` are held fixed
+    /// across the embedded RK45's internal stages.
+    #[allow(clippy::too_many_arguments)]
+    fn march_adaptive_substep(
+        &self,
+        model: &SimpleModel,
+        state: &mut SimulationState,
+        t_current: &[Float],
+        t_out: Float,
+        t_seconds: Float,
+        wind_direction: Float,
+        wind_speed: Float,
+        alloc: &mut ThermalModelMemory,
+        options: &crate::ode::DormandPrinceOptions,
+    ) -> Result<(), String> {
+        if let Some(sky_clearness) = self.sky_clearness {
+            apply_sky_ir_irradiance(&self.surfaces, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+            apply_sky_ir_irradiance(&self.fenestrations, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+        }
+
+        /* UPDATE SURFACE'S TEMPERATURES */
+        for ((solar_surf, model_surf), memory) in self
+            .surfaces
+            .iter()
+            .zip(model.surfaces.iter())
+            .zip(alloc.surfaces.iter_mut())
+        {
+            let (t_front, t_back) = surface_boundary_temperatures(
+                solar_surf, model, state, t_out, &self.ground, t_seconds,
+            )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+
+            // Update temperatures
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        } // end of iterating surface
+
+        // What  if they are open???
+        for ((solar_surf, model_surf), memory) in self
+            .fenestrations
+            .iter()
+            .zip(model.fenestrations.iter())
+            .zip(alloc.fenestrations.iter_mut())
+        {
+            let (t_front, t_back) = surface_boundary_temperatures(
+                solar_surf, model, state, t_out, &self.ground, t_seconds,
+            )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+
+            // Update temperatures
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        } // end of iterating surface
+
+        /* UPDATE ZONES' TEMPERATURE */
+        // Advanced with an embedded RK45 rather than solved analytically.
+        if !self.zone_mixing.is_empty() {
+            return Err(
+                "ZoneCouplingScheme::Adaptive does not support Zones coupled by zone_mixing links yet"
+                    .to_string(),
+            );
+        }
+        let (a, b, c) = self.calculate_zones_abc(model, state, t_current, t_out, t_seconds, &mut alloc.hvacs)?;
+
+        let future_temperatures = self.estimate_zones_future_temperatures_adaptive(
+            t_current,
+            &c,
+            self.dt,
+            options,
+            |_trial| (a.clone(), b.clone()),
+        );
+        for (i, zone) in self.zones.iter().enumerate() {
+            assert!(
+                !future_temperatures[i].is_nan(),
+                "Future temperatures is NaN"
+            );
+            zone.reference_space
+                .set_dry_bulb_temperature(state, future_temperatures[i])?;
+        }
+
+        Ok(())
+    }
+
+    /// The [`ZoneCouplingScheme::Coupled`] sub-step: fixed-point-iterate
+    /// marching every surface (read-only, via [`ThermalSurfaceData::march_readonly`])
+    /// against the zone-air analytical solve, using this sub-step's own
+    /// (converging) zone-air estimate rather than last sub-step's committed
+    /// one, until the estimate stops moving by more than `tolerance` (or
+    /// `max_iterations` is reached)—then commits the converged surfaces and
+    /// zone temperatures into `state`.
+    ///
+    /// Each fixed-point iteration's per-surface `march_readonly` pass runs
+    /// concurrently across surfaces behind the `parallel` feature, since a
+    /// surface's own chunks already march independently within it (see
+    /// `crate::surface::march_mass_chunk`'s doc comment). Batching chunks of
+    /// equal node-count across surfaces into a single wide `prod_tri_diag_into`
+    /// would save further overhead, but `Matrix` doesn't expose the
+    /// column-stacking/block-slicing this would need, so the cross-surface
+    /// win here is parallelism rather than vectorization.
+    #[allow(clippy::too_many_arguments)]
+    fn march_coupled_substep(
+        &self,
+        model: &SimpleModel,
+        state: &mut SimulationState,
+        t_current: &[Float],
+        t_out: Float,
+        t_seconds: Float,
+        wind_direction: Float,
+        wind_speed: Float,
+        alloc: &mut ThermalModelMemory,
+        max_iterations: usize,
+        tolerance: Float,
+    ) -> Result<(), String> {
+        if let Some(sky_clearness) = self.sky_clearness {
+            apply_sky_ir_irradiance(&self.surfaces, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+            apply_sky_ir_irradiance(&self.fenestrations, state, t_out, &self.ground, t_seconds, sky_clearness)?;
+        }
+
+        // The non-surface contributions to `a`/`b`/`c` (HVAC, luminaires,
+        // infiltration, ventilation, capacitance, zone mixing) don't depend
+        // on surface temperatures, and some of them (e.g.
+        // `ThermalHVAC::calc_cooling_heating_power`) advance their own
+        // `memory` as a side effect—so they must be computed exactly once
+        // per sub-step, not once per fixed-point iteration.
+        let (a0, b0, c) =
+            self.calculate_zones_non_surface_abc(model, state, t_current, t_out, t_seconds, &mut alloc.hvacs)?;
+
+        let mut zone_guess = t_current.to_vec();
+
+        for _ in 0..max_iterations.max(1) {
+            // Every surface's `march_readonly` only reads `state`/`model`/
+            // `zone_guess` and writes its own `memory`, so—unlike the
+            // committing loops below, which write back into `state`—these
+            // are independent and can run concurrently with `rayon` behind
+            // the `parallel` feature, exactly like the per-chunk marching
+            // within a single surface (see `march_mass_chunk`'s callers).
+            #[cfg(feature = "parallel")]
+            let surfaces_iter = self.surfaces.par_iter().zip(alloc.surfaces.par_iter_mut());
+            #[cfg(not(feature = "parallel"))]
+            let surfaces_iter = self.surfaces.iter().zip(alloc.surfaces.iter_mut());
+
+            let surface_pending: Vec<PendingSurface> = surfaces_iter
+                .map(|(surf, memory)| {
+                    march_surface_readonly(
+                        surf,
+                        model,
+                        state,
+                        t_out,
+                        &self.ground,
+                        t_seconds,
+                        &zone_guess,
+                        &alloc.zone_mrt,
+                        wind_direction,
+                        wind_speed,
+                        self.dt,
+                        memory,
+                    )
+                })
+                .collect::<Result<_, String>>()?;
+
+            #[cfg(feature = "parallel")]
+            let fenestrations_iter = self
+                .fenestrations
+                .par_iter()
+                .zip(alloc.fenestrations.par_iter_mut());
+            #[cfg(not(feature = "parallel"))]
+            let fenestrations_iter = self.fenestrations.iter().zip(alloc.fenestrations.iter_mut());
+
+            let fenestration_pending: Vec<PendingSurface> = fenestrations_iter
+                .map(|(surf, memory)| {
+                    march_surface_readonly(
+                        surf,
+                        model,
+                        state,
+                        t_out,
+                        &self.ground,
+                        t_seconds,
+                        &zone_guess,
+                        &alloc.zone_mrt,
+                        wind_direction,
+                        wind_speed,
+                        self.dt,
+                        memory,
+                    )
+                })
+                .collect::<Result<_, String>>()?;
+
+            let mut a = a0.clone();
+            let mut b = b0.clone();
+            accumulate_surface_abc(&self.surfaces, &surface_pending, &mut a, &mut b);
+            accumulate_surface_abc(&self.fenestrations, &fenestration_pending, &mut a, &mut b);
+
+            let next_guess =
+                self.estimate_zones_future_temperatures_multizone(state, t_current, &a, &b, &c, self.dt);
+
+            let max_change = zone_guess
+                .iter()
+                .zip(next_guess.iter())
+                .fold(0.0 as Float, |worst, (old, new)| worst.max((new - old).abs()));
+
+            zone_guess = next_guess;
+
+            if max_change < tolerance {
+                break;
+            }
+        }
+
+        // Converged (or gave up): commit surfaces and zone air at the final
+        // guess, exactly like `march_staged_substep` but driven by
+        // `zone_guess` instead of `t_current`.
+        for ((solar_surf, model_surf), memory) in self
+            .surfaces
+            .iter()
+            .zip(model.surfaces.iter())
+            .zip(alloc.surfaces.iter_mut())
+        {
+            let (t_front, t_back) =
+                surface_coupled_boundary_temperatures(
+                    solar_surf, model, state, t_out, &self.ground, t_seconds, &zone_guess,
+                )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        }
+        for ((solar_surf, model_surf), memory) in self
+            .fenestrations
+            .iter()
+            .zip(model.fenestrations.iter())
+            .zip(alloc.fenestrations.iter_mut())
+        {
+            let (t_front, t_back) =
+                surface_coupled_boundary_temperatures(
+                    solar_surf, model, state, t_out, &self.ground, t_seconds, &zone_guess,
+                )?;
+            let front_mrt = boundary_mrt(
+                &solar_surf.front_boundary,
+                solar_surf.front_space_index,
+                &alloc.zone_mrt,
+                t_front,
+            );
+            let back_mrt = boundary_mrt(
+                &solar_surf.back_boundary,
+                solar_surf.back_space_index,
+                &alloc.zone_mrt,
+                t_back,
+            );
+            let (q_front, q_back) = solar_surf.march(
+                state,
+                t_front,
+                t_back,
+                front_mrt,
+                back_mrt,
+                wind_direction,
+                wind_speed,
+                self.dt,
+                memory,
+                None,
+            )?;
+            model_surf.set_front_convective_heat_flow(state, q_front)?;
+            model_surf.set_back_convective_heat_flow(state, q_back)?;
+        }
+
+        for (i, zone) in self.zones.iter().enumerate() {
+            assert!(!zone_guess[i].is_nan(), "Future temperatures is NaN");
+            zone.reference_space
+                .set_dry_bulb_temperature(state, zone_guess[i])?;
+        }
+
+        Ok(())
+    }
+
     /// Retrieves the dt_subdivisions (i.e. the
     /// number of substimesteps per timestep of this
     /// model)
@@ -400,6 +1329,89 @@ impl ThermalModel {
         self.dt_subdivisions
     }
 
+    /// Overrides the default [`GroundTemperatureModel`], e.g. with one
+    /// derived from the site's actual weather file via
+    /// [`GroundTemperatureModel::from_dry_bulb_series`].
+    pub fn set_ground_temperature_model(&mut self, ground: GroundTemperatureModel) {
+        self.ground = ground;
+    }
+
+    /// Turns on automatic exterior sky/ground IR irradiance (see
+    /// [`Self::sky_clearness`]), with the given clearness fraction (`0` is
+    /// fully overcast/obstructed—sky and air are indistinguishable—and `1`
+    /// is a fully clear sky).
+    pub fn set_sky_clearness(&mut self, sky_clearness: Float) {
+        self.sky_clearness = Some(sky_clearness);
+    }
+
+    /// Sets the conduction [`IntegrationScheme`] used by every surface and
+    /// fenestration's [`ThermalSurfaceData::discretization`], e.g. to
+    /// switch the whole model from the default explicit
+    /// [`IntegrationScheme::RK4`] to an unconditionally-stable
+    /// [`IntegrationScheme::Theta`] (see
+    /// [`IntegrationScheme::backward_euler`]/
+    /// [`IntegrationScheme::crank_nicolson`]).
+    ///
+    /// When `scheme.is_unconditionally_stable()`, this also collapses
+    /// [`Self::dt_subdivisions`] to `1` and widens [`Self::dt`] back up to
+    /// the model's full main timestep, since [`Self::dt_subdivisions`] only
+    /// exists to satisfy the explicit scheme's stability limit (see
+    /// [`Self::new`])—an implicit scheme has none, so this is what actually
+    /// buys the larger, cheaper timestep an implicit solve is for, rather
+    /// than just solving the old subdivided steps more accurately. Note
+    /// this does *not* re-run `discretize_construction`'s node-count search
+    /// (each [`ThermalSurface`] only keeps the already-built
+    /// [`Discretization`], not the [`crate::model::SimpleModel`]/
+    /// `Construction` it came from), so the spatial mesh stays the one
+    /// sized for the explicit case; see [`Discretization::new_with_scheme`]
+    /// for building one sized for an implicit scheme from scratch. Setting
+    /// an explicit scheme back after this does not restore the original
+    /// subdivision count—stability-based sizing can only be recovered by
+    /// rebuilding the model.
+    pub fn set_scheme(&mut self, scheme: crate::discretization::IntegrationScheme) {
+        for surf in self.surfaces.iter_mut() {
+            surf.discretization.scheme = scheme.clone();
+        }
+        for fen in self.fenestrations.iter_mut() {
+            fen.discretization.scheme = scheme.clone();
+        }
+        if scheme.is_unconditionally_stable() {
+            let main_dt = self.dt * self.dt_subdivisions as Float;
+            self.dt_subdivisions = 1;
+            self.dt = main_dt;
+        }
+    }
+
+    /// Attaches a [`VentilationElement`] to the model, coupling its Zone's
+    /// air to the outdoors at a fixed air-change rate.
+    pub fn add_ventilation_element(&mut self, element: VentilationElement) {
+        self.ventilation.push(element);
+    }
+
+    /// Re-evaluates every surface's and fenestration's
+    /// [`crate::surface::ThermochromicCoating`] (if any) against its own
+    /// current node temperature in `state`, switching `front_alphas`/
+    /// `front_emissivity` and `back_alphas`/`back_emissivity` between their
+    /// "low" and "high" states as needed.
+    ///
+    /// [`Self::march`] takes `&self`—several surfaces can be marched
+    /// concurrently behind the `parallel` feature (see
+    /// [`crate::surface::ThermalSurfaceData::march_readonly`])—so it cannot
+    /// call this itself. A caller with a thermochromic coating attached
+    /// must call this once per step with the *previous* step's `state`
+    /// before calling [`Self::march`], the same caller-driven composition
+    /// [`ThermalModelMemory::hvac_memory_mut`] already relies on for
+    /// tank-fed emitters.
+    pub fn update_coatings(&mut self, state: &SimulationState) -> Result<(), String> {
+        for surf in self.surfaces.iter_mut() {
+            surf.update_coatings(state)?;
+        }
+        for fen in self.fenestrations.iter_mut() {
+            fen.update_coatings(state)?;
+        }
+        Ok(())
+    }
+
     /// Retrieves a ThermalZone
     pub fn get_thermal_zone(&self, index: usize) -> Result<&ThermalZone, String> {
         if index >= self.zones.len() {
@@ -456,6 +1468,37 @@ impl ThermalModel {
         &self,
         model: &SimpleModel,
         state: &SimulationState,
+        t_current: &[Float],
+        t_out: Float,
+        t_seconds: Float,
+        hvac_memory: &mut [ThermalHVACMemory],
+    ) -> Result<(Vec<Float>, Vec<Float>, Vec<Float>), String> {
+        let (mut a, mut b, c) =
+            self.calculate_zones_non_surface_abc(model, state, t_current, t_out, t_seconds, hvac_memory)?;
+
+        iterate_surfaces(&self.surfaces, state, &mut a, &mut b)?;
+        iterate_surfaces(&self.fenestrations, state, &mut a, &mut b)?;
+
+        Ok((a, b, c))
+    }
+
+    /// Everything [`Self::calculate_zones_abc`] folds into `a`/`b`/`c`
+    /// except the surface pass (`iterate_surfaces`, read from `state`'s
+    /// already-committed surface temperatures)—i.e. HVAC, luminaires,
+    /// infiltration, ventilation, capacitance, and zone mixing. Factored out
+    /// so [`ZoneCouplingScheme::Coupled`]'s fixed-point iteration can compute
+    /// this part once per sub-step and fold in a different (not-yet-
+    /// committed) surface pass every iteration, without re-running anything
+    /// here that has side effects on `hvac_memory`.
+    #[allow(clippy::type_complexity)]
+    fn calculate_zones_non_surface_abc(
+        &self,
+        model: &SimpleModel,
+        state: &SimulationState,
+        t_current: &[Float],
+        t_out: Float,
+        t_seconds: Float,
+        hvac_memory: &mut [ThermalHVACMemory],
     ) -> Result<(Vec<Float>, Vec<Float>, Vec<Float>), String> {
         let nzones = self.zones.len();
         // Initialize vectors containing a and b
@@ -465,20 +1508,32 @@ impl ThermalModel {
 
         /* Qi */
         // Heating/Cooling
-        for hvac in self.hvacs.iter() {
-            for (target_space_index, heating_cooling) in hvac.calc_cooling_heating_power(state)? {
-                a[target_space_index] += heating_cooling;
+        for (hvac, memory) in self.hvacs.iter().zip(hvac_memory.iter_mut()) {
+            for (target_space_index, heating_cooling, feedback_conductance) in
+                hvac.calc_cooling_heating_power(state, self.dt, t_current, t_out, t_seconds, memory)?
+            {
+                // Linearized around this step's room temperature: an
+                // emitter whose convective output falls off as the room
+                // warms (e.g. `WetEmitter`) feeds that sensitivity back
+                // into the zone's own `b[i]`, rather than treating this
+                // step's delivered heat as independent of the (not yet
+                // known) future room temperature.
+                a[target_space_index] +=
+                    heating_cooling + feedback_conductance * t_current[target_space_index];
+                b[target_space_index] += feedback_conductance;
             }
             // heating through air supply?
         }
-        // Luminaires
+        // Luminaires: only the convective share goes straight into the zone
+        // air term; the radiant share is spread onto the Zone's interior
+        // surfaces by `update_zones_radiant_terms`/`distribute_radiant_gains`.
         for luminaire in self.luminaires.iter() {
             let index = luminaire.target_space_index;
             let consumption = luminaire
                 .parent
                 .power_consumption(state)
                 .expect("Luminaire has no Power Consumption state");
-            a[index] += consumption;
+            a[index] += consumption * (1.0 - ThermalLuminaire::RADIANT_FRACTION);
         }
 
         let air = crate::gas::AIR;
@@ -499,17 +1554,35 @@ impl ThermalModel {
             }
 
             // ventilation
-            if let Some(t_vent_inwards) = space.ventilation_temperature(state) {
+            if let Some(mut t_vent_inwards) = space.ventilation_temperature(state) {
                 let v_vent = space
                     .ventilation_volume(state)
                     .expect("Space has ventilation temperature but not volume");
                 let cp_vent_inwards = air.heat_capacity(t_vent_inwards + 273.15);
                 let rho_vent_inwards = air.density(t_vent_inwards + 273.15);
-                a[i] += rho_vent_inwards * v_vent * cp_vent_inwards * t_vent_inwards;
-                b[i] += rho_vent_inwards * v_vent * cp_vent_inwards;
-            }
+                let mass_flow_vent = rho_vent_inwards * v_vent;
+
+                // Ductwork/MVHR distribution losses: correct the
+                // scheduled supply temperature for whatever it picks up
+                // (or loses) travelling from its source to this Zone,
+                // rather than assuming perfect delivery.
+                if let Some(duct) = &zone.ventilation_duct {
+                    let t_environment = match duct.duct.environment {
+                        crate::duct::DuctEnvironment::Ambient => t_out,
+                        crate::duct::DuctEnvironment::Zone(z) => t_current[z],
+                    };
+                    t_vent_inwards = duct.corrected_supply_temperature(
+                        t_vent_inwards,
+                        t_current[i],
+                        t_environment,
+                        mass_flow_vent,
+                        cp_vent_inwards,
+                    );
+                }
 
-            // Mixing with other zones
+                a[i] += mass_flow_vent * cp_vent_inwards * t_vent_inwards;
+                b[i] += mass_flow_vent * cp_vent_inwards;
+            }
 
             /* CAPACITANCE */
             let temp = space
@@ -518,50 +1591,119 @@ impl ThermalModel {
             c[i] = zone.mcp(temp);
         }
 
-        /* SURFACES */
-        fn iterate_surfaces<T: SurfaceTrait>(
-            surfaces: &[ThermalSurfaceData<T>],
-            state: &SimulationState,
-            a: &mut [Float],
-            b: &mut [Float],
-        ) -> Result<(), String> {
-            for surface in surfaces {
-                let parent = &surface.parent;
-                let h_front = parent.front_convection_coefficient(state).unwrap();
-                let h_back = parent.back_convection_coefficient(state).unwrap();
-
-                let ai = surface.area;
-                // if front leads to a Zone
-                if let Some(Boundary::Space { .. }) = &surface.front_boundary {
-                    let z_index = surface.front_space_index.unwrap(); // Should have one of these if boundary is Space
-
-                    let temp = surface.parent.front_temperature(state);
-                    a[z_index] += h_front * ai * temp;
-                    b[z_index] += h_front * ai;
-                }
-
-                // if back leads to a Zone
-                if let Some(Boundary::Space { .. }) = &surface.back_boundary {
-                    let z_index = surface.back_space_index.unwrap(); // Should have one of these if boundary is Space
-
-                    let temp = surface.parent.back_temperature(state);
-                    a[z_index] += h_back * ai * temp;
-                    b[z_index] += h_back * ai;
-                }
+        /* AIR MIXTURE WITH OTHER ZONES */
+        // Each link is a symmetric conductance between two air nodes: the
+        // air arriving at one zone comes from the other zone at its own
+        // current temperature, and vice-versa, both driven by the same
+        // mass flow.
+        for mix in self.zone_mixing.iter() {
+            if let Some(mass_flow) = mix.parent.mass_flow(state) {
+                let i = mix.zone_a_index;
+                let j = mix.zone_b_index;
+                let ti = t_current[i];
+                let tj = t_current[j];
+
+                let cp_j = air.heat_capacity(tj + 273.15);
+                a[i] += mass_flow * cp_j * tj;
+                b[i] += mass_flow * cp_j;
+
+                let cp_i = air.heat_capacity(ti + 273.15);
+                a[j] += mass_flow * cp_i * ti;
+                b[j] += mass_flow * cp_i;
             }
-            Ok(())
         }
 
-        iterate_surfaces(&self.surfaces, state, &mut a, &mut b)?;
-        iterate_surfaces(&self.fenestrations, state, &mut a, &mut b)?;
-
-        /* AIR MIXTURE WITH OTHER ZONES */
-        // unimplemented();
+        /* FIXED-ACH VENTILATION/INFILTRATION ELEMENTS */
+        // Additional to (and independent of) any infiltration/ventilation
+        // schedules above: a fixed air-change rate against the Zone's own
+        // volume, at a supply temperature that may itself depend on the
+        // Zone's current air temperature (e.g. MVHR heat recovery).
+        let hour_of_day = (t_seconds / 3600.0) % 24.0;
+        for element in self.ventilation.iter() {
+            let i = element.zone_index();
+            let zone_volume = self.zones[i].volume();
+            let cp_out = air.heat_capacity(t_out + 273.15);
+            let rho_out = air.density(t_out + 273.15);
+            let (g, t_supply) = element.conductance_and_supply_temperature(
+                zone_volume,
+                t_current[i],
+                t_out,
+                rho_out,
+                cp_out,
+                hour_of_day,
+            );
+            a[i] += g * t_supply;
+            b[i] += g;
+        }
 
         // RETURN
         Ok((a, b, c))
     }
 
+    /// Computes each Zone's mean radiant temperature and the radiant share
+    /// of its internal gains, ahead of marching its surfaces this sub-step.
+    ///
+    /// The MRT uses the simplified "radiant star node" method: every
+    /// interior-facing surface of a Zone is treated as exchanging long-wave
+    /// radiation with a single node at the area-weighted mean of their
+    /// temperatures (as last left by the previous sub-step's marching),
+    /// rather than with every other surface individually; this is written
+    /// into `zone_mrt`, to be read back by [`boundary_mrt`] when marching
+    /// surfaces. A Zone with no interior surfaces falls back to its own air
+    /// temperature (`t_current`).
+    ///
+    /// `zone_radiant_gain` is filled with each Zone's radiant internal gains
+    /// (W): the radiant share of luminaires' power consumption and of
+    /// [`ThermalHVAC::WetDistribution`] emitters' delivered output (from
+    /// `hvac_memory`, populated by the previous sub-step's
+    /// [`Self::calculate_zones_abc`]). Returns the total interior surface
+    /// area of each Zone, so the caller can turn that gain into a flux
+    /// density for [`distribute_radiant_gains`].
+    fn update_zones_radiant_terms(
+        &self,
+        state: &SimulationState,
+        t_current: &[Float],
+        hvac_memory: &[ThermalHVACMemory],
+        zone_mrt: &mut [Float],
+        zone_radiant_gain: &mut [Float],
+    ) -> Vec<Float> {
+        let nzones = self.zones.len();
+        let mut weighted_temperature = vec![0.0; nzones];
+        let mut area = vec![0.0; nzones];
+
+        accumulate_interior_terms(&self.surfaces, state, &mut weighted_temperature, &mut area);
+        accumulate_interior_terms(
+            &self.fenestrations,
+            state,
+            &mut weighted_temperature,
+            &mut area,
+        );
+
+        for i in 0..nzones {
+            zone_mrt[i] = if area[i] > 1e-9 {
+                weighted_temperature[i] / area[i]
+            } else {
+                t_current[i]
+            };
+        }
+
+        for (hvac, memory) in self.hvacs.iter().zip(hvac_memory.iter()) {
+            if let Some((target_space_index, radiant)) = hvac.radiant_gain(memory) {
+                zone_radiant_gain[target_space_index] += radiant;
+            }
+        }
+        for luminaire in self.luminaires.iter() {
+            let consumption = luminaire
+                .parent
+                .power_consumption(state)
+                .expect("Luminaire has no Power Consumption state");
+            zone_radiant_gain[luminaire.target_space_index] +=
+                consumption * ThermalLuminaire::RADIANT_FRACTION;
+        }
+
+        area
+    }
+
     /// Retrieves a vector of the current temperatures of all the Zones as
     /// registered in the Simulation State
     fn get_current_zones_temperatures(&self, state: &SimulationState) -> Vec<Float> {
@@ -638,6 +1780,146 @@ impl ThermalModel {
 
         ret
     }
+
+    /// Undoes the diagonal folding that [`Self::calculate_zones_non_surface_abc`]
+    /// applies for `self.zone_mixing` links—each zone simply picks up its
+    /// neighbour's *current* temperature as an extra driving term—and
+    /// rebuilds it as proper matrix coupling instead: an off-diagonal
+    /// conductance between the two (still-unknown) future zone
+    /// temperatures. Returns the adjusted `a` together with the conductance
+    /// matrix `B` (dense, `nzones x nzones`) such that the zone heat
+    /// balance is `c·dT/dt = a - B·T`, with `B`'s diagonal equal to `b` and
+    /// its off-diagonals `-G_ij` for each mixing link.
+    fn build_zone_coupling_matrix(
+        &self,
+        state: &SimulationState,
+        t_current: &[Float],
+        a: &[Float],
+        b: &[Float],
+    ) -> (Vec<Float>, Vec<Vec<Float>>) {
+        let nzones = self.zones.len();
+        let mut a = a.to_vec();
+        let mut mat = vec![vec![0.0; nzones]; nzones];
+        for i in 0..nzones {
+            mat[i][i] = b[i];
+        }
+
+        let air = crate::gas::AIR;
+        for mix in self.zone_mixing.iter() {
+            if let Some(mass_flow) = mix.parent.mass_flow(state) {
+                let i = mix.zone_a_index;
+                let j = mix.zone_b_index;
+                let ti = t_current[i];
+                let tj = t_current[j];
+
+                let cp_j = air.heat_capacity(tj + 273.15);
+                a[i] -= mass_flow * cp_j * tj;
+                mat[i][j] -= mass_flow * cp_j;
+
+                let cp_i = air.heat_capacity(ti + 273.15);
+                a[j] -= mass_flow * cp_i * ti;
+                mat[j][i] -= mass_flow * cp_i;
+            }
+        }
+
+        (a, mat)
+    }
+
+    /// The coupled-zones generalization of [`Self::estimate_zones_future_temperatures`]:
+    /// solves `c·dT/dt = a - B·T` for a (possibly dense) conductance matrix
+    /// `B`, using the same augmented-matrix/matrix-exponential trick as
+    /// [`crate::surface::expm_march`] does for a massive wall chunk's nodes.
+    /// Builds the augmented `(n+1) x (n+1)` block
+    /// $`\left[\begin{smallmatrix}-C^{-1}B & C^{-1}a \\ 0 & 0\end{smallmatrix}\right]\Delta t`$,
+    /// exponentiates it once, and reads the propagated temperatures off its
+    /// last column—exact (up to the Padé/scaling-and-squaring tolerance)
+    /// and unconditionally stable, and naturally preserving "a zone with no
+    /// conductance at all keeps its temperature" without needing to detect
+    /// that case separately.
+    fn estimate_zones_future_temperatures_coupled(
+        &self,
+        t_current: &[Float],
+        a: &[Float],
+        mat: &[Vec<Float>],
+        c: &[Float],
+        future_time: Float,
+    ) -> Vec<Float> {
+        let nzones = self.zones.len();
+        let mut augmented = vec![vec![0.0; nzones + 1]; nzones];
+        for i in 0..nzones {
+            for j in 0..nzones {
+                augmented[i][j] = -mat[i][j] / c[i] * future_time;
+            }
+            augmented[i][nzones] = a[i] / c[i] * future_time;
+        }
+        // Square the augmented block up to `(n+1) x (n+1)`, with a zero
+        // bottom row, as `expm` expects.
+        let mut square = augmented;
+        square.push(vec![0.0; nzones + 1]);
+
+        let propagated = crate::surface::expm(&square);
+
+        let mut ret = Vec::with_capacity(nzones);
+        for i in 0..nzones {
+            let mut v = propagated[i][nzones];
+            for j in 0..nzones {
+                v += propagated[i][j] * t_current[j];
+            }
+            ret.push(v);
+        }
+        ret
+    }
+
+    /// Estimates each Zone's future temperature, dispatching to
+    /// [`Self::estimate_zones_future_temperatures`] when no
+    /// [`Self::zone_mixing`] links are present (the common case: `B` is
+    /// diagonal, so the cheap per-zone closed form is exact), or to the
+    /// coupled [`Self::estimate_zones_future_temperatures_coupled`]
+    /// otherwise.
+    fn estimate_zones_future_temperatures_multizone(
+        &self,
+        state: &SimulationState,
+        t_current: &[Float],
+        a: &[Float],
+        b: &[Float],
+        c: &[Float],
+        future_time: Float,
+    ) -> Vec<Float> {
+        if self.zone_mixing.is_empty() {
+            return self.estimate_zones_future_temperatures(t_current, a, b, c, future_time);
+        }
+        let (a, mat) = self.build_zone_coupling_matrix(state, t_current, a, b);
+        self.estimate_zones_future_temperatures_coupled(t_current, &a, &mat, c, future_time)
+    }
+
+    /// An alternative to [`Self::estimate_zones_future_temperatures`] for
+    /// when `a`/`b` can't be treated as constant over the timestep—e.g.
+    /// natural-convection coefficients that depend on the surface-to-air
+    /// ΔT, or linearized longwave exchange that should really be `T⁴`.
+    /// Rather than the analytical exponential update, this integrates
+    /// `dT/dt = (a(T) - b(T)·T)/c` with an adaptive Dormand–Prince (RK45)
+    /// scheme (see [`crate::ode::integrate`]), re-evaluating `a`/`b` at
+    /// every stage via `recompute_ab`, which is given the Zones' trial
+    /// temperatures and must return fresh `(a, b)` vectors from the
+    /// current surface/HVAC/etc. state.
+    pub fn estimate_zones_future_temperatures_adaptive(
+        &self,
+        t_current: &[Float],
+        c: &[Float],
+        future_time: Float,
+        options: &crate::ode::DormandPrinceOptions,
+        mut recompute_ab: impl FnMut(&[Float]) -> (Vec<Float>, Vec<Float>),
+    ) -> Vec<Float> {
+        crate::ode::integrate(future_time, t_current, options, move |t| {
+            let (a, b) = recompute_ab(t);
+            let n = t.len();
+            let mut dtdt = Vec::with_capacity(n);
+            for i in 0..n {
+                dtdt.push((a[i] - b[i] * t[i]) / c[i]);
+            }
+            dtdt
+        })
+    }
 }
 
 /***********/
@@ -674,14 +1956,17 @@ mod testing {
 
         let n: usize = 1;
         let thermal_model =
-            ThermalModel::new(&META_OPTIONS, (), &simple_model, &mut state_header, n).unwrap();
+            ThermalModel::new(&META_OPTIONS, ZoneCouplingScheme::Staged, &simple_model, &mut state_header, n)
+                .unwrap();
         let state = state_header.take_values().unwrap();
         // MAP THE STATE
         // model.map_simulation_state(&mut state).unwrap();
+        let mut alloc = thermal_model.allocate_memory().unwrap();
+        let t_current = thermal_model.get_current_zones_temperatures(&state);
 
         // Test
         let (a, b, c) = thermal_model
-            .calculate_zones_abc(&simple_model, &state)
+            .calculate_zones_abc(&simple_model, &state, &t_current, 10.0, 0.0, &mut alloc.hvacs)
             .unwrap();
         assert_eq!(a.len(), 1);
         assert_eq!(c.len(), 1);
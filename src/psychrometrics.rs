@@ -0,0 +1,103 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Small, self-contained moist-air property calculations. This crate's
+//! [`crate::gas`] module and `SimulationState` have no notion of humidity
+//! today, so these are plain functions of caller-supplied dry-bulb
+//! temperature and relative humidity rather than anything wired into a
+//! Zone's own state—see [`crate::heating_cooling::EvaporativeCooler`] for
+//! where that matters.
+
+use crate::Float;
+
+// With `std` disabled, `f32`/`f64` have no inherent `sqrt`/`exp`/`atan`/
+// `powf`—those need a platform libm, which `std` provides but `core`
+// doesn't. Bringing `num_traits::Float` into scope (imported anonymously
+// to avoid colliding with this crate's own `Float` type alias above)
+// supplies the same-named methods via the `"libm"` feature's pure-Rust
+// implementation instead, so the call sites below don't change.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num_traits::Float as _;
+
+/// Approximates the wet-bulb temperature (C) from dry-bulb temperature
+/// `t_db` (C) and relative humidity `rh` (`0-100`), using the empirical
+/// correlation from Stull, R. (2011), "Wet-Bulb Temperature from Relative
+/// Humidity and Air Temperature", *Journal of Applied Meteorology and
+/// Climatology*. Valid roughly over `-20C` to `50C` and `5%` to `99%` RH;
+/// outside that range it's still a reasonable estimate but the paper's
+/// quoted error bound (about `1C`) no longer applies.
+pub fn wet_bulb_temperature(t_db: Float, rh: Float) -> Float {
+    let rh = rh.clamp(0.0, 100.0);
+    t_db * (0.151977 * (rh + 8.313659).sqrt()).atan() + (t_db + rh).atan() - (rh - 1.676331).atan()
+        + 0.00391838 * rh.powf(1.5) * (0.023101 * rh).atan()
+        - 4.686035
+}
+
+/// The saturation vapor pressure of water (in `Pa`) at temperature `t` (°C),
+/// via the Magnus-Tetens approximation (August-Roche-Magnus form). Used
+/// unmodified over ice as well as liquid water—a small error near and below
+/// freezing that [`crate::condensation`] inherits, since this crate has no
+/// separate ice-phase saturation curve.
+pub fn saturation_vapor_pressure(t: Float) -> Float {
+    611.2 * (17.62 * t / (243.12 + t)).exp()
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn wet_bulb_equals_dry_bulb_at_saturation() {
+        // At 100% RH there's no evaporative cooling potential left, so
+        // wet-bulb should sit very close to dry-bulb.
+        let t_db = 25.0;
+        let t_wb = wet_bulb_temperature(t_db, 100.0);
+        assert!((t_wb - t_db).abs() < 1.0);
+    }
+
+    #[test]
+    fn wet_bulb_is_below_dry_bulb_in_dry_air() {
+        let t_db = 35.0;
+        let t_wb = wet_bulb_temperature(t_db, 20.0);
+        assert!(t_wb < t_db);
+    }
+
+    #[test]
+    fn wet_bulb_drops_as_humidity_drops() {
+        let t_db = 30.0;
+        let wetter = wet_bulb_temperature(t_db, 60.0);
+        let drier = wet_bulb_temperature(t_db, 20.0);
+        assert!(drier < wetter);
+    }
+
+    #[test]
+    fn saturation_vapor_pressure_matches_known_point() {
+        // Saturation pressure of water at 20C is about 2339 Pa.
+        let p_sat = saturation_vapor_pressure(20.0);
+        assert!((p_sat - 2339.0).abs() / 2339.0 < 0.02);
+    }
+
+    #[test]
+    fn saturation_vapor_pressure_grows_with_temperature() {
+        let cooler = saturation_vapor_pressure(5.0);
+        let warmer = saturation_vapor_pressure(25.0);
+        assert!(warmer > cooler);
+    }
+}
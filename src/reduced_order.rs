@@ -0,0 +1,478 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A projection-based (POD) reduced-order surrogate for repeatedly marching
+//! the *same* [`crate::discretization::Discretization`]—useful for parametric
+//! studies and annual simulations that march it millions of times.
+//!
+//! Offline, the full node-level solver is run over a representative driving
+//! condition sequence and the node-temperature vectors are collected as
+//! snapshot columns; [`ReducedOrderModel::from_snapshots`] computes a POD
+//! basis via the "method of snapshots" (eigen-decomposing the small
+//! `n_snapshots x n_snapshots` Gram matrix rather than the large node-space
+//! one). Online, [`ReducedOrderModel::project_operators`] projects `K` and `C`
+//! into the reduced basis, and [`ReducedOrderModel::reconstruct`] recovers an
+//! approximate node-temperature vector `T ≈ Φ·a` from the reduced state `a`.
+
+use crate::Float;
+
+/// A POD basis and the reduced operators built from it, acting as a drop-in
+/// surrogate for the full node-level system of a [`crate::discretization::Discretization`].
+pub struct ReducedOrderModel {
+    /// The POD basis: `order()` vectors, each of length `n_nodes`.
+    basis: Vec<Vec<Float>>,
+
+    /// `Φᵀ·K·Φ`, an `order() x order()` reduced conductance matrix. Empty until
+    /// [`Self::project_operators`] is called.
+    pub k_r: Vec<Vec<Float>>,
+
+    /// `Φᵀ·C·Φ`, an `order() x order()` reduced mass matrix. Empty until
+    /// [`Self::project_operators`] is called.
+    pub c_r: Vec<Vec<Float>>,
+
+    /// The fraction of the snapshot energy captured by the basis actually kept
+    /// (always `>= ` the `energy_fraction` requested at construction, modulo
+    /// floating point).
+    pub captured_energy_fraction: Float,
+}
+
+impl ReducedOrderModel {
+    /// Number of nodes of the full-order model this surrogate was built for.
+    pub fn n_nodes(&self) -> usize {
+        self.basis.first().map(|v| v.len()).unwrap_or(0)
+    }
+
+    /// The order `r` of the reduced model (number of POD modes kept), which is
+    /// typically far below the node count.
+    pub fn order(&self) -> usize {
+        self.basis.len()
+    }
+
+    /// Builds a POD basis from snapshot node-temperature vectors (one per
+    /// driving-condition sample; all must have the same length), keeping the
+    /// leading modes that capture at least `energy_fraction` (e.g. `0.999`) of
+    /// the snapshot energy.
+    ///
+    /// Uses the method of snapshots: eigen-decomposes the `n_snapshots x
+    /// n_snapshots` Gram matrix `SᵀS` (via the cyclic Jacobi eigenvalue
+    /// algorithm, since it is small and symmetric), then lifts the resulting
+    /// eigenvectors back to node-space and normalizes them, which is far
+    /// cheaper than eigen-decomposing `SSᵀ` directly when `n_nodes` is large.
+    ///
+    /// # Panics
+    /// Panics if `snapshots` is empty, or if the snapshots are not all the
+    /// same length.
+    pub fn from_snapshots(snapshots: &[Vec<Float>], energy_fraction: Float) -> Self {
+        let n_snap = snapshots.len();
+        assert!(n_snap > 0, "Need at least one snapshot to build a POD basis");
+        let n_nodes = snapshots[0].len();
+        for s in snapshots {
+            assert_eq!(s.len(), n_nodes, "All snapshots must have the same length");
+        }
+
+        // Gram matrix G = S^T S, of size n_snap x n_snap
+        let mut gram = vec![vec![0.0; n_snap]; n_snap];
+        for i in 0..n_snap {
+            for j in i..n_snap {
+                let mut dot = 0.0;
+                for k in 0..n_nodes {
+                    dot += snapshots[i][k] * snapshots[j][k];
+                }
+                gram[i][j] = dot;
+                gram[j][i] = dot;
+            }
+        }
+
+        let (eigenvalues, eigenvectors) = jacobi_eigen_symmetric(gram);
+
+        // Sort indices by descending eigenvalue.
+        let mut order: Vec<usize> = (0..n_snap).collect();
+        order.sort_by(|&a, &b| eigenvalues[b].partial_cmp(&eigenvalues[a]).unwrap());
+
+        let total_energy: Float = eigenvalues.iter().map(|v| v.max(0.0)).sum();
+        let mut basis = Vec::new();
+        let mut kept_energy = 0.0;
+        for &idx in &order {
+            let lambda = eigenvalues[idx].max(0.0);
+            if lambda <= 1e-14 {
+                continue;
+            }
+            // Lift the snapshot-space eigenvector back to node-space: mode = S * v / sqrt(lambda)
+            let v = &eigenvectors[idx];
+            let mut mode = vec![0.0; n_nodes];
+            for (snap_i, coef) in v.iter().enumerate() {
+                for k in 0..n_nodes {
+                    mode[k] += coef * snapshots[snap_i][k];
+                }
+            }
+            let scale = 1.0 / lambda.sqrt();
+            for m in mode.iter_mut() {
+                *m *= scale;
+            }
+            basis.push(mode);
+            kept_energy += lambda;
+
+            if total_energy > 0.0 && kept_energy / total_energy >= energy_fraction {
+                break;
+            }
+        }
+        // If nothing was kept (e.g. all-zero snapshots), keep at least one mode.
+        if basis.is_empty() {
+            basis.push(vec![0.0; n_nodes]);
+        }
+
+        let captured_energy_fraction = if total_energy > 0.0 {
+            kept_energy / total_energy
+        } else {
+            1.0
+        };
+
+        Self {
+            basis,
+            k_r: Vec::new(),
+            c_r: Vec::new(),
+            captured_energy_fraction,
+        }
+    }
+
+    /// Projects the full-order `K` and `C` matrices (each `n_nodes x n_nodes`,
+    /// row-major) into the POD basis, computing `K_r = ΦᵀKΦ` and `C_r = ΦᵀCΦ`.
+    pub fn project_operators(&mut self, k: &[Vec<Float>], c: &[Vec<Float>]) {
+        let n = self.n_nodes();
+        assert_eq!(k.len(), n);
+        assert_eq!(c.len(), n);
+
+        let r = self.order();
+        self.k_r = vec![vec![0.0; r]; r];
+        self.c_r = vec![vec![0.0; r]; r];
+
+        // K*Phi_j and C*Phi_j, then dot with Phi_i
+        for j in 0..r {
+            let mut k_phi_j = vec![0.0; n];
+            let mut c_phi_j = vec![0.0; n];
+            for row in 0..n {
+                let mut ksum = 0.0;
+                let mut csum = 0.0;
+                for col in 0..n {
+                    let phi = self.basis[j][col];
+                    ksum += k[row][col] * phi;
+                    csum += c[row][col] * phi;
+                }
+                k_phi_j[row] = ksum;
+                c_phi_j[row] = csum;
+            }
+            for i in 0..r {
+                let mut kdot = 0.0;
+                let mut cdot = 0.0;
+                for row in 0..n {
+                    kdot += self.basis[i][row] * k_phi_j[row];
+                    cdot += self.basis[i][row] * c_phi_j[row];
+                }
+                self.k_r[i][j] = kdot;
+                self.c_r[i][j] = cdot;
+            }
+        }
+    }
+
+    /// Projects a full node-space source/boundary vector `q` into reduced
+    /// coordinates: `q_r = Φᵀq`.
+    pub fn project_source(&self, q: &[Float]) -> Vec<Float> {
+        self.basis
+            .iter()
+            .map(|mode| mode.iter().zip(q.iter()).map(|(m, v)| m * v).sum())
+            .collect()
+    }
+
+    /// Reconstructs an approximate full node-temperature vector `T ≈ Φ·a` from
+    /// a reduced state `a` (length `order()`).
+    pub fn reconstruct(&self, reduced_state: &[Float]) -> Vec<Float> {
+        assert_eq!(reduced_state.len(), self.order());
+        let n = self.n_nodes();
+        let mut t = vec![0.0; n];
+        for (mode, a) in self.basis.iter().zip(reduced_state.iter()) {
+            for (ti, mi) in t.iter_mut().zip(mode.iter()) {
+                *ti += a * mi;
+            }
+        }
+        t
+    }
+
+    /// The residual (in the same units as the temperature field) between a
+    /// full-order snapshot and what the reduced basis can represent of it,
+    /// i.e. `‖T - Φ·Φᵀ·T‖`. Callers should fall back to the full node-level
+    /// model automatically whenever this grows beyond an acceptable tolerance.
+    pub fn residual(&self, full_state: &[Float]) -> Float {
+        let reduced = self.project_source(full_state);
+        let reconstructed = self.reconstruct(&reduced);
+        full_state
+            .iter()
+            .zip(reconstructed.iter())
+            .map(|(a, b)| (a - b).powi(2))
+            .sum::<Float>()
+            .sqrt()
+    }
+
+    /// Advances a reduced state `a` (length [`Self::order`]) forward by `dt`
+    /// using classic explicit RK4 on the projected ODE `C_r da/dt = K_r a +
+    /// q_r`—the same stage structure as `crate::surface::rk4`, but on the
+    /// small `order() x order()` reduced system instead of the full
+    /// node-space one, which is the whole point of this module's `O(n) ->
+    /// O(r)` cost cut. `q_full` is the full node-space source/boundary
+    /// vector for this step (solar gains, boundary convection/radiation),
+    /// projected into reduced coordinates via [`Self::project_source`].
+    ///
+    /// # Panics
+    /// Panics if [`Self::project_operators`] hasn't been called yet (`k_r`
+    /// empty), or if `c_r` is singular.
+    pub fn march_rk4(&self, reduced_state: &[Float], q_full: &[Float], dt: Float) -> Vec<Float> {
+        let r = self.order();
+        assert_eq!(
+            self.k_r.len(),
+            r,
+            "project_operators() must be called before march_rk4()"
+        );
+        let q_r = self.project_source(q_full);
+
+        // Rearrange into k' = dt * C_r^{-1} * K_r, q' = dt * C_r^{-1} * q_r,
+        // mirroring `crate::surface::rearrange_k`.
+        let c_r_inv = invert_dense(&self.c_r);
+        let k_prime = scale_matrix(&matmul(&c_r_inv, &self.k_r), dt);
+        let q_prime: Vec<Float> = matvec(&c_r_inv, &q_r).iter().map(|v| v * dt).collect();
+
+        let stage = |a: &[Float]| -> Vec<Float> {
+            let mut out = matvec(&k_prime, a);
+            for (o, q) in out.iter_mut().zip(&q_prime) {
+                *o += q;
+            }
+            out
+        };
+
+        let k1 = stage(reduced_state);
+        let a2: Vec<Float> = reduced_state.iter().zip(&k1).map(|(a, k)| a + 0.5 * k).collect();
+        let k2 = stage(&a2);
+        let a3: Vec<Float> = reduced_state.iter().zip(&k2).map(|(a, k)| a + 0.5 * k).collect();
+        let k3 = stage(&a3);
+        let a4: Vec<Float> = reduced_state.iter().zip(&k3).map(|(a, k)| a + k).collect();
+        let k4 = stage(&a4);
+
+        reduced_state
+            .iter()
+            .enumerate()
+            .map(|(i, a)| a + k1[i] / 6.0 + k2[i] / 3.0 + k3[i] / 3.0 + k4[i] / 6.0)
+            .collect()
+    }
+}
+
+/// Inverts a small, dense `n x n` matrix via Gauss-Jordan elimination with
+/// partial pivoting. Adequate for the `order() x order()` reduced mass
+/// matrix [`ReducedOrderModel::march_rk4`] needs to invert every call, since
+/// `order()` is, by construction, far smaller than the full node count.
+fn invert_dense(m: &[Vec<Float>]) -> Vec<Vec<Float>> {
+    let n = m.len();
+    let mut a = m.to_vec();
+    let mut inv = vec![vec![0.0; n]; n];
+    for (i, row) in inv.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    for col in 0..n {
+        let pivot_row = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap())
+            .unwrap();
+        a.swap(col, pivot_row);
+        inv.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        assert!(pivot.abs() > 1e-14, "Cannot invert a singular reduced mass matrix C_r");
+        for v in a[col].iter_mut() {
+            *v /= pivot;
+        }
+        for v in inv[col].iter_mut() {
+            *v /= pivot;
+        }
+
+        for row in 0..n {
+            if row == col {
+                continue;
+            }
+            let factor = a[row][col];
+            if factor == 0.0 {
+                continue;
+            }
+            for k in 0..n {
+                a[row][k] -= factor * a[col][k];
+                inv[row][k] -= factor * inv[col][k];
+            }
+        }
+    }
+    inv
+}
+
+fn matmul(a: &[Vec<Float>], b: &[Vec<Float>]) -> Vec<Vec<Float>> {
+    let n = a.len();
+    let k = b.len();
+    let m = if k == 0 { 0 } else { b[0].len() };
+    let mut out = vec![vec![0.0; m]; n];
+    for i in 0..n {
+        for j in 0..m {
+            let mut sum = 0.0;
+            for l in 0..k {
+                sum += a[i][l] * b[l][j];
+            }
+            out[i][j] = sum;
+        }
+    }
+    out
+}
+
+fn matvec(m: &[Vec<Float>], v: &[Float]) -> Vec<Float> {
+    m.iter()
+        .map(|row| row.iter().zip(v).map(|(a, b)| a * b).sum())
+        .collect()
+}
+
+fn scale_matrix(m: &[Vec<Float>], s: Float) -> Vec<Vec<Float>> {
+    m.iter().map(|row| row.iter().map(|v| v * s).collect()).collect()
+}
+
+/// Cyclic Jacobi eigenvalue algorithm for a small, dense, symmetric matrix.
+/// Returns `(eigenvalues, eigenvectors)`, where `eigenvectors[i]` is the
+/// eigenvector associated to `eigenvalues[i]`. Adequate for the snapshot Gram
+/// matrices this module deals with, which are `n_snapshots x n_snapshots` and
+/// thus small compared to the node count.
+pub(crate) fn jacobi_eigen_symmetric(mut a: Vec<Vec<Float>>) -> (Vec<Float>, Vec<Vec<Float>>) {
+    let n = a.len();
+    let mut v = vec![vec![0.0; n]; n];
+    for (i, row) in v.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+
+    const MAX_SWEEPS: usize = 100;
+    for _ in 0..MAX_SWEEPS {
+        // Sum the off-diagonal magnitudes; stop once they are negligible.
+        let mut off_diag_sum = 0.0;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                off_diag_sum += a[p][q].abs();
+            }
+        }
+        if off_diag_sum < 1e-12 {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                if a[p][q].abs() < 1e-14 {
+                    continue;
+                }
+                let theta = (a[q][q] - a[p][p]) / (2.0 * a[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let t = if theta == 0.0 { 1.0 } else { t };
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                let a_pp = a[p][p];
+                let a_qq = a[q][q];
+                let a_pq = a[p][q];
+
+                a[p][p] = c * c * a_pp - 2.0 * s * c * a_pq + s * s * a_qq;
+                a[q][q] = s * s * a_pp + 2.0 * s * c * a_pq + c * c * a_qq;
+                a[p][q] = 0.0;
+                a[q][p] = 0.0;
+
+                for i in 0..n {
+                    if i != p && i != q {
+                        let a_ip = a[i][p];
+                        let a_iq = a[i][q];
+                        a[i][p] = c * a_ip - s * a_iq;
+                        a[p][i] = a[i][p];
+                        a[i][q] = s * a_ip + c * a_iq;
+                        a[q][i] = a[i][q];
+                    }
+                }
+                for i in 0..n {
+                    let v_ip = v[i][p];
+                    let v_iq = v[i][q];
+                    v[i][p] = c * v_ip - s * v_iq;
+                    v[i][q] = s * v_ip + c * v_iq;
+                }
+            }
+        }
+    }
+
+    let eigenvalues: Vec<Float> = (0..n).map(|i| a[i][i]).collect();
+    let eigenvectors: Vec<Vec<Float>> = (0..n).map(|i| (0..n).map(|j| v[j][i]).collect()).collect();
+    (eigenvalues, eigenvectors)
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_pod_basis_reconstructs_snapshots_used_to_build_it() {
+        // Snapshots that only vary along a single direction should be captured
+        // almost exactly by a single POD mode.
+        let snapshots = vec![
+            vec![1.0, 2.0, 3.0, 4.0],
+            vec![2.0, 4.0, 6.0, 8.0],
+            vec![0.5, 1.0, 1.5, 2.0],
+        ];
+        let rom = ReducedOrderModel::from_snapshots(&snapshots, 0.999);
+        assert_eq!(rom.order(), 1);
+        assert!(rom.captured_energy_fraction > 0.999);
+
+        for snap in &snapshots {
+            let residual = rom.residual(snap);
+            assert!(residual < 1e-6, "residual = {residual}");
+        }
+    }
+
+    #[test]
+    fn test_pod_basis_needs_more_modes_for_independent_snapshots() {
+        let snapshots = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        let rom = ReducedOrderModel::from_snapshots(&snapshots, 0.999);
+        assert_eq!(rom.order(), 3);
+    }
+
+    #[test]
+    fn test_project_operators_and_reconstruct_roundtrip() {
+        let snapshots = vec![vec![1.0, 2.0, 3.0], vec![2.0, 3.0, 4.0]];
+        let mut rom = ReducedOrderModel::from_snapshots(&snapshots, 0.999);
+
+        let k = vec![
+            vec![-2.0, 1.0, 0.0],
+            vec![1.0, -2.0, 1.0],
+            vec![0.0, 1.0, -2.0],
+        ];
+        let c = vec![
+            vec![1.0, 0.0, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ];
+        rom.project_operators(&k, &c);
+        assert_eq!(rom.k_r.len(), rom.order());
+        assert_eq!(rom.c_r.len(), rom.order());
+    }
+}
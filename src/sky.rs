@@ -0,0 +1,164 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::Float;
+
+/// Splits the single lumped "incident infrared irradiance" that surfaces
+/// read out of [`crate::discretization`]'s border conditions into sky,
+/// ground and surrounding-air components, weighted by the view factors a
+/// surface of a given tilt has toward each.
+///
+/// A flat roof sees mostly sky (and, on a clear night, radiates heavily to
+/// it, since the sky is usually much colder than the air); a vertical wall
+/// sees half sky/air and half ground; a floor slab sees only the ground.
+/// Lumping all three into a single "environment temperature" (the
+/// crate's previous behaviour, preserved here via [`Self::uniform`])
+/// ignores that split.
+#[derive(Debug, Clone, Copy)]
+pub struct SkyModel {
+    /// Effective sky temperature, in `C`. Often well below air temperature,
+    /// especially under a clear night sky.
+    pub sky_temperature: Float,
+
+    /// Ground surface temperature, in `C`.
+    pub ground_temperature: Float,
+
+    /// Air temperature, in `C`—also stands in for whatever surrounds the
+    /// surface horizontally (nearby buildings, vegetation, horizon haze)
+    /// that isn't sky or ground.
+    pub air_temperature: Float,
+
+    /// Fraction (`0` to `1`) of the sky-facing view factor that actually
+    /// sees the sky rather than being scattered back by clouds or blocked
+    /// by obstructions. `1` is a clear, unobstructed sky; `0` means the
+    /// sky-facing view factor is entirely replaced by `air_temperature`
+    /// (e.g. a fully overcast night, or a heavily obstructed site).
+    pub sky_clearness: Float,
+}
+
+impl SkyModel {
+    /// Builds a [`SkyModel`] that reproduces this crate's previous,
+    /// single-value behaviour: sky, ground and air are all set to
+    /// `air_temperature`, so [`Self::irradiance`] returns
+    /// `SIGMA * (air_temperature + 273.15)^4` regardless of tilt, exactly
+    /// as if the environment were a single uniform blackbody enclosure.
+    /// Existing models that never set up sky/ground temperatures
+    /// separately see no change in behavior.
+    pub fn uniform(air_temperature: Float) -> Self {
+        Self {
+            sky_temperature: air_temperature,
+            ground_temperature: air_temperature,
+            air_temperature,
+            sky_clearness: 0.0,
+        }
+    }
+
+    /// The view factors `(f_sky, f_ground, f_air)` that a surface of the
+    /// given tilt has toward the sky, the ground and the surrounding air.
+    /// `cos_tilt` is `1` for a surface facing straight up (e.g. a flat
+    /// roof), `-1` for a surface facing straight down (e.g. a floor slab)
+    /// and `0` for a vertical wall. The three factors always add up to `1`.
+    pub fn view_factors(&self, cos_tilt: Float) -> (Float, Float, Float) {
+        let f_sky_total = (1. + cos_tilt) / 2.;
+        let f_ground = (1. - cos_tilt) / 2.;
+        let f_sky = f_sky_total * self.sky_clearness;
+        let f_air = f_sky_total * (1. - self.sky_clearness);
+        (f_sky, f_ground, f_air)
+    }
+
+    /// The directional incident IR irradiance (`W/m^2`) on a surface of the
+    /// given tilt (see [`Self::view_factors`] for the `cos_tilt`
+    /// convention), combining the sky, ground and air components through
+    /// their view factors via the Stefan-Boltzmann law.
+    pub fn irradiance(&self, cos_tilt: Float) -> Float {
+        let (f_sky, f_ground, f_air) = self.view_factors(cos_tilt);
+        crate::SIGMA
+            * (f_sky * (self.sky_temperature + 273.15).powi(4)
+                + f_ground * (self.ground_temperature + 273.15).powi(4)
+                + f_air * (self.air_temperature + 273.15).powi(4))
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_uniform_matches_single_value_environment() {
+        let sky = SkyModel::uniform(22.0);
+        let expected = crate::SIGMA * (22.0 + 273.15 as Float).powi(4);
+        for cos_tilt in [-1.0, -0.5, 0.0, 0.5, 1.0] {
+            let irrad = sky.irradiance(cos_tilt);
+            assert!(
+                (irrad - expected).abs() < 1e-6,
+                "cos_tilt = {cos_tilt} | irrad = {irrad} | expected = {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_view_factors_sum_to_one() {
+        let sky = SkyModel {
+            sky_temperature: -10.0,
+            ground_temperature: 15.0,
+            air_temperature: 8.0,
+            sky_clearness: 0.7,
+        };
+        for cos_tilt in [-1.0, -0.3, 0.0, 0.4, 1.0] {
+            let (f_sky, f_ground, f_air) = sky.view_factors(cos_tilt);
+            assert!((f_sky + f_ground + f_air - 1.0).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_upward_facing_surface_sees_no_ground() {
+        let sky = SkyModel {
+            sky_temperature: -10.0,
+            ground_temperature: 15.0,
+            air_temperature: 8.0,
+            sky_clearness: 1.0,
+        };
+        let (f_sky, f_ground, _f_air) = sky.view_factors(1.0);
+        assert!((f_ground).abs() < 1e-6);
+        assert!((f_sky - 1.0).abs() < 1e-6);
+
+        // A clear-sky upward roof should radiate to (and thus receive
+        // irradiance consistent with) the cold sky temperature alone.
+        let expected = crate::SIGMA * (-10.0 + 273.15 as Float).powi(4);
+        assert!((sky.irradiance(1.0) - expected).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_downward_facing_surface_sees_only_ground() {
+        let sky = SkyModel {
+            sky_temperature: -10.0,
+            ground_temperature: 15.0,
+            air_temperature: 8.0,
+            sky_clearness: 1.0,
+        };
+        let (f_sky, f_ground, f_air) = sky.view_factors(-1.0);
+        assert!(f_sky.abs() < 1e-6);
+        assert!(f_air.abs() < 1e-6);
+        assert!((f_ground - 1.0).abs() < 1e-6);
+
+        let expected = crate::SIGMA * (15.0 + 273.15 as Float).powi(4);
+        assert!((sky.irradiance(-1.0) - expected).abs() < 1e-6);
+    }
+}
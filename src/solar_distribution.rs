@@ -0,0 +1,110 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Every surface in this crate currently receives solar gain as a single
+//! scalar `front_incident_solar_irradiance`, set by whatever upstream model
+//! (weather file plus a sun-position/shading calculation) computed it—`heat`
+//! itself has no sun-vector or surface-adjacency model to distribute it with.
+//!
+//! This module provides the narrow, composable pieces a caller with that
+//! geometry already on hand can use to go further: [`split_beam_diffuse`]
+//! decomposes a surface's total irradiance into its direct and diffuse
+//! components, and [`SolarMaterial`] is a per-surface absorb/reflect
+//! response. What it deliberately does *not* attempt is a full ray-traced
+//! inter-reflection network (tracing a reflected ray on to whichever
+//! neighboring surface it strikes): that needs a ray-surface intersection/
+//! occlusion test, which isn't an API this crate's `geometry3d` dependency
+//! is confirmed to expose, and building one from scratch is a standalone
+//! ray-tracer, not a thermal-model feature. A caller that already has such
+//! a test can still use [`SolarMaterial::interact`]'s reflected flux as the
+//! input to its own tracing pass.
+
+use crate::Float;
+
+/// Splits a surface's total incident solar irradiance `global` (W/m2) into
+/// its direct-beam and diffuse components, given `diffuse_fraction` (the
+/// fraction of `global` that is diffuse sky radiation, e.g. from
+/// [`crate::sky::SkyModel`] or a weather file's direct-normal/diffuse-
+/// horizontal split) and `cos_incidence`, the cosine of the angle between
+/// the surface's outward normal and the sun vector (zero or negative means
+/// the sun is behind the surface, so it gets no beam component).
+pub fn split_beam_diffuse(global: Float, diffuse_fraction: Float, cos_incidence: Float) -> (Float, Float) {
+    let diffuse_fraction = diffuse_fraction.clamp(0., 1.);
+    let diffuse = global * diffuse_fraction;
+    let beam = if cos_incidence > 0. {
+        (global - diffuse).max(0.)
+    } else {
+        0.
+    };
+    (beam, diffuse)
+}
+
+/// A surface's shortwave optical response to incident radiation: some
+/// fraction is absorbed, some is reflected. Doesn't distinguish specular
+/// from diffuse reflection—see the module-level doc for why a reflected
+/// ray isn't traced on to another surface here.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SolarMaterial {
+    /// Fraction of incident shortwave radiation absorbed (0 to 1).
+    pub absorptance: Float,
+    /// Fraction of incident shortwave radiation reflected (0 to 1). Not
+    /// required to sum to 1 with `absorptance`—the remainder is whatever
+    /// this material neither absorbs nor reflects (e.g. transmitted
+    /// through a glazing layer modeled elsewhere).
+    pub reflectance: Float,
+}
+
+impl SolarMaterial {
+    /// The `(absorbed, reflected)` flux (W/m2) this material returns for
+    /// `incident` irradiance (W/m2).
+    pub fn interact(&self, incident: Float) -> (Float, Float) {
+        (incident * self.absorptance, incident * self.reflectance)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_split_beam_diffuse_sums_to_global() {
+        let (beam, diffuse) = split_beam_diffuse(800., 0.25, 0.6);
+        assert!((beam + diffuse - 800.).abs() < 1e-6);
+        assert!((diffuse - 200.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_sun_behind_surface_has_no_beam_component() {
+        let (beam, diffuse) = split_beam_diffuse(800., 0.25, -0.1);
+        assert_eq!(beam, 0.0);
+        assert!((diffuse - 200.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_material_interact_splits_absorbed_and_reflected() {
+        let material = SolarMaterial {
+            absorptance: 0.7,
+            reflectance: 0.2,
+        };
+        let (absorbed, reflected) = material.interact(500.);
+        assert!((absorbed - 350.).abs() < 1e-6);
+        assert!((reflected - 100.).abs() < 1e-6);
+    }
+}
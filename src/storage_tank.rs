@@ -0,0 +1,311 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use crate::Float;
+
+/// The specific heat of water (J/kgK), used by [`StorageTank::advect`] to
+/// convert [`StorageTank::charge_mass_flow`] into an equivalent fraction of
+/// a node's own thermal mass displaced per step.
+const WATER_SPECIFIC_HEAT: Float = 4186.0;
+
+/// A stratified hot-water storage (buffer) tank, represented as `N` stacked
+/// volume nodes (top to bottom) each with its own temperature, so that
+/// a hydronic heater/emitter can draw from stored water rather than an
+/// idealized constant-power source.
+///
+/// Node `0` is the top of the tank (the hottest node in a well-stratified
+/// tank, and the one [`Self::top_temperature`] surfaces as the supply to a
+/// [`crate::heating_cooling::WetEmitter`] or similar draw-off). Node
+/// `n_nodes() - 1` is the bottom.
+///
+/// Wiring a tank's [`Self::top_temperature`] in as the actual `T_e` supply
+/// seen by a [`crate::heating_cooling::WetEmitter`] (rather than the
+/// emitter's own lumped mass) is left to the caller: `simple_model`'s `HVAC`
+/// enum has no tank-backed emitter kind yet, so this module only provides
+/// the tank's own physics, to be composed once that wiring exists.
+#[derive(Debug, Clone)]
+pub struct StorageTank {
+    /// Each node's temperature (°C), top node first.
+    pub temperatures: Vec<Float>,
+
+    /// Each node's thermal capacitance (J/K)—its water volume times the
+    /// water's density and specific heat, plus a share of the tank's metal
+    /// mass.
+    pub node_capacitance: Vec<Float>,
+
+    /// Each node's standing-loss conductance to the ambient/surrounding
+    /// zone air (W/K), i.e. `U*A_i` for that node's share of the tank's
+    /// insulated shell.
+    pub node_loss_conductance: Vec<Float>,
+
+    /// The conductance between any two vertically adjacent nodes (W/K),
+    /// from conduction through the water column and the tank wall.
+    pub inter_node_conductance: Float,
+
+    /// The charge/discharge loop's mass flow rate (kg/s) through the tank,
+    /// set via [`Self::set_charge`] and consumed by
+    /// [`Self::march_with_charge`]'s advection step. Zero means no loop
+    /// flow this step.
+    pub charge_mass_flow: Float,
+
+    /// The temperature (°C) of the fluid entering through
+    /// [`Self::charge_mass_flow`], e.g. a solar collector loop's return
+    /// temperature, or a cold-mains refill. Set via [`Self::set_charge`].
+    pub charge_inlet_temperature: Float,
+
+    /// An external heat-source gain (W), e.g. a solar-collector loop's
+    /// [`Self::solar_charge`] output, applied at
+    /// [`Self::march_with_charge`]'s `charge_node` on the next step. Set
+    /// via [`Self::set_solar_gain`].
+    pub solar_gain: Float,
+
+    /// The cumulative standing loss (J) this tank has given up to its
+    /// surroundings since construction, tallied by every
+    /// [`Self::march_with_charge`] step. Read via
+    /// [`Self::cumulative_losses`].
+    pub cumulative_losses: Float,
+}
+
+impl StorageTank {
+    /// The number of stacked volume nodes.
+    pub fn n_nodes(&self) -> usize {
+        self.temperatures.len()
+    }
+
+    /// The top node's temperature (°C)—the supply a heater/emitter drawing
+    /// from this tank would see.
+    pub fn top_temperature(&self) -> Float {
+        self.temperatures[0]
+    }
+
+    /// The useful gain (W) from a solar-thermal collector feeding this tank,
+    /// per the standard quadratic-free (first-order) collector efficiency
+    /// model `Q = A_coll*(eta0*irradiance - a1*(t_fluid - t_out))`, clipped
+    /// at zero (the collector is bypassed rather than run backwards when
+    /// its fluid would be cooler than the tank).
+    pub fn solar_charge(
+        collector_area: Float,
+        eta0: Float,
+        a1: Float,
+        irradiance: Float,
+        t_fluid: Float,
+        t_out: Float,
+    ) -> Float {
+        (collector_area * (eta0 * irradiance - a1 * (t_fluid - t_out))).max(0.0)
+    }
+
+    /// The power (W) a draw-off loop can extract from the tank's top node
+    /// through a heat exchanger of conductance `loop_conductance` (W/K)
+    /// returning fluid at `t_return`—the discharge-side counterpart to
+    /// [`Self::solar_charge`]'s charge side, clipped at zero so a draw-off
+    /// loop warmer than the tank doesn't inject heat back in through this
+    /// path.
+    ///
+    /// [`crate::heating_cooling::WetEmitter`] has no tank-backed supply of
+    /// its own (see this module's doc comment): a caller ties the two
+    /// together each step by reading the emitter's current temperature off
+    /// [`crate::heating_cooling::ThermalHVACMemory::WetDistribution`]'s
+    /// `t_e` field, computing this method's discharge power against it,
+    /// writing that back into the same memory's `q_in` field before
+    /// marching the model, then feeding the same power back into
+    /// [`Self::march`] here as a (negative) charge so the tank depletes by
+    /// the energy the emitter actually drew.
+    pub fn discharge_power(&self, loop_conductance: Float, t_return: Float) -> Float {
+        (loop_conductance * (self.top_temperature() - t_return)).max(0.0)
+    }
+
+    /// The temperature (°C) a draw-off loop sees at the tank's outlet—an
+    /// alias for [`Self::top_temperature`], named to match the
+    /// inlet/outlet vocabulary of [`Self::set_charge`].
+    pub fn outlet_temperature(&self) -> Float {
+        self.top_temperature()
+    }
+
+    /// The tank's total stored thermal energy (J) above
+    /// `reference_temperature` (e.g. the cold-mains temperature), summed
+    /// across all nodes' capacitance.
+    pub fn total_stored_energy(&self, reference_temperature: Float) -> Float {
+        self.temperatures
+            .iter()
+            .zip(&self.node_capacitance)
+            .map(|(t, c)| c * (t - reference_temperature))
+            .sum()
+    }
+
+    /// The cumulative standing loss (J) tallied by every
+    /// [`Self::march_with_charge`] step since construction.
+    pub fn cumulative_losses(&self) -> Float {
+        self.cumulative_losses
+    }
+
+    /// Sets the charge/discharge loop's mass flow (kg/s) and inlet
+    /// temperature (°C) for the next [`Self::march_with_charge`] step.
+    pub fn set_charge(&mut self, mass_flow: Float, inlet_temperature: Float) {
+        self.charge_mass_flow = mass_flow;
+        self.charge_inlet_temperature = inlet_temperature;
+    }
+
+    /// Sets the external heat-source gain (W, e.g. from
+    /// [`Self::solar_charge`]) to apply at
+    /// [`Self::march_with_charge`]'s `charge_node` on the next step.
+    pub fn set_solar_gain(&mut self, gain: Float) {
+        self.solar_gain = gain;
+    }
+
+    /// Displaces each node's contents by the fraction of its own thermal
+    /// mass that [`Self::charge_mass_flow`] would turn over in `dt`
+    /// seconds, entering at `charge_node` at
+    /// [`Self::charge_inlet_temperature`] and pushing each node's previous
+    /// contents one step further from `charge_node`—the plug-flow
+    /// approximation standard to stratified-tank models, distinct from (and
+    /// run before) the conduction/diffusion in [`Self::derivative`].
+    ///
+    /// A `charge_node` of `0` (the top) models a solar-loop return
+    /// stacking hot water onto the top and pushing cooler water down; a
+    /// `charge_node` of `n_nodes() - 1` (the bottom) models a cold-mains
+    /// refill pushing the stack up. Any other `charge_node` is treated as a
+    /// top-direction charge.
+    fn advect(&mut self, dt: Float, charge_node: usize) {
+        if self.charge_mass_flow <= 0.0 {
+            return;
+        }
+        let n = self.n_nodes();
+        let step: isize = if charge_node == n.saturating_sub(1) { -1 } else { 1 };
+        let flow_energy = self.charge_mass_flow * WATER_SPECIFIC_HEAT * dt;
+        let mut inflow_temperature = self.charge_inlet_temperature;
+        let mut i = charge_node as isize;
+        while i >= 0 && (i as usize) < n {
+            let idx = i as usize;
+            let frac = (flow_energy / self.node_capacitance[idx]).min(1.0);
+            let outgoing_temperature = self.temperatures[idx];
+            self.temperatures[idx] += frac * (inflow_temperature - outgoing_temperature);
+            inflow_temperature = outgoing_temperature;
+            i += step;
+        }
+    }
+
+    /// Advances the tank by `dt` seconds using the charge/discharge loop
+    /// and solar gain set via [`Self::set_charge`]/[`Self::set_solar_gain`],
+    /// rather than [`Self::march`]'s explicit `charge_node`/`q_charge`
+    /// arguments: runs the plug-flow [`Self::advect`] displacement first
+    /// (the mass-flow side of the loop), then tallies the step's standing
+    /// loss (evaluated at the post-advection temperatures) into
+    /// [`Self::cumulative_losses`], and finally runs [`Self::march`] itself
+    /// for the conduction/standing-loss/heat-source integration and
+    /// [`Self::mix`].
+    pub fn march_with_charge(&mut self, dt: Float, t_ambient: Float, charge_node: usize) {
+        self.advect(dt, charge_node);
+
+        let loss_this_step: Float = (0..self.n_nodes())
+            .map(|i| self.node_loss_conductance[i] * (self.temperatures[i] - t_ambient))
+            .sum::<Float>()
+            * dt;
+        self.cumulative_losses += loss_this_step.max(0.0);
+
+        let solar_gain = self.solar_gain;
+        self.march(dt, t_ambient, charge_node, solar_gain);
+    }
+
+    /// The rate of change of each node's temperature (K/s), given the
+    /// standing losses to `t_ambient`, conduction with vertically adjacent
+    /// nodes, and a charge input `q_charge` (W) injected at `charge_node`.
+    fn derivative(
+        &self,
+        temperatures: &[Float],
+        t_ambient: Float,
+        charge_node: usize,
+        q_charge: Float,
+    ) -> Vec<Float> {
+        let n = temperatures.len();
+        let mut d = vec![0.0; n];
+        for i in 0..n {
+            let mut q = -self.node_loss_conductance[i] * (temperatures[i] - t_ambient);
+            if i > 0 {
+                q -= self.inter_node_conductance * (temperatures[i] - temperatures[i - 1]);
+            }
+            if i + 1 < n {
+                q -= self.inter_node_conductance * (temperatures[i] - temperatures[i + 1]);
+            }
+            if i == charge_node {
+                q += q_charge;
+            }
+            d[i] = q / self.node_capacitance[i];
+        }
+        d
+    }
+
+    /// A single classic Runge-Kutta-4 step of [`Self::derivative`] over the
+    /// whole node-temperature vector.
+    fn rk4_step(
+        &self,
+        t_ambient: Float,
+        charge_node: usize,
+        q_charge: Float,
+        h: Float,
+    ) -> Vec<Float> {
+        let n = self.n_nodes();
+        let t0 = &self.temperatures;
+
+        let k1 = self.derivative(t0, t_ambient, charge_node, q_charge);
+        let t1: Vec<Float> = (0..n).map(|i| t0[i] + h / 2. * k1[i]).collect();
+
+        let k2 = self.derivative(&t1, t_ambient, charge_node, q_charge);
+        let t2: Vec<Float> = (0..n).map(|i| t0[i] + h / 2. * k2[i]).collect();
+
+        let k3 = self.derivative(&t2, t_ambient, charge_node, q_charge);
+        let t3: Vec<Float> = (0..n).map(|i| t0[i] + h * k3[i]).collect();
+
+        let k4 = self.derivative(&t3, t_ambient, charge_node, q_charge);
+
+        (0..n)
+            .map(|i| t0[i] + h / 6. * (k1[i] + 2. * k2[i] + 2. * k3[i] + k4[i]))
+            .collect()
+    }
+
+    /// A buoyancy-driven mixing pass: repeatedly swaps any adjacent pair of
+    /// nodes where a lower node is hotter than the node above it, until the
+    /// profile is monotonically non-increasing from top to bottom. This
+    /// models the near-instantaneous overturning that happens whenever
+    /// conduction or a charge input would otherwise leave warmer water
+    /// sitting below cooler water.
+    pub fn mix(&mut self) {
+        loop {
+            let mut swapped = false;
+            for i in 0..self.temperatures.len().saturating_sub(1) {
+                if self.temperatures[i + 1] > self.temperatures[i] {
+                    self.temperatures.swap(i, i + 1);
+                    swapped = true;
+                }
+            }
+            if !swapped {
+                break;
+            }
+        }
+    }
+
+    /// Advances the tank's node temperatures by `dt` seconds: standing
+    /// losses to `t_ambient`, inter-node conduction, and a charge input
+    /// `q_charge` (W, assumed constant over the step) injected at
+    /// `charge_node`, followed by a buoyancy-driven [`Self::mix`] pass.
+    pub fn march(&mut self, dt: Float, t_ambient: Float, charge_node: usize, q_charge: Float) {
+        self.temperatures = self.rk4_step(t_ambient, charge_node, q_charge, dt);
+        self.mix();
+    }
+}
@@ -18,7 +18,7 @@ OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 SOFTWARE.
 */
 
-use crate::convection::ConvectionParams;
+use crate::convection::{ConvectionAlgorithm, ConvectionParams};
 use crate::discretization::Discretization;
 use crate::glazing::Glazing;
 use crate::surface_trait::SurfaceTrait;
@@ -32,6 +32,9 @@ use simple_model::{
 use simple_model::{SimulationState, SiteDetails};
 use std::rc::Rc;
 
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
 /// Calculates whether a surface is facing the wind direction
 /// **wind_direction in Radians**
 pub fn is_windward(wind_direction: Float, cos_tilt: Float, normal: Vector3D) -> bool {
@@ -46,6 +49,22 @@ pub fn is_windward(wind_direction: Float, cos_tilt: Float, normal: Vector3D) ->
 }
 
 
+/// A cached Thomas-algorithm factorization of [`theta_method`]'s implicit
+/// tridiagonal system, keyed by the `(dt, theta)` and unfactored bands it was
+/// computed from. [`theta_method`] reuses `factored_main_diag` instead of
+/// recomputing it whenever a later call's bands match exactly—e.g. across a
+/// `Theta` scheme's Newton iterations, or successive timesteps, for a
+/// construction whose conductivities (and hence `K`) don't change.
+#[derive(Debug, Clone)]
+pub struct ThetaFactorization {
+    dt: Float,
+    theta: Float,
+    sub_diag: Vec<Float>,
+    super_diag: Vec<Float>,
+    unfactored_main_diag: Vec<Float>,
+    factored_main_diag: Vec<Float>,
+}
+
 /// The memory needed to simulate the marching forward
 /// of a massive chunk
 pub struct ChunkMemory {
@@ -63,6 +82,70 @@ pub struct ChunkMemory {
     pub k3: Matrix,
     /// memory for a matrix
     pub k4: Matrix,
+    /// The 5th/6th stages of the embedded RKF45 pair (see [`rkf45_step`]);
+    /// unused by `IntegrationScheme::RK4`/`IntegrationScheme::AdaptiveRK4`,
+    /// which only need `k1..k4`.
+    pub k5: Matrix,
+    /// See [`Self::k5`].
+    pub k6: Matrix,
+    /// Scratch buffer for the embedded pair's 4th-order solution, compared
+    /// against the 5th-order solution (written into the march's own `t` in
+    /// place) to estimate [`rkf45_adaptive`]'s local error.
+    pub t4: Matrix,
+
+    /// The cached exponential propagator `exp([[A,b],[0,0]]*dt)` computed by
+    /// [`expm_march`] for [`crate::discretization::IntegrationScheme::Exponential`],
+    /// kept around for inspection/reuse across steps where `k`, `c` and `dt`
+    /// do not change. `None` until the first exponential march.
+    pub propagator: Option<Vec<Vec<Float>>>,
+
+    /// The sub-diagonal band of the implicit $`\theta`$-method's tridiagonal
+    /// system, i.e. `sub_diag[i]` multiplies `T^{n+1}_{i-1}` in row `i`
+    /// (`sub_diag[0]` is unused). This—together with [`Self::main_diag`]/
+    /// [`Self::super_diag`]—*is* this crate's tridiagonal/banded storage:
+    /// three `Vec<Float>`s sized once per chunk and filled by [`theta_method`]
+    /// from `memory.k`'s three nonzero bands every step, solved in `O(n)` by
+    /// [`thomas_solve_factored`] (the forward-elimination/back-substitution
+    /// Thomas algorithm, with [`ChunkMemory::theta_factorization`] caching the
+    /// elimination pass itself across calls) rather than a dense `n x n`
+    /// factorization.
+    pub sub_diag: Vec<Float>,
+    /// The main diagonal band of the implicit $`\theta`$-method's tridiagonal system
+    pub main_diag: Vec<Float>,
+    /// The super-diagonal band of the implicit $`\theta`$-method's tridiagonal
+    /// system, i.e. `super_diag[i]` multiplies `T^{n+1}_{i+1}` in row `i`
+    /// (`super_diag[n-1]` is unused)
+    pub super_diag: Vec<Float>,
+    /// The right-hand-side of the implicit $`\theta`$-method's tridiagonal system
+    pub rhs: Vec<Float>,
+
+    /// The cached factorization of the implicit $`\theta`$-method's last
+    /// tridiagonal system, reused by [`theta_method`] across calls whose
+    /// `K`/`dt`/`theta` haven't changed. `None` until the first solve.
+    pub theta_factorization: Option<ThetaFactorization>,
+
+    /// The cached inputs [`expm_march`] last exponentiated, reused—like
+    /// [`Self::theta_factorization`]—whenever a later call's `dt`/`k`/`q`/`c`
+    /// match exactly, so that a construction marched with
+    /// [`crate::discretization::IntegrationScheme::Exponential`] over many
+    /// unchanging timesteps (the common annual-simulation case this mode is
+    /// for) exponentiates the augmented matrix once rather than on every
+    /// step. `None` until the first exponential march.
+    pub expm_factorization: Option<ExpmFactorization>,
+}
+
+/// The cached matrix-exponential propagator computed by [`expm_march`],
+/// keyed by the `dt` and flattened `k`/`q`/`c` it was computed from—mirroring
+/// [`ThetaFactorization`]'s caching for [`theta_method`]. `k`/`q`/`c` are
+/// flattened into plain `Vec<Float>`s (rather than kept as `Matrix`es) purely
+/// so the cache can be compared with `==`.
+#[derive(Debug, Clone)]
+pub struct ExpmFactorization {
+    dt: Float,
+    k: Vec<Float>,
+    q: Vec<Float>,
+    c: Vec<Float>,
+    propagator: Vec<Vec<Float>>,
 }
 
 impl ChunkMemory {
@@ -76,10 +159,77 @@ impl ChunkMemory {
             k2: Matrix::new(0.0, n+1, 1),
             k3: Matrix::new(0.0, n+1, 1),
             k4: Matrix::new(0.0, n+1, 1),
+            k5: Matrix::new(0.0, n+1, 1),
+            k6: Matrix::new(0.0, n+1, 1),
+            t4: Matrix::new(0.0, n+1, 1),
+            propagator: None,
+            sub_diag: vec![0.0; n+1],
+            main_diag: vec![0.0; n+1],
+            super_diag: vec![0.0; n+1],
+            rhs: vec![0.0; n+1],
+            theta_factorization: None,
+            expm_factorization: None,
         }
     }
 }
 
+/// Solves a tridiagonal system via the Thomas algorithm: forward elimination
+/// (computing the modified super-diagonal and right-hand-side), followed by
+/// back-substitution. `sub[0]` and `super_diag[n-1]` are ignored (there is no
+/// element there). Runs in `O(n)`, without forming or inverting the full
+/// matrix.
+///
+/// [`ChunkMemory::sub_diag`]/[`ChunkMemory::main_diag`]/
+/// [`ChunkMemory::super_diag`]/[`ChunkMemory::rhs`] are this banded storage,
+/// sized once when the chunk's [`ChunkMemory`] is built and reused every
+/// step—there is no dense `n x n` allocation on the implicit path. The
+/// explicit [`rk4`] path gets the same `O(n)` treatment from the other
+/// side: `memory.k` is only ever populated along its three bands by
+/// [`crate::discretization::Discretization::get_k_q`], and
+/// [`matrix::Matrix::prod_tri_diag_into`] multiplies it accordingly rather
+/// than running a dense matrix-vector product.
+fn thomas_solve(sub: &[Float], main_diag: &mut [Float], super_diag: &[Float], rhs: &mut [Float]) -> Vec<Float> {
+    thomas_factorize(sub, main_diag, super_diag);
+    thomas_solve_factored(main_diag, sub, super_diag, rhs)
+}
+
+/// The forward-elimination half of the Thomas algorithm: reduces `main_diag`
+/// in place into the factored tridiagonal system's pivots, independent of
+/// any particular right-hand side. Since [`thomas_solve_factored`]'s
+/// rhs-elimination and back-substitution only need these pivots (plus the
+/// unmodified `sub`/`super_diag`), a factorization computed here can be
+/// reused by [`thomas_solve_factored`] across repeated solves against the
+/// same `K`/`dt`/`theta`—see [`ChunkMemory::theta_factorization`].
+fn thomas_factorize(sub: &[Float], main_diag: &mut [Float], super_diag: &[Float]) {
+    let n = main_diag.len();
+    for i in 1..n {
+        let w = sub[i] / main_diag[i - 1];
+        main_diag[i] -= w * super_diag[i - 1];
+    }
+}
+
+/// The rhs-elimination and back-substitution half of the Thomas algorithm,
+/// given a `factored_main_diag` already produced by [`thomas_factorize`].
+fn thomas_solve_factored(
+    factored_main_diag: &[Float],
+    sub: &[Float],
+    super_diag: &[Float],
+    rhs: &mut [Float],
+) -> Vec<Float> {
+    let n = factored_main_diag.len();
+    for i in 1..n {
+        let w = sub[i] / factored_main_diag[i - 1];
+        rhs[i] -= w * rhs[i - 1];
+    }
+
+    let mut solution = vec![0.0; n];
+    solution[n - 1] = rhs[n - 1] / factored_main_diag[n - 1];
+    for i in (0..n - 1).rev() {
+        solution[i] = (rhs[i] - super_diag[i] * solution[i + 1]) / factored_main_diag[i];
+    }
+    solution
+}
+
 /// The memory needed to simulate the marching of 
 /// a surface
 pub struct SurfaceMemory {
@@ -91,7 +241,56 @@ pub struct SurfaceMemory {
     pub temperatures: Matrix,
 
     /// The solar absorption on each node
-    pub q: Matrix
+    pub q: Matrix,
+
+    /// User-defined heat injected at each node (W), e.g. an embedded
+    /// radiant-floor loop or an electric heating mat between layers. Set
+    /// with [`ThermalSurfaceData::set_node_heat_source`] and read back with
+    /// [`ThermalSurfaceData::get_node_heat_source`]; added into the node's
+    /// energy balance every [`ThermalSurfaceData::march`] exactly like the
+    /// surface's own solar absorption.
+    pub node_heat_sources: Matrix,
+
+    /// One cached [`crate::discretization::ChunkStateSpace`] per entry of
+    /// [`ThermalSurfaceData::massive_chunks`], built lazily by
+    /// [`ThermalSurfaceData::march_nodes_reduced`] and rebuilt whenever its
+    /// `dt` no longer matches the one requested.
+    pub state_space_cache: Vec<Option<crate::discretization::ChunkStateSpace>>,
+}
+
+impl SurfaceMemory {
+    /// Takes a snapshot of the current node temperatures, to be restored
+    /// later with [`Self::restore`] if a staggered-coupling iteration or a
+    /// marching step needs to be retried.
+    pub fn checkpoint(&self) -> Matrix {
+        self.temperatures.clone()
+    }
+
+    /// Restores the node temperatures from a snapshot previously taken with
+    /// [`Self::checkpoint`], discarding whatever marching happened since.
+    pub fn restore(&mut self, checkpoint: &Matrix) {
+        self.temperatures.copy_from(checkpoint);
+    }
+
+    /// Marches this surface's temperatures forward from `checkpoint`, calling
+    /// `march_fn` repeatedly (once per substep) without re-taking the
+    /// snapshot—useful for winding a surface back to the present after its
+    /// state was rolled back and retried with different boundary conditions.
+    pub fn wind_forward<F>(
+        &mut self,
+        checkpoint: &Matrix,
+        n_steps: usize,
+        mut march_fn: F,
+    ) -> Result<(), String>
+    where
+        F: FnMut(&mut Self) -> Result<(), String>,
+    {
+        self.restore(checkpoint);
+        for _ in 0..n_steps {
+            march_fn(self)?;
+        }
+        Ok(())
+    }
 }
 
 /// Calculates a surface's wind speed modifier; that is to say, the value by which
@@ -158,6 +357,229 @@ pub fn wind_speed_modifier(height: Float, site_details: &Option<SiteDetails>) ->
     (270. / 10. as Float).powf(0.14) * (height / delta).powf(alpha)
 }
 
+/// Multiplies two dense, square matrices of the same size.
+fn mat_mul(a: &[Vec<Float>], b: &[Vec<Float>]) -> Vec<Vec<Float>> {
+    let n = a.len();
+    let mut out = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for k in 0..n {
+            let a_ik = a[i][k];
+            if a_ik == 0.0 {
+                continue;
+            }
+            for j in 0..n {
+                out[i][j] += a_ik * b[k][j];
+            }
+        }
+    }
+    out
+}
+
+/// The `n x n` identity matrix.
+fn mat_identity(n: usize) -> Vec<Vec<Float>> {
+    let mut out = vec![vec![0.0; n]; n];
+    for (i, row) in out.iter_mut().enumerate() {
+        row[i] = 1.0;
+    }
+    out
+}
+
+/// The $`\infty`$-norm of a dense matrix (the largest absolute row sum),
+/// used to pick the scaling-and-squaring factor in [`expm`].
+fn inf_norm(a: &[Vec<Float>]) -> Float {
+    a.iter()
+        .map(|row| row.iter().map(|v| v.abs()).sum::<Float>())
+        .fold(0.0, Float::max)
+}
+
+/// Solves the dense linear system `a * x = b` (`a` and `b` both `n x n`) via
+/// Gaussian elimination with partial pivoting. Used to solve for the Padé
+/// approximant's rational form in [`expm`], where `a` is not (in general)
+/// tridiagonal.
+fn solve_dense(mut a: Vec<Vec<Float>>, mut b: Vec<Vec<Float>>) -> Vec<Vec<Float>> {
+    let n = a.len();
+    for col in 0..n {
+        // Partial pivoting.
+        let pivot_row = (col..n)
+            .max_by(|&i, &j| a[i][col].abs().partial_cmp(&a[j][col].abs()).unwrap())
+            .unwrap();
+        a.swap(col, pivot_row);
+        b.swap(col, pivot_row);
+
+        let pivot = a[col][col];
+        for row in (col + 1)..n {
+            let factor = a[row][col] / pivot;
+            if factor == 0.0 {
+                continue;
+            }
+            for k in col..n {
+                a[row][k] -= factor * a[col][k];
+            }
+            for k in 0..n {
+                b[row][k] -= factor * b[col][k];
+            }
+        }
+    }
+
+    // Back-substitution.
+    for col in (0..n).rev() {
+        for k in 0..n {
+            let mut sum = b[col][k];
+            for j in (col + 1)..n {
+                sum -= a[col][j] * b[j][k];
+            }
+            b[col][k] = sum / a[col][col];
+        }
+    }
+    b
+}
+
+/// The matrix exponential `exp(a)` of a small, dense `n x n` matrix, computed
+/// via scaling-and-squaring with a diagonal order-6 Padé approximant (the
+/// `dgpadm` scheme): `a` is scaled down by a power of two until its
+/// $`\infty`$-norm is at most $`\frac{1}{2}`$, the Padé approximant `N·D⁻¹` of
+/// `exp(a')` is formed and solved for, and the result is squared back up.
+pub(crate) fn expm(a: &[Vec<Float>]) -> Vec<Vec<Float>> {
+    let n = a.len();
+    let norm = inf_norm(a);
+
+    let mut squarings = 0;
+    let mut scale = 1.0;
+    while norm * scale > 0.5 {
+        scale *= 0.5;
+        squarings += 1;
+    }
+
+    let scaled: Vec<Vec<Float>> = a
+        .iter()
+        .map(|row| row.iter().map(|v| v * scale).collect())
+        .collect();
+
+    // Diagonal Pade(6,6) coefficients, c_k = (12-k)!*6! / (12! * k! * (6-k)!).
+    const C: [Float; 7] = [
+        1.0,
+        1.0 / 2.0,
+        5.0 / 44.0,
+        1.0 / 66.0,
+        1.0 / 792.0,
+        1.0 / 15840.0,
+        1.0 / 665280.0,
+    ];
+
+    let identity = mat_identity(n);
+    let a2 = mat_mul(&scaled, &scaled);
+    let a4 = mat_mul(&a2, &a2);
+    let a6 = mat_mul(&a2, &a4);
+
+    // U = A * (c1*I + c3*A2 + c5*A4), V = c0*I + c2*A2 + c4*A4 + c6*A6
+    let mut u_inner = vec![vec![0.0; n]; n];
+    let mut v = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            u_inner[i][j] = C[1] * identity[i][j] + C[3] * a2[i][j] + C[5] * a4[i][j];
+            v[i][j] = C[0] * identity[i][j] + C[2] * a2[i][j] + C[4] * a4[i][j] + C[6] * a6[i][j];
+        }
+    }
+    let u = mat_mul(&scaled, &u_inner);
+
+    let mut numerator = vec![vec![0.0; n]; n];
+    let mut denominator = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in 0..n {
+            numerator[i][j] = v[i][j] + u[i][j];
+            denominator[i][j] = v[i][j] - u[i][j];
+        }
+    }
+
+    let mut result = solve_dense(denominator, numerator);
+    for _ in 0..squarings {
+        result = mat_mul(&result, &result);
+    }
+    result
+}
+
+/// Marches a massive chunk forward one timestep using a precomputed
+/// matrix-exponential propagator, giving an exact (up to the Padé/scaling-and-
+/// squaring tolerance) and unconditionally stable update for the linear
+/// system `C dT/dt = K T + q` over one timestep, replacing the explicit RK4
+/// stages entirely.
+///
+/// Builds `A = C⁻¹K` and `b = C⁻¹q`, then exponentiates the augmented
+/// `(n+1) x (n+1)` block $`\left[\begin{smallmatrix}A & b \\ 0 & 0
+/// \end{smallmatrix}\right]\Delta t`$ in one shot: the top-left `n x n` block
+/// of the result is the propagator $`P = \exp(A\Delta t)`$, and the top `n`
+/// entries of its last column are the particular-solution offset
+/// $`A^{-1}(\exp(A\Delta t) - I)b`$, so that $`T^{n+1} = P\,T^n +
+/// \text{offset}`$. The propagator and offset are cached in
+/// [`ChunkMemory::expm_factorization`] so that repeated calls with the same
+/// `dt`/`k`/`q`/`c` (i.e., a constant network stepped at a constant
+/// timestep—the common case across an annual simulation's many steps) reuse
+/// them instead of re-exponentiating.
+fn expm_march(dt: Float, c: &Matrix, memory: &mut ChunkMemory, t: &mut Matrix) -> Result<(), String> {
+    let (n, ..) = t.size();
+
+    let mut flat_k = Vec::with_capacity(n * n);
+    let mut flat_q = Vec::with_capacity(n);
+    let mut flat_c = Vec::with_capacity(n);
+    let mut augmented = vec![vec![0.0; n + 1]; n + 1];
+    for i in 0..n {
+        let c_ii = c.get(i, i)?;
+        flat_c.push(c_ii);
+        for j in 0..n {
+            let k_ij = memory.k.get(i, j)?;
+            flat_k.push(k_ij);
+            augmented[i][j] = k_ij / c_ii * dt;
+        }
+        let q_i = memory.q.get(i, 0)?;
+        flat_q.push(q_i);
+        augmented[i][n] = q_i / c_ii * dt;
+    }
+
+    // `c` (the per-node capacitance diagonal `expm_march` is called with)
+    // must be part of the cache key alongside `dt`/`k`/`q`: it feeds
+    // `augmented` above just as directly as they do, and it isn't constant
+    // across calls in general—temperature-dependent capacitance (PCM via
+    // `set_phase_change_override`, or `set_specific_heat_override`; see
+    // `Discretization::has_temperature_dependent_properties`) can change
+    // `c` between two steps whose `k`/`q` happen to stay identical, which
+    // would otherwise silently reuse a propagator computed at the wrong
+    // latent-heat state.
+    let reuse = matches!(
+        &memory.expm_factorization,
+        Some(f) if f.dt == dt && f.k == flat_k && f.q == flat_q && f.c == flat_c
+    );
+
+    let propagated = if reuse {
+        memory.expm_factorization.as_ref().unwrap().propagator.clone()
+    } else {
+        let propagated = expm(&augmented);
+        memory.expm_factorization = Some(ExpmFactorization {
+            dt,
+            k: flat_k,
+            q: flat_q,
+            c: flat_c,
+            propagator: propagated.clone(),
+        });
+        propagated
+    };
+
+    let mut next = vec![0.0; n];
+    for i in 0..n {
+        // propagated[i][n] is the particular-solution offset contributed by `b`.
+        let mut sum = propagated[i][n];
+        for j in 0..n {
+            sum += propagated[i][j] * t.get(j, 0)?;
+        }
+        next[i] = sum;
+    }
+    for (i, v) in next.into_iter().enumerate() {
+        t.set(i, 0, v)?;
+    }
+
+    memory.propagator = Some(propagated);
+    Ok(())
+}
+
 fn rearrange_k(dt: Float, c: &Matrix, memory: &mut ChunkMemory) -> Result<(), String> {
     let (crows, ..) = c.size();
     // Rearrenge into dT = (dt/C) * K + (dt/C)*q
@@ -179,6 +601,56 @@ fn rearrange_k(dt: Float, c: &Matrix, memory: &mut ChunkMemory) -> Result<(), St
     Ok(())
 }
 
+/// Computes the explicit-Euler/RK4 diffusion stability bound
+/// `dt_max = min_i (2 * C.get(i,i) / |K.get(i,i)|)` for a chunk's tridiagonal
+/// `K` and diagonal `C`, i.e. the largest timestep for which an explicit
+/// march (`rk4`) will not oscillate or blow up at the stiffest node. Nodes
+/// whose diagonal `K` entry is (numerically) zero do not constrain the
+/// timestep and are skipped; if every node is unconstrained, returns
+/// `Float::INFINITY`.
+///
+/// `k` must be the *raw*, un-rearranged `K` (i.e. as filled by
+/// [`crate::discretization::Discretization::get_k_q`], before any
+/// [`rearrange_k`] call), since `rearrange_k` folds `dt`/`C` into `K` itself.
+fn explicit_stability_dt_max(k: &Matrix, c: &Matrix) -> Result<Float, String> {
+    let (n, ..) = c.size();
+    let mut dt_max = Float::INFINITY;
+    for i in 0..n {
+        let k_ii = k.get(i, i)?.abs();
+        if k_ii > 1e-12 {
+            let bound = 2. * c.get(i, i)? / k_ii;
+            dt_max = dt_max.min(bound);
+        }
+    }
+    Ok(dt_max)
+}
+
+/// Folds a [`TabsCoupling`] into this chunk's `memory.k`/`memory.q`, exactly
+/// like [`crate::discretization::Discretization::get_k_q`] folds in the
+/// convective boundary terms: `ua * (T_fluid - T_node)` splits into a
+/// constant `ua * t_fluid` term (added to `q`) and a `-ua` term on `T_node`'s
+/// own coefficient (added to `K`'s diagonal), so the coupling is implicit
+/// rather than a pure source. `tabs` is `(node_index, ua, t_fluid)`, already
+/// resolved from `SimulationState` by the caller (these chunk functions only
+/// see `Matrix` data, not `state`). A no-op if `node_index` isn't in this
+/// chunk's `[ini, fin)` range. Must be re-applied after every `get_k_q` call
+/// since that function zeroes `memory.k`/`memory.q` before rebuilding them.
+fn apply_tabs_coupling(
+    tabs: Option<(usize, Float, Float)>,
+    ini: usize,
+    fin: usize,
+    memory: &mut ChunkMemory,
+) -> Result<(), String> {
+    if let Some((node_index, ua, t_fluid)) = tabs {
+        if node_index >= ini && node_index < fin {
+            let local_i = node_index - ini;
+            memory.q.add_to_element(local_i, 0, ua * t_fluid)?;
+            memory.k.add_to_element(local_i, local_i, -ua)?;
+        }
+    }
+    Ok(())
+}
+
 /// Marches forward through time, solving the
 /// Ordinary Differential Equation that governs the heat transfer in walls.
 ///
@@ -218,6 +690,204 @@ fn rearrange_k(dt: Float, c: &Matrix, memory: &mut ChunkMemory) -> Result<(), St
 /// * $`k_2 = \Delta t \times f(t+\frac{\Delta t}{2}, T+\frac{k_1}{2})`$
 /// * $`k_3 = \Delta t \times f(t+\frac{\Delta t}{2}, T+\frac{k_2}{2})`$
 /// * $`k_4 = \Delta t \times f(t+\delta t, T+k_3 )`$
+/// Marches a massive chunk forward one timestep using the implicit
+/// $`\theta`$-method (see [`crate::discretization::IntegrationScheme`]),
+/// exploiting that `K` (and thus the system) is tridiagonal by extracting its
+/// three bands into `memory.sub_diag`/`memory.main_diag`/`memory.super_diag`
+/// and solving with the Thomas algorithm, in `O(n)` and with no full matrix
+/// inversion; the factorization itself is cached in
+/// [`ChunkMemory::theta_factorization`] and reused whenever a later call's
+/// `dt`/`theta`/bands are unchanged, rather than always refactorizing.
+///
+/// Because `UValue::Cavity` conductances depend on the node temperatures on
+/// both of their sides, `K` (and thus the system) is nonlinear. This is handled
+/// by re-linearizing `K` and `q` around the latest guess of `T^{n+1}` and
+/// repeating the solve a couple of times, i.e., a simple Newton-style fixed
+/// point iteration on the conductances.
+///
+/// This function assumes `memory.k` and `memory.q` have already been filled in
+/// by [`crate::discretization::Discretization::get_k_q`] for the *current*
+/// guess of `T^{n+1}` (on the first call, that guess is simply `T^n`).
+fn theta_method(
+    dt: Float,
+    theta: Float,
+    c: &Matrix,
+    memory: &mut ChunkMemory,
+    t: &mut Matrix,
+) -> Result<(), String> {
+    let (n, ..) = t.size();
+
+    // K' = dt * C^-1 * K ; q' = dt * C^-1 * q
+    rearrange_k(dt, c, memory)?;
+
+    // RHS = (I + (1-theta)*K') * T^n + q'
+    memory.k.prod_tri_diag_into(t, &mut memory.k1)?;
+    memory.k1 *= 1. - theta;
+    memory.k1 += t;
+    memory.k1 += &memory.q;
+
+    // LHS = I - theta*K'
+    memory.aux.copy_from(&memory.k);
+    memory.aux *= -theta;
+    for i in 0..n {
+        memory.aux.add_to_element(i, i, 1.0)?;
+    }
+
+    // `memory.aux` is tridiagonal (K, and thus K', only ever couple
+    // neighbouring nodes), so extract its three bands and solve with the
+    // Thomas algorithm instead of a general (dense) solver.
+    for i in 0..n {
+        memory.main_diag[i] = memory.aux.get(i, i)?;
+        memory.rhs[i] = memory.k1.get(i, 0)?;
+        memory.sub_diag[i] = if i > 0 {
+            memory.aux.get(i, i - 1).unwrap_or(0.0)
+        } else {
+            0.0
+        };
+        memory.super_diag[i] = if i + 1 < n {
+            memory.aux.get(i, i + 1).unwrap_or(0.0)
+        } else {
+            0.0
+        };
+    }
+
+    // Reuse the previous factorization when it was built from the exact
+    // same `(dt, theta)` and tridiagonal bands—e.g. a construction without
+    // `UValue::Cavity` conductances keeps the same `K` across every Newton
+    // iteration and every timestep, so this skips refactorizing on every
+    // call. Any change to the bands (stiffer/softer conductances, a
+    // different `dt`) falls back to a fresh factorization.
+    let reuse = matches!(
+        &memory.theta_factorization,
+        Some(f) if f.dt == dt
+            && f.theta == theta
+            && f.sub_diag == memory.sub_diag[..n]
+            && f.super_diag == memory.super_diag[..n]
+            && f.unfactored_main_diag == memory.main_diag[..n]
+    );
+
+    let factored_main_diag = if reuse {
+        memory.theta_factorization.as_ref().unwrap().factored_main_diag.clone()
+    } else {
+        let mut factored = memory.main_diag[..n].to_vec();
+        thomas_factorize(&memory.sub_diag[..n], &mut factored, &memory.super_diag[..n]);
+        memory.theta_factorization = Some(ThetaFactorization {
+            dt,
+            theta,
+            sub_diag: memory.sub_diag[..n].to_vec(),
+            super_diag: memory.super_diag[..n].to_vec(),
+            unfactored_main_diag: memory.main_diag[..n].to_vec(),
+            factored_main_diag: factored.clone(),
+        });
+        factored
+    };
+
+    let solved = thomas_solve_factored(
+        &factored_main_diag,
+        &memory.sub_diag[..n],
+        &memory.super_diag[..n],
+        &mut memory.rhs[..n],
+    );
+    for (i, v) in solved.into_iter().enumerate() {
+        t.set(i, 0, v)?;
+    }
+    Ok(())
+}
+
+/// One timestep's worth of boundary conditions for [`march_theta_series`]:
+/// everything [`Discretization::get_k_q`] needs, plus the per-node solar
+/// source term for that step (e.g. from [`ThermalSurfaceData::solar_source_term`]).
+pub struct ThetaBoundaryStep {
+    /// The length of this step, in seconds.
+    pub dt: Float,
+    /// The front boundary condition.
+    pub front_env: ConvectionParams,
+    /// The front convective film coefficient.
+    pub front_hs: Float,
+    /// The front linearized radiative film coefficient.
+    pub front_rad_hs: Float,
+    /// The back boundary condition.
+    pub back_env: ConvectionParams,
+    /// The back convective film coefficient.
+    pub back_hs: Float,
+    /// The back linearized radiative film coefficient.
+    pub back_rad_hs: Float,
+    /// The per-node solar source term for this step, added to `q`.
+    pub solar_radiation: Matrix,
+}
+
+/// Marches a whole [`Discretization`]'s node network (unlike
+/// [`ThermalSurfaceData::march_readonly`], there is no massive/no-mass chunk
+/// splitting here—every node is treated as massive, via its own
+/// [`Discretization::node_mass`]) through a supplied sequence of boundary
+/// conditions using the implicit theta-method (see
+/// [`crate::discretization::IntegrationScheme::Theta`]: `theta == 1.0` is
+/// Backward Euler, `theta == 0.5` is Crank-Nicolson), and returns the full
+/// temperature trajectory: `result[0]` is `initial_temperatures` and
+/// `result[i + 1]` is the state after `steps[i]`.
+///
+/// Since `C/dt + theta*K` only ever couples neighbouring nodes, each step's
+/// solve is the same tridiagonal Thomas-algorithm factor/solve
+/// [`theta_method`] already uses for [`march_mass_chunk`] rather than a
+/// general banded Gaussian elimination—an O(n) solve with the same
+/// n-diagonal bandwidth as `K`, reused every step.
+///
+/// A small Newton-style inner loop (mirroring [`march_mass_chunk`]'s own)
+/// re-evaluates `K`/`q` at the just-solved `T^{n+1}` a few times per step,
+/// to account for temperature-dependent conductances (e.g.
+/// `UValue::Cavity`) that a single linear solve wouldn't capture.
+pub fn march_theta_series(
+    discretization: &Discretization,
+    initial_temperatures: &Matrix,
+    theta: Float,
+    steps: &[ThetaBoundaryStep],
+) -> Result<Vec<Matrix>, String> {
+    const N_ITERATIONS: usize = 3;
+
+    let (n_nodes, ..) = initial_temperatures.size();
+    let mut memory = ChunkMemory::new(0, n_nodes);
+    let mut trajectory = Vec::with_capacity(steps.len() + 1);
+    trajectory.push(initial_temperatures.clone());
+
+    for step in steps {
+        let global_temperatures = trajectory.last().unwrap().clone();
+        let mut local_temps = global_temperatures.clone();
+
+        let c: Vec<Float> = (0..n_nodes)
+            .map(|i| {
+                let t = global_temperatures.get(i, 0).unwrap();
+                discretization.node_mass(i, t)
+            })
+            .collect();
+        let c = Matrix::diag(c);
+
+        for _ in 0..N_ITERATIONS {
+            discretization.get_k_q(
+                0,
+                n_nodes,
+                &local_temps,
+                &step.front_env,
+                step.front_hs,
+                step.front_rad_hs,
+                &step.back_env,
+                step.back_hs,
+                step.back_rad_hs,
+                true, // implicit_radiation
+                &mut memory,
+            )?;
+            for i in 0..n_nodes {
+                let v = step.solar_radiation.get(i, 0)?;
+                memory.q.add_to_element(i, 0, v)?;
+            }
+            theta_method(step.dt, theta, &c, &mut memory, &mut local_temps)?;
+        }
+
+        trajectory.push(local_temps);
+    }
+
+    Ok(trajectory)
+}
+
 fn rk4(
     // dt: Float,
     c: &Matrix,
@@ -306,6 +976,649 @@ fn rk4(
     Ok(())
 }
 
+/// Advances `t` forward by the whole chunk `dt`, like [`rk4`], but estimates
+/// its own local error via step-doubling and sub-steps as needed instead of
+/// taking a single fixed-size step: each trial sub-step of size `h` is taken
+/// twice—once as a full [`rk4`] step, once as two consecutive half-steps of
+/// `h/2`—and, since RK4 is 4th order, their difference divided by
+/// `2^4 - 1 = 15` is the local error estimate. The sub-step is accepted
+/// (taking the Richardson-extrapolated value `t_half + (t_half - t_full)/15`)
+/// once that estimate falls within `options.atol + options.rtol*|T|`;
+/// otherwise it is retried with `h` shrunk by `0.9*(tol/err)^(1/5)` (clamped
+/// to `[0.2, 5.0]`). `raw_k`/`raw_q` are the un-rearranged `K`/`q` for this
+/// chunk (i.e. `memory.k`/`memory.q` as filled by [`Discretization::get_k_q`],
+/// before any [`rearrange_k`] call), since each trial sub-step needs to
+/// re-rearrange them for its own step size; `memory`'s `k1`-`k4`/`aux`
+/// buffers are reused by every [`rk4`] call within.
+#[allow(clippy::too_many_arguments)]
+fn rk4_adaptive(
+    raw_k: &Matrix,
+    raw_q: &Matrix,
+    c: &Matrix,
+    options: &crate::discretization::Rk4AdaptiveOptions,
+    memory: &mut ChunkMemory,
+    t: &mut Matrix,
+    dt: Float,
+) -> Result<(), String> {
+    const SAFETY: Float = 0.9;
+    const MIN_GROWTH: Float = 0.2;
+    const MAX_GROWTH: Float = 5.0;
+    const RICHARDSON: Float = 15.0; // 2^4 - 1
+
+    let min_step = dt.abs() * options.min_step_fraction;
+    let mut elapsed = 0.0;
+    let mut h = dt;
+    #[cfg(debug_assertions)]
+    let mut n_accepted_substeps: usize = 0;
+
+    while elapsed < dt - 1e-9 {
+        h = h.min(dt - elapsed);
+
+        // One full step of size `h`
+        let mut t_full = t.clone();
+        memory.k.copy_from(raw_k);
+        memory.q.copy_from(raw_q);
+        rearrange_k(h, c, memory)?;
+        rk4(c, memory, &mut t_full)?;
+
+        // Two half-steps of size `h/2`
+        let mut t_half = t.clone();
+        memory.k.copy_from(raw_k);
+        memory.q.copy_from(raw_q);
+        rearrange_k(h / 2., c, memory)?;
+        rk4(c, memory, &mut t_half)?;
+        rk4(c, memory, &mut t_half)?;
+
+        let (nrows, _) = t_full.size();
+        let mut err_norm: Float = 0.0;
+        for i in 0..nrows {
+            let full = t_full.get(i, 0)?;
+            let half = t_half.get(i, 0)?;
+            let scale = options.atol + options.rtol * half.abs().max(t.get(i, 0)?.abs());
+            let e = (full - half) / RICHARDSON / scale;
+            err_norm += e * e;
+        }
+        err_norm = (err_norm / nrows as Float).sqrt();
+
+        let accept = err_norm <= 1.0 || h <= min_step + 1e-12;
+
+        let mut growth = if err_norm > 1e-12 {
+            SAFETY * err_norm.powf(-1.0 / 5.0)
+        } else {
+            MAX_GROWTH
+        };
+        growth = growth.clamp(MIN_GROWTH, MAX_GROWTH);
+
+        if accept {
+            for i in 0..nrows {
+                let full = t_full.get(i, 0)?;
+                let half = t_half.get(i, 0)?;
+                t.set(i, 0, half + (half - full) / RICHARDSON)?;
+            }
+            elapsed += h;
+            #[cfg(debug_assertions)]
+            {
+                n_accepted_substeps += 1;
+            }
+            h = (h * growth).clamp(min_step, dt);
+        } else {
+            h = (h * growth).max(min_step);
+        }
+    }
+
+    #[cfg(debug_assertions)]
+    if n_accepted_substeps > 1 {
+        dbg!(
+            "rk4_adaptive: chunk dt={} resolved in {} accepted substep(s), final step size {}",
+            dt,
+            n_accepted_substeps,
+            h
+        );
+    }
+
+    Ok(())
+}
+
+/// `*target += coefficient * term`, without a fused primitive on [`Matrix`]:
+/// clones `term`, scales the clone, and adds it in place. Used by
+/// [`rkf45_step`] to accumulate each stage's linear combination of previous
+/// stages.
+fn add_scaled(target: &mut Matrix, coefficient: Float, term: &Matrix) -> Result<(), String> {
+    if coefficient == 0.0 {
+        return Ok(());
+    }
+    let mut scaled = term.clone();
+    scaled *= coefficient;
+    *target += &scaled;
+    Ok(())
+}
+
+/// Advances `t` forward by one step of size `h` (as already baked into
+/// `memory.k`/`memory.q` by [`rearrange_k`]) using the classic
+/// Runge–Kutta–Fehlberg 4(5) embedded pair: six stages `k1..k6`, combined
+/// two ways into a 4th-order solution (into the scratch [`ChunkMemory::t4`])
+/// and a 5th-order solution (written back into `t` in place), whose
+/// difference [`rkf45_adaptive`] uses as a local error estimate—without
+/// the cost of [`rk4_adaptive`]'s step-doubling (two extra stages instead of
+/// a whole second RK4 pass per trial step).
+fn rkf45_step(memory: &mut ChunkMemory, t: &mut Matrix) -> Result<(), String> {
+    const A21: Float = 1.0 / 4.0;
+    const A31: Float = 3.0 / 32.0;
+    const A32: Float = 9.0 / 32.0;
+    const A41: Float = 1932.0 / 2197.0;
+    const A42: Float = -7200.0 / 2197.0;
+    const A43: Float = 7296.0 / 2197.0;
+    const A51: Float = 439.0 / 216.0;
+    const A52: Float = -8.0;
+    const A53: Float = 3680.0 / 513.0;
+    const A54: Float = -845.0 / 4104.0;
+    const A61: Float = -8.0 / 27.0;
+    const A62: Float = 2.0;
+    const A63: Float = -3544.0 / 2565.0;
+    const A64: Float = 1859.0 / 4104.0;
+    const A65: Float = -11.0 / 40.0;
+    // 4th order solution weights (b3 is implicitly 0).
+    const B4_1: Float = 25.0 / 216.0;
+    const B4_3: Float = 1408.0 / 2565.0;
+    const B4_4: Float = 2197.0 / 4104.0;
+    const B4_5: Float = -1.0 / 5.0;
+    // 5th order solution weights (b2 is implicitly 0).
+    const B5_1: Float = 16.0 / 135.0;
+    const B5_3: Float = 6656.0 / 12825.0;
+    const B5_4: Float = 28561.0 / 56430.0;
+    const B5_5: Float = -9.0 / 50.0;
+    const B5_6: Float = 2.0 / 55.0;
+
+    memory.k1 *= 0.0;
+    memory.k2 *= 0.0;
+    memory.k3 *= 0.0;
+    memory.k4 *= 0.0;
+    memory.k5 *= 0.0;
+    memory.k6 *= 0.0;
+
+    // k1 = K' * T + q'
+    memory.k.prod_tri_diag_into(t, &mut memory.k1)?;
+    memory.k1 += &memory.q;
+
+    // k2 = K' * (T + A21*k1) + q'
+    memory.aux.copy_from(t);
+    add_scaled(&mut memory.aux, A21, &memory.k1)?;
+    memory.k.prod_tri_diag_into(&memory.aux, &mut memory.k2)?;
+    memory.k2 += &memory.q;
+
+    // k3
+    memory.aux.copy_from(t);
+    add_scaled(&mut memory.aux, A31, &memory.k1)?;
+    add_scaled(&mut memory.aux, A32, &memory.k2)?;
+    memory.k.prod_tri_diag_into(&memory.aux, &mut memory.k3)?;
+    memory.k3 += &memory.q;
+
+    // k4
+    memory.aux.copy_from(t);
+    add_scaled(&mut memory.aux, A41, &memory.k1)?;
+    add_scaled(&mut memory.aux, A42, &memory.k2)?;
+    add_scaled(&mut memory.aux, A43, &memory.k3)?;
+    memory.k.prod_tri_diag_into(&memory.aux, &mut memory.k4)?;
+    memory.k4 += &memory.q;
+
+    // k5
+    memory.aux.copy_from(t);
+    add_scaled(&mut memory.aux, A51, &memory.k1)?;
+    add_scaled(&mut memory.aux, A52, &memory.k2)?;
+    add_scaled(&mut memory.aux, A53, &memory.k3)?;
+    add_scaled(&mut memory.aux, A54, &memory.k4)?;
+    memory.k.prod_tri_diag_into(&memory.aux, &mut memory.k5)?;
+    memory.k5 += &memory.q;
+
+    // k6
+    memory.aux.copy_from(t);
+    add_scaled(&mut memory.aux, A61, &memory.k1)?;
+    add_scaled(&mut memory.aux, A62, &memory.k2)?;
+    add_scaled(&mut memory.aux, A63, &memory.k3)?;
+    add_scaled(&mut memory.aux, A64, &memory.k4)?;
+    add_scaled(&mut memory.aux, A65, &memory.k5)?;
+    memory.k.prod_tri_diag_into(&memory.aux, &mut memory.k6)?;
+    memory.k6 += &memory.q;
+
+    // 4th order solution, into the scratch buffer—used only to estimate error.
+    memory.t4.copy_from(t);
+    add_scaled(&mut memory.t4, B4_1, &memory.k1)?;
+    add_scaled(&mut memory.t4, B4_3, &memory.k3)?;
+    add_scaled(&mut memory.t4, B4_4, &memory.k4)?;
+    add_scaled(&mut memory.t4, B4_5, &memory.k5)?;
+
+    // 5th order solution, written back into `t` in place—the accepted value.
+    add_scaled(t, B5_1, &memory.k1)?;
+    add_scaled(t, B5_3, &memory.k3)?;
+    add_scaled(t, B5_4, &memory.k4)?;
+    add_scaled(t, B5_5, &memory.k5)?;
+    add_scaled(t, B5_6, &memory.k6)?;
+
+    Ok(())
+}
+
+/// Advances `t` forward by the whole chunk `dt`, like [`rk4_adaptive`], but
+/// estimates its own local error from a single embedded RKF45 step (see
+/// [`rkf45_step`]) instead of step-doubling a plain RK4: each trial sub-step
+/// of size `h` produces both a 4th- and 5th-order update from the same six
+/// stages, and `err`—the max-norm, over nodes, of their difference scaled by
+/// `options.atol + options.rtol*|T|`—is compared against `1.0` to decide
+/// whether to accept the 5th-order update (accepting regardless once `h`
+/// has shrunk to `options.min_step_fraction` of `dt`, so the march can't
+/// stall). Either way `h` is rescaled by `clamp((1/err)^(1/5), 0.2, 5.0)`
+/// (with a `0.9` safety factor, as in [`rk4_adaptive`]) before the next
+/// trial. `raw_k`/`raw_q` are the un-rearranged `K`/`q` for this chunk, since
+/// each trial needs to re-rearrange them for its own `h`.
+///
+/// Returns `Ok(true)` if the march reached `dt` within `options.rk_nmax`
+/// sub-step attempts, with the accepted solution written into `t`. Returns
+/// `Ok(false)` if the cap was hit first—`t` is left untouched in that
+/// case, so the caller can fall back to [`fixed_subdivision_rk4`] for the
+/// whole chunk instead of patching together a partially-adaptive step.
+#[allow(clippy::too_many_arguments)]
+fn rkf45_adaptive(
+    raw_k: &Matrix,
+    raw_q: &Matrix,
+    c: &Matrix,
+    options: &crate::discretization::Rkf45Options,
+    memory: &mut ChunkMemory,
+    t: &mut Matrix,
+    dt: Float,
+) -> Result<bool, String> {
+    const SAFETY: Float = 0.9;
+    const MIN_GROWTH: Float = 0.2;
+    const MAX_GROWTH: Float = 5.0;
+
+    let min_step = dt.abs() * options.min_step_fraction;
+    let mut elapsed = 0.0;
+    let mut h = dt;
+    let mut attempts = 0;
+    let mut trial_t = t.clone();
+
+    while elapsed < dt - 1e-9 {
+        attempts += 1;
+        if attempts > options.rk_nmax {
+            return Ok(false);
+        }
+        h = h.min(dt - elapsed);
+
+        let mut t_trial = trial_t.clone();
+        memory.k.copy_from(raw_k);
+        memory.q.copy_from(raw_q);
+        rearrange_k(h, c, memory)?;
+        rkf45_step(memory, &mut t_trial)?;
+
+        let (nrows, _) = t_trial.size();
+        let mut err_norm: Float = 0.0;
+        for i in 0..nrows {
+            let t5 = t_trial.get(i, 0)?;
+            let t4 = memory.t4.get(i, 0)?;
+            let scale = options.atol + options.rtol * t5.abs().max(trial_t.get(i, 0)?.abs());
+            let e = (t5 - t4).abs() / scale;
+            if e > err_norm {
+                err_norm = e;
+            }
+        }
+
+        let accept = err_norm <= 1.0 || h <= min_step + 1e-12;
+
+        let mut growth = if err_norm > 1e-12 {
+            SAFETY * err_norm.powf(-1.0 / 5.0)
+        } else {
+            MAX_GROWTH
+        };
+        growth = growth.clamp(MIN_GROWTH, MAX_GROWTH);
+
+        if accept {
+            trial_t.copy_from(&t_trial);
+            elapsed += h;
+            h = (h * growth).clamp(min_step, dt);
+        } else {
+            h = (h * growth).max(min_step);
+        }
+    }
+
+    t.copy_from(&trial_t);
+    Ok(true)
+}
+
+/// Advances `t` forward by the whole chunk `dt` using a fixed number of
+/// explicit [`rk4`] sub-steps, sized so each is below
+/// `explicit_stability_dt_max`'s bound for `raw_k`/`c`. This is the same
+/// fixed-subdivision strategy [`march_mass_chunk`] uses for
+/// [`crate::discretization::IntegrationScheme::RK4`], factored out so
+/// [`rkf45_adaptive`] can fall back to it when it exhausts `rk_nmax`
+/// attempts without reaching `dt`.
+fn fixed_subdivision_rk4(
+    raw_k: &Matrix,
+    raw_q: &Matrix,
+    c: &Matrix,
+    dt: Float,
+    memory: &mut ChunkMemory,
+    t: &mut Matrix,
+) -> Result<(), String> {
+    let dt_max = explicit_stability_dt_max(raw_k, c)?;
+    let n_substeps = if dt_max.is_finite() && dt_max > 0.0 {
+        (dt / dt_max).ceil().max(1.0) as usize
+    } else {
+        1
+    };
+    let h = dt / n_substeps as Float;
+    for _ in 0..n_substeps {
+        memory.k.copy_from(raw_k);
+        memory.q.copy_from(raw_q);
+        rearrange_k(h, c, memory)?;
+        rk4(c, memory, t)?;
+    }
+    Ok(())
+}
+
+/// Solves a single massive chunk—the part of [`ThermalSurfaceData::march_readonly`]'s
+/// massive-node phase that only needs the [`Discretization`] and plain `Matrix` data, not the
+/// parent surface itself. Because it never touches `self.parent` (an
+/// `Rc<T>`, so `!Sync`), every chunk belonging to a surface—and, for that
+/// matter, chunks belonging to different surfaces—can be solved concurrently
+/// with `rayon` behind the `parallel` feature, as [`ThermalSurfaceData::march_readonly`]
+/// does; `global_temperatures` is read-only here, and the resulting slice is
+/// written back by the caller once every chunk has finished.
+#[allow(clippy::too_many_arguments)]
+fn march_mass_chunk(
+    discretization: &Discretization,
+    global_temperatures: &Matrix,
+    solar_radiation: &Matrix,
+    dt: Float,
+    front_env: &ConvectionParams,
+    front_hs: Float,
+    front_rad_hs: Float,
+    back_env: &ConvectionParams,
+    back_hs: Float,
+    back_rad_hs: Float,
+    ini: usize,
+    fin: usize,
+    memory: &mut ChunkMemory,
+    tabs: Option<(usize, Float, Float)>,
+) -> Result<Matrix, String> {
+    discretization.get_k_q(
+        ini,
+        fin,
+        global_temperatures,
+        front_env,
+        front_hs,
+        front_rad_hs,
+        back_env,
+        back_hs,
+        back_rad_hs,
+        true, // implicit_radiation: fold the boundary radiative tangent into K for a larger stable step
+        memory,
+    )?;
+    apply_tabs_coupling(tabs, ini, fin, memory)?;
+
+    // `node_mass` re-evaluates a node's capacitance against its current
+    // temperature when it has a temperature-dependent specific heat (see
+    // `Discretization::set_specific_heat_override`), instead of reading the
+    // constant `segments[i].0` baked in at discretization time—so `C` tracks
+    // the wall's state exactly like `K` already does via `UValue::u_value`.
+    let c: Vec<Float> = (ini..fin)
+        .map(|global_i| {
+            let t = global_temperatures.get(global_i, 0).unwrap();
+            discretization.node_mass(global_i, t)
+        })
+        .collect();
+    let c = Matrix::diag(c);
+
+    // ... here we add solar gains
+    for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
+        let v = solar_radiation.get(global_i, 0).unwrap();
+        memory.q.add_to_element(local_i, 0, v).unwrap();
+    }
+
+    // Use the chosen integration scheme to update the temperatures of massive nodes.
+    let mut local_temps = Matrix::new(0.0, fin - ini, 1);
+    for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
+        let v = global_temperatures.get(global_i, 0).unwrap();
+        local_temps.set(local_i, 0, v).unwrap();
+    }
+
+    match discretization.scheme {
+        crate::discretization::IntegrationScheme::RK4 => {
+            // `rk4` is explicit, so it is only stable for `dt` below
+            // `explicit_stability_dt_max`; rather than let the user
+            // hand-tune the timestep for thin layers, split `dt` into
+            // as many uniform substeps as the stiffest node in this
+            // chunk needs. `K`/`C` vary over time (the convective
+            // boundary terms depend on the weather/state), so the
+            // bound is recomputed here rather than cached once.
+            let raw_k = memory.k.clone();
+            let raw_q = memory.q.clone();
+            fixed_subdivision_rk4(&raw_k, &raw_q, &c, dt, memory, &mut local_temps)?;
+        }
+        crate::discretization::IntegrationScheme::AdaptiveRK4 { options } => {
+            let raw_k = memory.k.clone();
+            let raw_q = memory.q.clone();
+            rk4_adaptive(&raw_k, &raw_q, &c, &options, memory, &mut local_temps, dt)?;
+        }
+        crate::discretization::IntegrationScheme::Theta { theta } => {
+            // A couple of Newton-style iterations to account for the
+            // nonlinearity introduced by `UValue::Cavity` conductances,
+            // which depend on the (yet unknown) T^{n+1}.
+            const N_ITERATIONS: usize = 3;
+            for _ in 0..N_ITERATIONS {
+                let mut temp_global = global_temperatures.clone();
+                for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
+                    let v = local_temps.get(local_i, 0).unwrap();
+                    temp_global.set(global_i, 0, v).unwrap();
+                }
+                discretization.get_k_q(
+                    ini,
+                    fin,
+                    &temp_global,
+                    front_env,
+                    front_hs,
+                    front_rad_hs,
+                    back_env,
+                    back_hs,
+                    back_rad_hs,
+                    true, // implicit_radiation
+                    memory,
+                )?;
+                apply_tabs_coupling(tabs, ini, fin, memory)?;
+                for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
+                    let v = solar_radiation.get(global_i, 0).unwrap();
+                    memory.q.add_to_element(local_i, 0, v).unwrap();
+                }
+                theta_method(dt, theta, &c, memory, &mut local_temps)?;
+            }
+        }
+        crate::discretization::IntegrationScheme::Exponential => {
+            expm_march(dt, &c, memory, &mut local_temps)?;
+        }
+        crate::discretization::IntegrationScheme::RKF45 { options } => {
+            let raw_k = memory.k.clone();
+            let raw_q = memory.q.clone();
+            let converged =
+                rkf45_adaptive(&raw_k, &raw_q, &c, &options, memory, &mut local_temps, dt)?;
+            if !converged {
+                // Exhausted rk_nmax attempts without reaching `dt`: fall back
+                // to a fixed explicit subdivision rather than failing the march.
+                fixed_subdivision_rk4(&raw_k, &raw_q, &c, dt, memory, &mut local_temps)?;
+            }
+        }
+    }
+
+    Ok(local_temps)
+}
+
+/// A Newton tolerance on the no-mass solve's `‖ΔT‖_∞`, in `°C`—below this,
+/// [`march_nomass_chunk`] accepts the current iterate as converged.
+const NOMASS_NEWTON_TOL: Float = 1e-6;
+
+/// A hard cap on [`march_nomass_chunk`]'s Newton iterations. The only
+/// remaining nonlinearity once the boundary radiation is folded into `K`
+/// (see below) is a temperature-dependent conductive `u_value` (e.g. a
+/// cavity's convective correlation), so this should converge in a handful of
+/// iterations; the cap just guards against a pathological construction.
+const NOMASS_MAX_ITERATIONS: usize = 64;
+
+/// Solves a single nomass chunk—the part of [`ThermalSurfaceData::march_readonly`]'s
+/// nomass-node phase that only needs the [`Discretization`] and plain `Matrix` data. See
+/// [`march_mass_chunk`] for why this makes it safe to run concurrently.
+///
+/// Solves the steady, no-mass energy balance `ini..fin` of a surface via
+/// Newton–Raphson on the residual `F(T) = K(T)·T − q(T)`.
+///
+/// [`Discretization::get_k_q`] (called here with `implicit_radiation: true`)
+/// already builds `K`/`q` fully linearly in `T`—conduction, the convective
+/// boundary terms and the boundary radiative exchange all sit on `K`'s
+/// diagonal, the latter via `front_rad_hs`/`back_rad_hs`, the closed-form
+/// radiative Jacobian entry `4·ε·σ·(T + 273.15)³`—so each `K·T = −q` solve
+/// below is already the Newton step `J·ΔT = −F`, expressed directly in terms
+/// of the new `T` (rather than `ΔT`) the same way the old fixed-point loop
+/// was. Unlike [`ThermalSurfaceData::march_readonly`]'s massive-node path
+/// (which linearizes `h_r` once per timestep, at the previous timestep's
+/// surface temperature, since its RK4/theta march needs a fixed `K`/`q` for
+/// the whole step), `front_rad_hs`/`back_rad_hs` are recomputed here from
+/// `local_temperatures`' *current* boundary-node iterate at the top of every
+/// loop pass, so the radiative Jacobian itself converges alongside `T`
+/// instead of staying pinned to its pre-iteration value.
+#[allow(clippy::too_many_arguments)]
+fn march_nomass_chunk(
+    discretization: &Discretization,
+    global_temperatures: &Matrix,
+    solar_radiation: &Matrix,
+    front_env: &ConvectionParams,
+    front_hs: Float,
+    front_emissivity: Float,
+    back_env: &ConvectionParams,
+    back_hs: Float,
+    back_emissivity: Float,
+    ini: usize,
+    fin: usize,
+    memory: &mut ChunkMemory,
+    tabs: Option<(usize, Float, Float)>,
+) -> Result<Matrix, String> {
+    let mut local_temperatures = global_temperatures.clone();
+
+    for _count in 0..NOMASS_MAX_ITERATIONS {
+        // Recompute the radiative Jacobian entries from this iterate's
+        // boundary-node temperatures, rather than reusing a value fixed
+        // before the loop started.
+        let front_ts = local_temperatures.get(ini, 0)?;
+        let front_rad_hs = 4.
+            * front_emissivity
+            * crate::SIGMA
+            * (273.15 + (front_env.rad_temperature + front_ts) / 2.).powi(3);
+        let back_ts = local_temperatures.get(fin - 1, 0)?;
+        let back_rad_hs = 4.
+            * back_emissivity
+            * crate::SIGMA
+            * (273.15 + (back_env.rad_temperature + back_ts) / 2.).powi(3);
+
+        // Calculate K/q based on heat transfer (convection, IR radiation)
+        discretization.get_k_q(
+            ini,
+            fin,
+            &local_temperatures,
+            front_env,
+            front_hs,
+            front_rad_hs,
+            back_env,
+            back_hs,
+            back_rad_hs,
+            true, // implicit_radiation
+            memory,
+        )?;
+        apply_tabs_coupling(tabs, ini, fin, memory)?;
+
+        // add solar gains
+        for (local_i, i) in (ini..fin).into_iter().enumerate() {
+            let v = solar_radiation.get(i, 0)?;
+            memory.q.add_to_element(local_i, 0, v)?;
+        }
+        memory.q *= -1.;
+
+        let temps = memory.k.clone().mut_n_diag_gaussian(memory.q.clone(), 3)?; // and just like that, q is the new temperatures
+
+        let mut max_delta: Float = 0.0;
+        for (local_i, i) in (ini..fin).into_iter().enumerate() {
+            let new_t = temps.get(local_i, 0)?;
+            let old_t = local_temperatures.get(i, 0)?;
+            max_delta = max_delta.max((new_t - old_t).abs());
+            local_temperatures.set(i, 0, new_t)?;
+        }
+
+        assert!(!max_delta.is_nan());
+
+        if max_delta < NOMASS_NEWTON_TOL {
+            break;
+        }
+    }
+
+    let mut local_temps = Matrix::new(0.0, fin - ini, 1);
+    for (local_i, i) in (ini..fin).into_iter().enumerate() {
+        local_temps.set(local_i, 0, local_temperatures.get(i, 0)?)?;
+    }
+    Ok(local_temps)
+}
+
+/// A hydronic/TABS (thermally-activated building system) coupling of one
+/// discretization node to a circulating fluid loop—e.g. an embedded
+/// radiant-floor pipe. The node exchanges `ua * (T_fluid - T_node)` with the
+/// loop, folded directly into that node's `K`/`q` entries (implicit, like
+/// the convective boundary terms in [`crate::discretization::Discretization::get_k_q`])
+/// rather than added as a plain source, so the nomass Gauss solve and the
+/// massive RK4/Theta marches stay stable even for a large `ua`. Set on a
+/// surface with [`ThermalSurfaceData::set_tabs_coupling`].
+#[derive(Debug, Clone, Copy)]
+pub struct TabsCoupling {
+    /// Index (local to this surface, `0` is the outermost/front node) of
+    /// the node coupled to the fluid loop
+    pub node_index: usize,
+    /// Fluid-to-node conductance (W/K)
+    pub ua: Float,
+    /// `SimulationState` index the loop's inlet fluid temperature (°C) is
+    /// read from every timestep
+    pub fluid_temperature_index: usize,
+    /// `SimulationState` index the loop's fluid mass flow rate (kg/s) is
+    /// read from every timestep
+    pub mass_flow_index: usize,
+    /// `SimulationState` index the computed loop outlet temperature (°C) is
+    /// written back to after marching, if the plant loop needs it
+    pub outlet_temperature_index: Option<usize>,
+    /// Specific heat capacity of the circulating fluid (J/kg-K)
+    pub fluid_cp: Float,
+}
+
+impl TabsCoupling {
+    /// Water's specific heat capacity (J/kg-K), the default [`Self::fluid_cp`]
+    pub const WATER_CP: Float = 4186.0;
+
+    /// Builds a [`TabsCoupling`] with [`Self::fluid_cp`] defaulting to
+    /// [`Self::WATER_CP`] and no `outlet_temperature_index` (i.e., the
+    /// outlet temperature is computed but not written back to `state`).
+    pub fn new(
+        node_index: usize,
+        ua: Float,
+        fluid_temperature_index: usize,
+        mass_flow_index: usize,
+    ) -> Self {
+        Self {
+            node_index,
+            ua,
+            fluid_temperature_index,
+            mass_flow_index,
+            outlet_temperature_index: None,
+            fluid_cp: Self::WATER_CP,
+        }
+    }
+
+    /// Sets the `SimulationState` index the computed loop outlet
+    /// temperature is written back to.
+    pub fn with_outlet_temperature_index(mut self, index: usize) -> Self {
+        self.outlet_temperature_index = Some(index);
+        self
+    }
+}
+
 /// This is a Surface from the point of view of our thermal solver.
 /// Since this module only calculate heat transfer (and not short-wave solar
 /// radiation, e.g., light), both simple_model::Fenestration and simple_model::Surface
@@ -353,6 +1666,26 @@ pub struct ThermalSurfaceData<T: SurfaceTrait> {
     /// The cosine of the tilt angle (normal * Vector3D(0., 0., 1.))
     pub cos_tilt: Float,
 
+    /// The convection correlation used for this surface's exterior and
+    /// interior coefficients (see [`crate::convection::ConvectionAlgorithm`]).
+    /// Defaults to TARP; set with [`Self::set_convection_algorithm`] to
+    /// calibrate against measured data.
+    pub convection_algorithm: ConvectionAlgorithm,
+
+    /// The burial depth (m) used to evaluate the
+    /// [`crate::ground::GroundTemperatureModel`] for a
+    /// [`Boundary::Ground`] side of this surface. Defaults to `0.0`
+    /// (slab-on-grade); set with [`Self::set_ground_depth`] for a
+    /// basement wall or floor.
+    pub ground_depth: Float,
+
+    /// A minimum (gustiness) wind speed, in m/s, combined in quadrature
+    /// with `wind_speed * wind_speed_modifier` when evaluating exterior
+    /// convection so that calm-air conditions do not collapse the
+    /// forced-convection term to zero. Defaults to `0.3`; set with
+    /// [`Self::set_v_gust`] to calibrate per site.
+    pub v_gust: Float,
+
     /// The chunks of nodes that have mass
     pub massive_chunks: Vec<(usize, usize)>,
 
@@ -367,6 +1700,20 @@ pub struct ThermalSurfaceData<T: SurfaceTrait> {
     /// to the back incident radiation (i.e., they do not add up to 1.0)
     pub back_alphas: Matrix,
 
+    /// One slot per node: the `SimulationState` index this node's external
+    /// heat source (W)—embedded electric resistance heating, a heating
+    /// cable, or any other user-placed volumetric source—is read from every
+    /// [`Self::march`]/[`Self::march_readonly`], or `None` if that node has
+    /// no such source. Unlike [`Self::set_node_heat_source`] (a fixed value
+    /// that persists until changed), the value behind a registered index is
+    /// re-read from `state` each timestep, so it can be scheduled/time-varying.
+    /// Set with [`Self::set_node_heat_source_index`].
+    pub node_heat_source_indices: Vec<Option<usize>>,
+
+    /// The hydronic/TABS coupling of a node to a circulating fluid loop, if
+    /// any; set with [`Self::set_tabs_coupling`].
+    pub tabs: Option<TabsCoupling>,
+
     /// [**Only available during testing**] this allows setting a fixed convection
     /// coefficient
     #[cfg(debug_assertions)]
@@ -376,6 +1723,101 @@ pub struct ThermalSurfaceData<T: SurfaceTrait> {
     /// coefficient
     #[cfg(debug_assertions)]
     pub back_hs: Option<Float>,
+
+    /// A thermochromic/switchable coating on the front (i.e., exterior)
+    /// face, if any; re-evaluated against the front node's own temperature
+    /// by [`Self::update_coatings`]. `None` (the default) keeps
+    /// `front_alphas`/`front_emissivity` fixed at their constructed values.
+    pub front_coating: Option<ThermochromicCoating>,
+
+    /// A thermochromic/switchable coating on the back (i.e., interior)
+    /// face, if any; re-evaluated against the back node's own temperature
+    /// by [`Self::update_coatings`]. `None` (the default) keeps
+    /// `back_alphas`/`back_emissivity` fixed at their constructed values.
+    pub back_coating: Option<ThermochromicCoating>,
+}
+
+/// A temperature-triggered optical coating with a hysteresis band: once
+/// the surface reaches `rising_threshold_temperature` the coating
+/// switches to its `high_solar_absorptance`/`high_emissivity`, and it
+/// doesn't switch back to `low_solar_absorptance`/`low_emissivity` until
+/// the surface has cooled to `falling_threshold_temperature`. This models
+/// devices whose shortwave absorptance and longwave emissivity respond to
+/// the surface's own temperature rather than being fixed material
+/// constants—e.g. thermochromic paints and switchable roof coatings that
+/// turn reflective once hot, shedding solar gain—see
+/// [`ThermalSurfaceData::update_coatings`].
+///
+/// `rising_threshold_temperature == falling_threshold_temperature`
+/// collapses this to a simple single-threshold switch (no hysteresis
+/// band). A piecewise-linear absorptance-vs-temperature curve and a
+/// per-timestep schedule-driven coating are both natural extensions of
+/// this same `properties_at` interface, but aren't implemented here:
+/// `heat` has no existing dependency on the `schedule` crate (it's only
+/// ever used by callers, e.g. via `weather::SyntheticWeather`), and
+/// introducing one for a single optional feature didn't seem worth the
+/// new coupling.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ThermochromicCoating {
+    /// The surface temperature (C) at or above which the coating switches
+    /// from its "low" to its "high" properties.
+    pub rising_threshold_temperature: Float,
+    /// The surface temperature (C) at or below which the coating switches
+    /// back from its "high" to its "low" properties.
+    pub falling_threshold_temperature: Float,
+    /// Solar absorptance (0 to 1) in the "low" state.
+    pub low_solar_absorptance: Float,
+    /// Solar absorptance (0 to 1) in the "high" state.
+    pub high_solar_absorptance: Float,
+    /// Thermal (longwave) emissivity (0 to 1) in the "low" state.
+    pub low_emissivity: Float,
+    /// Thermal (longwave) emissivity (0 to 1) in the "high" state.
+    pub high_emissivity: Float,
+    /// Whether the coating is currently in its "high" state—updated by
+    /// [`Self::properties_at`] as the surface crosses either threshold,
+    /// and otherwise held fixed (that's the hysteresis). Starts `false`
+    /// (the "low" state) on a freshly-constructed coating.
+    pub currently_high: bool,
+}
+
+impl ThermochromicCoating {
+    /// The `(solar_absorptance, emissivity)` pair the coating presents at
+    /// the given surface temperature, updating [`Self::currently_high`] if
+    /// `surface_temperature` has crossed the relevant threshold.
+    pub fn properties_at(&mut self, surface_temperature: Float) -> (Float, Float) {
+        if surface_temperature >= self.rising_threshold_temperature {
+            self.currently_high = true;
+        } else if surface_temperature <= self.falling_threshold_temperature {
+            self.currently_high = false;
+        }
+        if self.currently_high {
+            (self.high_solar_absorptance, self.high_emissivity)
+        } else {
+            (self.low_solar_absorptance, self.low_emissivity)
+        }
+    }
+}
+
+/// Rescales `alphas` so its entries sum to `target_total`, preserving their
+/// relative distribution across nodes—used by
+/// [`ThermalSurfaceData::update_coatings`] to retarget a surface's absorbed
+/// fraction without disturbing how it is split between nodes.
+fn rescale_alphas(alphas: &Matrix, target_total: Float) -> Result<Matrix, String> {
+    let (n_rows, n_cols) = alphas.size();
+    let mut total = 0.0;
+    for i in 0..n_rows {
+        total += alphas.get(i, 0)?;
+    }
+    let ratio = if total.abs() > 1e-12 {
+        target_total / total
+    } else {
+        0.0
+    };
+    let mut scaled = Matrix::new(0.0, n_rows, n_cols);
+    for i in 0..n_rows {
+        scaled.set(i, 0, alphas.get(i, 0)? * ratio)?;
+    }
+    Ok(scaled)
 }
 
 impl<T: SurfaceTrait> ThermalSurfaceData<T> {
@@ -394,13 +1836,111 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
         let n_nodes = fin - ini;
         let q = Matrix::new(0.0, n_nodes, 1);
         let temperatures = Matrix::new(0.0, n_nodes, 1);
+        let node_heat_sources = Matrix::new(0.0, n_nodes, 1);
+        let state_space_cache = (0..self.massive_chunks.len()).map(|_| None).collect();
 
         SurfaceMemory{
             massive_chunks,
             nomass_chunks,
             temperatures,
             q,
+            node_heat_sources,
+            state_space_cache,
+        }
+    }
+
+    /// Writes this surface's restartable marching state—node temperatures,
+    /// cached massive-chunk propagators, and the last-committed front/back
+    /// convection coefficients—to `writer`, so a long annual run can be
+    /// paused and later resumed with [`Self::read_restart`] instead of
+    /// starting over.
+    ///
+    /// This delegates the node-temperature/propagator part to
+    /// [`Discretization::write_state`]. The front/back convection
+    /// coefficients are read from `state` (where [`Self::commit_march`]
+    /// leaves them via [`SurfaceTrait::set_front_convection_coefficient`]/
+    /// [`SurfaceTrait::set_back_convection_coefficient`]) rather than from
+    /// `memory`, since `memory` holds no boundary-condition history of its
+    /// own; there is no separate "last-known border conditions" to persist
+    /// beyond these two coefficients plus the node temperatures already
+    /// covered above, as `t_front`/`t_back`/`front_mrt`/`back_mrt` are
+    /// supplied fresh by the caller on every [`Self::march`] call and are
+    /// not cached state.
+    pub fn write_restart<W: std::io::Write>(
+        &self,
+        state: &SimulationState,
+        memory: &SurfaceMemory,
+        mut writer: W,
+    ) -> Result<(), String> {
+        self.discretization.write_state(
+            &memory.temperatures,
+            &memory.massive_chunks,
+            &mut writer,
+        )?;
+        let front_hs = self.parent.front_convection_coefficient(state).unwrap_or(Float::NAN);
+        let back_hs = self.parent.back_convection_coefficient(state).unwrap_or(Float::NAN);
+        serde_json::to_writer(writer, &[front_hs, back_hs]).map_err(|e| e.to_string())
+    }
+
+    /// Reads back a restart file written by [`Self::write_restart`],
+    /// restoring `memory`'s node temperatures and massive-chunk propagators
+    /// and `state`'s front/back convection coefficients in place.
+    ///
+    /// Fails—without modifying `state` or `memory`—if the stored layout
+    /// (schema version, segment count, node count, or massive-chunk count)
+    /// doesn't match this surface's own [`Discretization`], e.g. because the
+    /// file was written by a differently-discretized construction. See
+    /// [`Discretization::read_state`].
+    pub fn read_restart<R: std::io::Read>(
+        &self,
+        state: &mut SimulationState,
+        memory: &mut SurfaceMemory,
+        mut reader: R,
+    ) -> Result<(), String> {
+        self.discretization.read_state(
+            &mut reader,
+            &mut memory.temperatures,
+            &mut memory.massive_chunks,
+        )?;
+        self.parent.set_node_temperatures(state, &memory.temperatures);
+        let hs: [Float; 2] =
+            serde_json::from_reader(reader).map_err(|e| e.to_string())?;
+        if hs[0].is_finite() {
+            self.parent.set_front_convection_coefficient(state, hs[0])?;
         }
+        if hs[1].is_finite() {
+            self.parent.set_back_convection_coefficient(state, hs[1])?;
+        }
+        Ok(())
+    }
+
+    /// Nudges this surface's simulated node temperatures toward sparse field
+    /// measurements (e.g. embedded wall thermocouples), via
+    /// [`crate::assimilation::assimilate`]. Reads the background state from
+    /// `state` (the current [`SurfaceTrait::get_node_temperatures`]),
+    /// computes the PBDW correction against `readings`, and writes the
+    /// corrected temperatures back into `state`—so a subsequent
+    /// [`Self::march`] continues from the assimilated state rather than the
+    /// pure forward-simulated one. Returns each sensor's background value
+    /// and residual, for diagnostics/logging.
+    pub fn assimilate(
+        &self,
+        state: &mut SimulationState,
+        readings: &[crate::assimilation::SensorReading],
+    ) -> Result<Vec<crate::assimilation::AssimilatedSensor>, String> {
+        let temperatures = self.parent.get_node_temperatures(state);
+        let (n_nodes, ..) = temperatures.size();
+        let u_bk: Vec<Float> = (0..n_nodes).map(|i| temperatures.get(i, 0).unwrap()).collect();
+
+        let (corrected, sensors) = crate::assimilation::assimilate(&u_bk, readings)?;
+
+        let mut corrected_matrix = Matrix::new(0.0, n_nodes, 1);
+        for (i, v) in corrected.iter().enumerate() {
+            corrected_matrix.set(i, 0, *v)?;
+        }
+        self.parent.set_node_temperatures(state, &corrected_matrix);
+
+        Ok(sensors)
     }
 
     /// Creates a new [`ThermalSurfaceData`]
@@ -540,6 +2080,9 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
             perimeter,
             normal,
             cos_tilt,
+            convection_algorithm: ConvectionAlgorithm::default(),
+            ground_depth: 0.0,
+            v_gust: 0.3,
             discretization,
             front_boundary: None,
             back_boundary: None,
@@ -552,13 +2095,89 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
             back_alphas,
             massive_chunks,
             nomass_chunks,
+            node_heat_source_indices: vec![None; n_nodes],
+            tabs: None,
             #[cfg(debug_assertions)]
             front_hs: None,
             #[cfg(debug_assertions)]
             back_hs: None,
+            front_coating: None,
+            back_coating: None,
         })
     }
 
+    /// Re-evaluates any attached [`ThermochromicCoating`]s against the
+    /// surface's current front/back node temperatures, rescaling
+    /// `front_alphas`/`back_alphas` and `front_emissivity`/`back_emissivity`
+    /// to match. Has no effect on a side with no coating attached. Call
+    /// this once per timestep—before applying that step's incident
+    /// solar/IR irradiance—so a surface's optical properties can respond
+    /// to its own temperature (thermochromic behaviour) or, by swapping
+    /// out `front_coating`/`back_coating` between calls, to a time-varying
+    /// control signal (e.g. a switchable coating driven by a `Schedule`).
+    pub fn update_coatings(&mut self, state: &SimulationState) -> Result<(), String> {
+        if self.front_coating.is_some() {
+            let t = self.parent.front_temperature(state);
+            let (absorptance, emissivity) = self.front_coating.as_mut().unwrap().properties_at(t);
+            self.front_alphas = rescale_alphas(&self.front_alphas, absorptance)?;
+            self.front_emissivity = emissivity;
+        }
+        if self.back_coating.is_some() {
+            let t = self.parent.back_temperature(state);
+            let (absorptance, emissivity) = self.back_coating.as_mut().unwrap().properties_at(t);
+            self.back_alphas = rescale_alphas(&self.back_alphas, absorptance)?;
+            self.back_emissivity = emissivity;
+        }
+        Ok(())
+    }
+
+    /// Assembles the per-node solar source term `q_solar` from this
+    /// surface's `front_alphas`/`back_alphas` (already split per node by
+    /// [`Self::new`]: a transparent layer's absorptance spread evenly over
+    /// all of its nodes, an opaque layer's put wholly on its outermost
+    /// node) and the incident
+    /// irradiance on each side: `q_solar[node] = front_alphas[node] *
+    /// front_irradiance + back_alphas[node] * back_irradiance`. This is the
+    /// same vector [`Self::march_readonly`] adds into `q` every timestep, so
+    /// a caller that just wants "the solar source term for these optical
+    /// properties" doesn't have to re-derive that per-node split by hand.
+    pub fn solar_source_term(&self, front_irradiance: Float, back_irradiance: Float) -> Matrix {
+        let mut q_solar = &self.front_alphas * front_irradiance;
+        q_solar += &(&self.back_alphas * back_irradiance);
+        q_solar
+    }
+
+    /// Sets the convection correlation used for this surface's exterior and
+    /// interior coefficients, overriding the default TARP model.
+    pub fn set_convection_algorithm(&mut self, algorithm: ConvectionAlgorithm) {
+        self.convection_algorithm = algorithm;
+    }
+
+    /// Sets the burial depth used to evaluate the ground temperature for
+    /// a [`Boundary::Ground`] side of this surface, overriding the
+    /// default slab-on-grade depth of `0.0`.
+    pub fn set_ground_depth(&mut self, depth: Float) {
+        self.ground_depth = depth;
+    }
+
+    /// Sets the gustiness wind speed (m/s) combined in quadrature with
+    /// the modified weather-file wind speed when evaluating exterior
+    /// convection, overriding the default of `0.3`.
+    pub fn set_v_gust(&mut self, v_gust: Float) {
+        self.v_gust = v_gust;
+    }
+
+    /// Couples a node to a circulating fluid loop (radiant floor, TABS),
+    /// overriding any previous coupling. See [`TabsCoupling`].
+    pub fn set_tabs_coupling(&mut self, coupling: TabsCoupling) {
+        self.tabs = Some(coupling);
+    }
+
+    /// Removes a coupling set with [`Self::set_tabs_coupling`], if any.
+    pub fn clear_tabs_coupling(&mut self) {
+        self.tabs = None;
+    }
+
     /// Sets the front boundary
     pub fn set_front_boundary(&mut self, b: &Boundary, model: &SimpleModel) {
         self.front_boundary = Some(b.clone());
@@ -585,11 +2204,14 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn calc_border_conditions(
         &self,
         state: &SimulationState,
         t_front: Float,
         t_back: Float,
+        front_mrt: Float,
+        back_mrt: Float,
         wind_direction: Float,
         wind_speed: Float,
     ) -> (ConvectionParams, ConvectionParams, Float, Float) {
@@ -606,7 +2228,7 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
                     let front_env = ConvectionParams {
                         air_temperature: t_front,
                         air_speed: 0.0,
-                        rad_temperature: t_front,
+                        rad_temperature: front_mrt,
                         surface_temperature: self.parent.front_temperature(state),
                         roughness_index: 1,
                         cos_surface_tilt: self.cos_tilt,
@@ -614,7 +2236,7 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
 
                     (
                         front_env,
-                        front_env.get_tarp_natural_convection_coefficient(),
+                        front_env.get_interior_convection_coefficient(&self.convection_algorithm),
                     )
                 }
                 Boundary::AmbientTemperature { temperature } => {
@@ -629,15 +2251,33 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
 
                     (
                         front_env,
-                        front_env.get_tarp_natural_convection_coefficient(),
+                        front_env.get_interior_convection_coefficient(&self.convection_algorithm),
+                    )
+                }
+                Boundary::Ground => {
+                    // Below-grade: no wind, and the ground itself is
+                    // taken as both the air and radiant temperature this
+                    // side exchanges with, same as `AmbientTemperature`.
+                    let front_env = ConvectionParams {
+                        air_temperature: t_front,
+                        air_speed: 0.0,
+                        rad_temperature: t_front,
+                        surface_temperature: self.parent.front_temperature(state),
+                        roughness_index: 1,
+                        cos_surface_tilt: self.cos_tilt,
+                    };
+
+                    (
+                        front_env,
+                        front_env.get_interior_convection_coefficient(&self.convection_algorithm),
                     )
                 }
-                Boundary::Ground => unreachable!(),
             }
         } else {
             let mut front_env = ConvectionParams {
                 air_temperature: t_front,
-                air_speed: wind_speed * self.wind_speed_modifier,
+                air_speed: ((wind_speed * self.wind_speed_modifier).powi(2) + self.v_gust.powi(2))
+                    .sqrt(),
                 rad_temperature: (ir_front / crate::SIGMA).powf(0.25) - 273.15,
                 surface_temperature: self.parent.front_temperature(state),
                 roughness_index: 1,
@@ -646,7 +2286,12 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
             front_env.cos_surface_tilt = -self.cos_tilt;
             (
                 front_env,
-                front_env.get_tarp_convection_coefficient(self.area, self.perimeter, windward),
+                front_env.get_exterior_convection_coefficient(
+                    &self.convection_algorithm,
+                    self.area,
+                    self.perimeter,
+                    windward,
+                ),
             )
         };
 
@@ -656,12 +2301,12 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
                     let back_env = ConvectionParams {
                         air_temperature: t_back,
                         air_speed: 0.0,
-                        rad_temperature: t_back, //self.parent.back_temperature(state),//(ir_back/crate::SIGMA).powf(0.25) - 273.15,
+                        rad_temperature: back_mrt,
                         surface_temperature: self.parent.back_temperature(state),
                         roughness_index: 1,
                         cos_surface_tilt: self.cos_tilt,
                     };
-                    (back_env, back_env.get_tarp_natural_convection_coefficient())
+                    (back_env, back_env.get_interior_convection_coefficient(&self.convection_algorithm))
                 }
                 Boundary::AmbientTemperature { temperature } => {
                     let front_env = ConvectionParams {
@@ -675,15 +2320,33 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
 
                     (
                         front_env,
-                        front_env.get_tarp_natural_convection_coefficient(),
+                        front_env.get_interior_convection_coefficient(&self.convection_algorithm),
+                    )
+                }
+                Boundary::Ground => {
+                    // Below-grade: no wind, and the ground itself is
+                    // taken as both the air and radiant temperature this
+                    // side exchanges with, same as `AmbientTemperature`.
+                    let back_env = ConvectionParams {
+                        air_temperature: t_back,
+                        air_speed: 0.0,
+                        rad_temperature: t_back,
+                        surface_temperature: self.parent.back_temperature(state),
+                        roughness_index: 1,
+                        cos_surface_tilt: self.cos_tilt,
+                    };
+
+                    (
+                        back_env,
+                        back_env.get_interior_convection_coefficient(&self.convection_algorithm),
                     )
                 }
-                Boundary::Ground => unreachable!(),
             }
         } else {
             let back_env = ConvectionParams {
                 air_temperature: t_back,
-                air_speed: wind_speed * self.wind_speed_modifier,
+                air_speed: ((wind_speed * self.wind_speed_modifier).powi(2) + self.v_gust.powi(2))
+                    .sqrt(),
                 rad_temperature: (ir_back / crate::SIGMA).powf(0.25) - 273.15,
                 surface_temperature: self.parent.back_temperature(state),
                 roughness_index: 1,
@@ -691,7 +2354,12 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
             };
             (
                 back_env,
-                back_env.get_tarp_convection_coefficient(self.area, self.perimeter, windward),
+                back_env.get_exterior_convection_coefficient(
+                    &self.convection_algorithm,
+                    self.area,
+                    self.perimeter,
+                    windward,
+                ),
             )
         };
 
@@ -710,206 +2378,250 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
         (front_env, back_env, front_hs, back_hs)
     }
 
-    fn march_mass(&self,
-        global_temperatures: &mut Matrix, 
-        solar_radiation: &Matrix, 
-        dt: Float,
-        t_front: Float,
-        t_back: Float,
-        front_rad_hs: Float, 
-        back_rad_hs: Float, 
-        wind_direction: Float,
-        wind_speed: Float,
-        ini: usize, 
-        fin: usize, 
-        memory: &mut ChunkMemory,
-        state: &SimulationState,
-    )->Result<(),String>{
-
-        
-
-        
-        let (front_env, back_env, front_hs, back_hs) =
-            self.calc_border_conditions(state, t_front, t_back, wind_direction, wind_speed);
-
-        self.discretization.get_k_q(
-            ini,
-            fin,
-            &global_temperatures,
-            &front_env,
-            front_hs,
-            front_rad_hs,
-            &back_env,
-            back_hs,
-            back_rad_hs,
-            memory,
-        )?;
-
-        let c = self
-            .discretization
-            .segments
-            .iter()
-            .skip(ini)
-            .take(fin - ini)
-            .map(|(mass, _)| *mass)
-            .collect();
-        let c = Matrix::diag(c);
-        
-        
-        // ... here we add solar gains
-        for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
-            let v = solar_radiation.get(global_i, 0).unwrap();
-            memory.q.add_to_element(local_i, 0, v).unwrap();
-        }
-        
-        rearrange_k(dt, &c, memory)?;
-
-        // Use RT4 for updating temperatures of massive nodes.
-        let mut local_temps = Matrix::new(0.0, fin - ini, 1);
-        for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
-            let v = global_temperatures.get(global_i, 0).unwrap();
-            local_temps.set(local_i, 0, v).unwrap();
+    /// Sets the heat injected at `node_index` (W) for every subsequent
+    /// [`Self::march`], until changed or cleared again. This is how embedded
+    /// radiant layers (a hydronic loop or an electric mat cast into a
+    /// construction) are modelled: the source is a property of the node, not
+    /// a one-off value recomputed each step, so—unlike the `node_source`
+    /// argument to [`Self::march`]—it is stored in `memory` and survives
+    /// across steps without the caller re-supplying it.
+    ///
+    /// Node indices are local to this surface (`0` is the outermost/front
+    /// node). Ideally this would be exposed as a proper state element
+    /// alongside [`SurfaceTrait::add_node_temperature_states`]'s per-node
+    /// temperatures, but `simple_model::SimulationStateElement` is a closed
+    /// enum defined upstream with no such variant, so the value lives here
+    /// in `SurfaceMemory` instead, following the same memory-threading
+    /// pattern used for chunk state elsewhere in this module.
+    pub fn set_node_heat_source(
+        &self,
+        memory: &mut SurfaceMemory,
+        node_index: usize,
+        watts: Float,
+    ) -> Result<(), String> {
+        let (n_nodes, ..) = memory.node_heat_sources.size();
+        if node_index >= n_nodes {
+            return Err(format!(
+                "Tried to set a node heat source at node {node_index}, but this Surface only has {n_nodes} nodes"
+            ));
         }
+        memory.node_heat_sources.set(node_index, 0, watts)
+    }
 
-        
-        rk4( &c, memory, &mut local_temps)?;
+    /// Gets the heat (W) currently being injected at `node_index`, as set by
+    /// [`Self::set_node_heat_source`]. Returns `0.0` for a node with no
+    /// source set.
+    pub fn get_node_heat_source(
+        &self,
+        memory: &SurfaceMemory,
+        node_index: usize,
+    ) -> Result<Float, String> {
+        memory.node_heat_sources.get(node_index, 0)
+    }
 
-        for (local_i, global_i) in (ini..fin).into_iter().enumerate() {
-            let v = local_temps.get(local_i, 0).unwrap();
-            global_temperatures.set(global_i, 0, v).unwrap();
+    /// Registers `node_index`'s external heat source (W) as tracking
+    /// `state[state_index]`, so [`Self::march`]/[`Self::march_readonly`]
+    /// re-read it fresh every timestep instead of holding the fixed value
+    /// [`Self::set_node_heat_source`] would. This is how a scheduled embedded
+    /// heater (a heating cable on a timer, a controlled radiant loop) is
+    /// modelled: point the node at whichever existing state index the
+    /// schedule/controller already drives, the same raw-index access
+    /// [`crate::surface_trait::SurfaceTrait::front_temperature`] uses—there
+    /// being no dedicated `SimulationStateElement` variant for this, per
+    /// [`Self::set_node_heat_source`]'s doc comment.
+    ///
+    /// Node indices are local to this surface (`0` is the outermost/front
+    /// node).
+    pub fn set_node_heat_source_index(
+        &mut self,
+        node_index: usize,
+        state_index: usize,
+    ) -> Result<(), String> {
+        let n_nodes = self.node_heat_source_indices.len();
+        if node_index >= n_nodes {
+            return Err(format!(
+                "Tried to register a node heat source index at node {node_index}, but this Surface only has {n_nodes} nodes"
+            ));
         }
+        self.node_heat_source_indices[node_index] = Some(state_index);
         Ok(())
     }
 
-    fn march_nomass(&self, 
-        global_temperatures: &mut Matrix, 
-        solar_radiation: &Matrix, 
-        t_front: Float,
-        t_back: Float,
-        front_rad_hs: Float, 
-        back_rad_hs: Float, 
-        wind_direction: Float,
-        wind_speed: Float,
-        ini: usize, 
-        fin: usize, 
-        memory: &mut ChunkMemory,
-        state: &SimulationState,
-    )->Result<(),String>{
-        let mut old_err = 99999.;
-        let mut count = 0;
-        
-       
-
-        loop {
-
-            // Update convection coefficients
-            let (front_env, back_env, front_hs, back_hs) =
-                self.calc_border_conditions(state, t_front, t_back, wind_direction, wind_speed);
-
-            
-            // Calculate q based on heat transfer (convection, IR radiation)
-            self.discretization.get_k_q(
-                ini,
-                fin,
-                &global_temperatures,
-                &front_env,
-                front_hs,
-                front_rad_hs,
-                &back_env,
-                back_hs,
-                back_rad_hs,
-                memory,
-            )?;
-            
-            // add solar gains
-            for (local_i, i) in (ini..fin).into_iter().enumerate() {
-                let v = solar_radiation.get(i, 0)?;
-                memory.q.add_to_element(local_i, 0, v)?;
-            }
-            memory.q *= -1.;
+    /// Clears a node heat source index previously set with
+    /// [`Self::set_node_heat_source_index`], so that node goes back to being
+    /// driven solely by [`Self::set_node_heat_source`] (or nothing).
+    pub fn clear_node_heat_source_index(&mut self, node_index: usize) {
+        if let Some(slot) = self.node_heat_source_indices.get_mut(node_index) {
+            *slot = None;
+        }
+    }
 
-            let temps = memory.k.clone().mut_n_diag_gaussian(memory.q.clone(), 3)?; // and just like that, q is the new temperatures
+    /// Marches this surface's massive chunks using a cached backward-Euler
+    /// state-space reduction (see [`crate::discretization::ChunkStateSpace`])
+    /// instead of [`Self::march`]'s per-step assemble-and-solve, rebuilding
+    /// each chunk's cache only if it is missing or was built for a
+    /// different `dt`.
+    ///
+    /// `boundary_inputs[i]` is the external flux vector `u` (in `W`, one
+    /// entry per node of chunk `i`) driving that chunk this step: the
+    /// front/back boundary convective+radiative flux at the chunk's first
+    /// and last node, plus any [`Self::set_node_heat_source`] values for
+    /// interior nodes. Unlike [`Self::march`], this does not compute those
+    /// fluxes itself—the whole point of the reduction is to skip
+    /// recomputing anything that `A`/`B` already captured, so the caller
+    /// supplies them explicitly.
+    ///
+    /// This is an optional, faster alternative to [`Self::march`] for
+    /// surfaces marched a very large number of times with an unchanging
+    /// `dt` (e.g. annual simulations); it trades the per-step refresh of
+    /// temperature-dependent conductivities for two matrix-vector products
+    /// per massive chunk. No-mass chunks have no capacitance to reduce and
+    /// are unaffected—continue marching them with [`Self::march`].
+    pub fn march_nodes_reduced(
+        &self,
+        memory: &mut SurfaceMemory,
+        dt: Float,
+        reference_temperature: Float,
+        boundary_inputs: &[Matrix],
+    ) -> Result<(), String> {
+        if boundary_inputs.len() != self.massive_chunks.len() {
+            return Err(format!(
+                "march_nodes_reduced expected {} boundary input vectors (one per massive chunk), got {}",
+                self.massive_chunks.len(),
+                boundary_inputs.len()
+            ));
+        }
 
-            let mut err = 0.0;
-            for (local_i, i) in (ini..fin).into_iter().enumerate() {
-                let local_temp = temps.get(local_i, 0).unwrap();
-                let global_temp = global_temperatures.get(i, 0)?;
-                err += (local_temp - global_temp).abs();
-            }
-            if err > old_err {
-                #[cfg(debug_assertions)]
-                if count > 100 {
-                    eprintln!("Breaking after {} iterations... because BAD!", count);
-                }
-                break;
+        for (chunk_index, (ini, fin)) in self.massive_chunks.iter().enumerate() {
+            let needs_rebuild = match &memory.state_space_cache[chunk_index] {
+                Some(cache) => cache.dt != dt,
+                None => true,
+            };
+            if needs_rebuild {
+                memory.state_space_cache[chunk_index] = Some(self.discretization.build_chunk_state_space(
+                    *ini,
+                    *fin,
+                    dt,
+                    reference_temperature,
+                )?);
             }
 
-            assert!(
-                !err.is_nan(),
-                // "Error is NaN... \nfront_env = {:?}| back_env = {:?} \nfront_hc = {} | back_hs = {}. \nError = {}\ntemps={}\nq={}\nsolar_front={}, solar_back={}\nfront_alphas={}\nback_alphas={}\n",
-                // front_env,
-                // back_env,
-                // front_hs,
-                // back_hs,
-                // err / ((fin - ini) as Float),
-                // temps,                    
-                // q,
-                // solar_front,
-                // solar_back,
-                // self.front_alphas,
-                // self.back_alphas,
-            );
-
-            // if count > 10000 {
-            //     eprintln!("Err is {}", err / ((fin - ini) as Float))
-            // }
-            assert!(
-                count < 99199000,
-                "Excessive number of iterations... \n====\t\tfront_env = {:?}\n\tback_env = {:?}\n\tfront_hc = {}\n\tback_hs = {}.\n\tError = {}\n====\n",
-                front_env,
-                back_env,
-                front_hs,
-                back_hs,
-                err / ((fin - ini) as Float),
-            );
-            for (local_i, i) in (ini..fin).into_iter().enumerate() {
-                let local_temp = temps.get(local_i, 0).unwrap();
-                // temperatures.set(i, 0, local_temp).unwrap();
-                global_temperatures.add_to_element(i, 0, local_temp)?;
-                global_temperatures.scale_element(i, 0, 0.5)?;
+            let n = fin - ini;
+            let mut x = Matrix::new(0.0, n, 1);
+            for local_i in 0..n {
+                x.set(local_i, 0, memory.temperatures.get(ini + local_i, 0)?)?;
             }
 
-            let max_allowed_error = if count < 100 { 0.01 } else /*if count < 1000*/ { 0.5 }; // else { 1. };
+            let cache = memory.state_space_cache[chunk_index].as_ref().unwrap();
+            let next = cache.march(&x, &boundary_inputs[chunk_index])?;
 
-            if err / ((fin - ini) as Float) < max_allowed_error {
-                #[cfg(debug_assertions)]
-                if count > 100 {
-                    dbg!("Breaking after {} iterations... because GOOD!", count);
-                }
-                break;
+            for local_i in 0..n {
+                memory.temperatures.set(ini + local_i, 0, next.get(local_i, 0)?)?;
             }
-            old_err = err;
-            count += 1;
         }
         Ok(())
     }
-    
-    /// Marches one timestep. Returns front and back heat flow    
+
+    /// Computes this surface's directional incident IR irradiance from a
+    /// [`crate::sky::SkyModel`]—splitting it into sky/ground/air components
+    /// by this surface's tilt—and writes it into `state` through
+    /// [`SurfaceTrait::set_front_infrared_irradiance`]/
+    /// [`SurfaceTrait::set_back_infrared_irradiance`], ready to be picked up
+    /// by the next [`Self::march`]'s border-condition calculation exactly
+    /// like any other externally-supplied IR irradiance.
+    ///
+    /// `front_sky` and `back_sky` are independent because the two sides of
+    /// a surface can face very different things (e.g. a roof's front faces
+    /// the sky while its back faces a conditioned attic). Pass
+    /// [`crate::sky::SkyModel::uniform`] for a side that should keep the
+    /// previous single-value behaviour.
+    pub fn set_sky_ir_irradiance(
+        &self,
+        state: &mut SimulationState,
+        front_sky: &crate::sky::SkyModel,
+        back_sky: &crate::sky::SkyModel,
+    ) -> Result<(), String> {
+        self.parent
+            .set_front_infrared_irradiance(state, front_sky.irradiance(-self.cos_tilt))?;
+        self.parent
+            .set_back_infrared_irradiance(state, back_sky.irradiance(self.cos_tilt))?;
+        Ok(())
+    }
+
+    /// Marches one timestep. Returns front and back heat flow
+    ///
+    /// `front_mrt`/`back_mrt` are the mean radiant temperature "seen" by each
+    /// side: for a [`Boundary::Space`] side this should be the owning Zone's
+    /// mean radiant temperature (see [`crate::model::ThermalModel`]'s MRT
+    /// computation), so that long-wave exchange with the rest of the Zone's
+    /// surfaces uses the radiant star node rather than the air temperature;
+    /// for any other boundary, pass the same value as the corresponding
+    /// `t_front`/`t_back` to keep the previous behaviour.
+    ///
+    /// `node_source`, if given, is a per-node vector (W, same length and node
+    /// ordering as `memory.temperatures`) of externally-injected heat—e.g., built
+    /// with [`crate::discretization::Discretization::distribute_layer_source`]
+    /// for embedded heating or in-layer solar absorption. It is added into `q`
+    /// exactly like the surface's own solar absorption, alongside whatever was
+    /// set with [`Self::set_node_heat_source`].
+    #[allow(clippy::too_many_arguments)]
     pub fn march(
         &self,
         state: &mut SimulationState,
         t_front: Float,
         t_back: Float,
+        front_mrt: Float,
+        back_mrt: Float,
+        wind_direction: Float,
+        wind_speed: Float,
+        dt: Float,
+        memory: &mut SurfaceMemory,
+        node_source: Option<&Matrix>,
+    ) -> Result<(Float, Float), String> {
+        let (front_hs, back_hs) = self.march_readonly(
+            state,
+            t_front,
+            t_back,
+            front_mrt,
+            back_mrt,
+            wind_direction,
+            wind_speed,
+            dt,
+            memory,
+            node_source,
+        )?;
+        self.commit_march(state, memory, t_front, t_back, front_hs, back_hs)
+    }
+
+    /// The read-only core of [`Self::march`]: advances `memory` one timestep
+    /// without writing anything back into `state`, returning the front/back
+    /// convection coefficients [`Self::commit_march`] needs to finish the job.
+    ///
+    /// Since this only needs a shared `&SimulationState` and touches no state
+    /// other than its own `memory` (a private scratch per surface, allocated
+    /// by [`Self::allocate_memory`]), several surfaces can be marched
+    /// concurrently—e.g. with `rayon`'s `par_iter`, behind the `parallel`
+    /// feature—as long as each has its own `SurfaceMemory`, and the results
+    /// are applied with [`Self::commit_march`] afterwards.
+    ///
+    /// See [`Self::march`] for `front_mrt`/`back_mrt`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn march_readonly(
+        &self,
+        state: &SimulationState,
+        t_front: Float,
+        t_back: Float,
+        front_mrt: Float,
+        back_mrt: Float,
         wind_direction: Float,
         wind_speed: Float,
         dt: Float,
         memory: &mut SurfaceMemory,
+        node_source: Option<&Matrix>,
     ) -> Result<(Float, Float), String> {
         let tempsssss = self.parent.get_node_temperatures(state);
         memory.temperatures.copy_from(&tempsssss);
 
-        let (rows, ..) = memory.temperatures.size();
-
         // Calculate and set Front and Back Solar Irradiance
         let mut solar_front = self.parent.front_solar_irradiance(state);
         if solar_front.is_nan() || solar_front < 0.0 {
@@ -922,49 +2634,99 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
 
         /////////////////////
         // 1st: Calculate the solar absorption in each node
-        /////////////////////        
-        // memory.q *= 0.0; // clean, just in case
-        // self.front_alphas.scale_into(solar_front, &mut memory.q)?;
-        let mut solar_radiation = &self.front_alphas * solar_front;
-        solar_radiation += &(&self.back_alphas * solar_back);
-        // memory.q += &(&self.back_alphas * solar_back);
+        /////////////////////
+        let mut solar_radiation = self.solar_source_term(solar_front, solar_back);
+        if let Some(source) = node_source {
+            solar_radiation += source;
+        }
+        solar_radiation += &memory.node_heat_sources;
+        // State-driven per-node sources (scheduled embedded heating/etc.),
+        // registered with `set_node_heat_source_index`, are re-read every
+        // timestep rather than cached in `memory` like `node_heat_sources`.
+        for (local_i, state_index) in self.node_heat_source_indices.iter().enumerate() {
+            if let Some(state_index) = state_index {
+                let watts = state[*state_index];
+                solar_radiation.add_to_element(local_i, 0, watts)?;
+            }
+        }
+
+        // Resolve the TABS coupling's inlet fluid temperature from `state`
+        // once, here, since `march_mass_chunk`/`march_nomass_chunk` only see
+        // plain `Matrix` data (see their doc comments on why `self` isn't
+        // captured by their—potentially parallel—closures).
+        let tabs = self
+            .tabs
+            .as_ref()
+            .map(|t| (t.node_index, t.ua, state[t.fluid_temperature_index]));
 
         /////////////////////
         // 2nd: Calculate the temperature in all no-mass nodes.
         // Also, the heat flow into
         /////////////////////
 
-        let (front_env, back_env, _front_hs, _back_hs) =
-            self.calc_border_conditions(state, t_front, t_back, wind_direction, wind_speed);
-        let front_rad_hs = 4.
-            * self.front_emissivity
-            * crate::SIGMA
-            * (273.15 + (front_env.rad_temperature + front_env.surface_temperature) / 2.).powi(3);
-        let back_rad_hs = 4.
-            * self.back_emissivity
-            * crate::SIGMA
-            * (273.15 + (back_env.rad_temperature + back_env.surface_temperature) / 2.).powi(3);
-        
-        for (chunk_i,(ini, fin)) in self.nomass_chunks.iter().enumerate() {
-            self.march_nomass(
-                &mut memory.temperatures, 
-                &solar_radiation,// &memory.q,                
-                t_front,
-                t_back,
-                front_rad_hs,
-                back_rad_hs,
-                wind_direction,
-                wind_speed,
-                *ini, *fin, 
-                &mut memory.nomass_chunks[chunk_i],
-                state,
-            )?;
+        let (front_env, back_env, front_hs, back_hs) = self.calc_border_conditions(
+            state,
+            t_front,
+            t_back,
+            front_mrt,
+            back_mrt,
+            wind_direction,
+            wind_speed,
+        );
+        {
+            // A read-only snapshot, so it can be shared across chunks (and,
+            // under the `parallel` feature, across threads) while
+            // `memory.nomass_chunks` is borrowed mutably below. Likewise,
+            // `discretization` is bound to a plain `&Discretization`—rather
+            // than reached via `self.discretization` inside the closure—so
+            // the parallel closure never captures `self` (whose `Rc<T>`
+            // parent field is `!Sync`).
+            let discretization = &self.discretization;
+            let snapshot = memory.temperatures.clone();
+
+            #[cfg(feature = "parallel")]
+            let chunks_iter = self.nomass_chunks.par_iter().zip(memory.nomass_chunks.par_iter_mut());
+            #[cfg(not(feature = "parallel"))]
+            let chunks_iter = self.nomass_chunks.iter().zip(memory.nomass_chunks.iter_mut());
+
+            let solved: Result<Vec<Matrix>, String> = chunks_iter
+                .map(|((ini, fin), chunk_memory)| {
+                    march_nomass_chunk(
+                        discretization,
+                        &snapshot,
+                        &solar_radiation,
+                        &front_env,
+                        front_hs,
+                        self.front_emissivity,
+                        &back_env,
+                        back_hs,
+                        self.back_emissivity,
+                        *ini,
+                        *fin,
+                        chunk_memory,
+                        tabs,
+                    )
+                })
+                .collect();
+            for ((ini, fin), local_temps) in self.nomass_chunks.iter().zip(solved?.into_iter()) {
+                for (local_i, global_i) in (*ini..*fin).into_iter().enumerate() {
+                    let v = local_temps.get(local_i, 0).unwrap();
+                    memory.temperatures.set(global_i, 0, v).unwrap();
+                }
+            }
         }
 
         // Calculate final conditions.
 
-        let (front_env, back_env, _front_hs, _back_hs) =
-            self.calc_border_conditions(state, t_front, t_back, wind_direction, wind_speed);
+        let (front_env, back_env, front_hs, back_hs) = self.calc_border_conditions(
+            state,
+            t_front,
+            t_back,
+            front_mrt,
+            back_mrt,
+            wind_direction,
+            wind_speed,
+        );
         let front_rad_hs = 4.
             * self.front_emissivity
             * crate::SIGMA
@@ -976,35 +2738,83 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
 
         /////////////////////
         // 3rd: Calculate K and C matrices for the massive walls, and march
-        /////////////////////
-        
-        for (chunk_i,(ini, fin)) in self.massive_chunks.iter().enumerate() {            
-            self.march_mass(
-                &mut memory.temperatures,
-                &solar_radiation,// &memory.q,                
-                dt,
-                t_front,
-                t_back,
-                front_rad_hs,
-                back_rad_hs,
-                wind_direction,
-                wind_speed,
-                *ini, *fin, 
-                &mut memory.massive_chunks[chunk_i],
-                state,
-            )?;
+        /////////////////////
+
+        {
+            let discretization = &self.discretization;
+            let snapshot = memory.temperatures.clone();
+
+            #[cfg(feature = "parallel")]
+            let chunks_iter = self
+                .massive_chunks
+                .par_iter()
+                .zip(memory.massive_chunks.par_iter_mut());
+            #[cfg(not(feature = "parallel"))]
+            let chunks_iter = self.massive_chunks.iter().zip(memory.massive_chunks.iter_mut());
+
+            let solved: Result<Vec<Matrix>, String> = chunks_iter
+                .map(|((ini, fin), chunk_memory)| {
+                    march_mass_chunk(
+                        discretization,
+                        &snapshot,
+                        &solar_radiation,
+                        dt,
+                        &front_env,
+                        front_hs,
+                        front_rad_hs,
+                        &back_env,
+                        back_hs,
+                        back_rad_hs,
+                        *ini,
+                        *fin,
+                        chunk_memory,
+                        tabs,
+                    )
+                })
+                .collect();
+            for ((ini, fin), local_temps) in self.massive_chunks.iter().zip(solved?.into_iter()) {
+                for (local_i, global_i) in (*ini..*fin).into_iter().enumerate() {
+                    let v = local_temps.get(local_i, 0).unwrap();
+                    memory.temperatures.set(global_i, 0, v).unwrap();
+                }
+            }
         }
 
         /////////////////////
-        // 4th: Set temperatures, calc heat-flows and return
+        // 4th: Figure out the front/back convection coefficients for this
+        // new state. The actual state writes happen in `commit_march`.
         /////////////////////
+        let (_front_env, _back_env, front_hs, back_hs) = self.calc_border_conditions(
+            state,
+            t_front,
+            t_back,
+            front_mrt,
+            back_mrt,
+            wind_direction,
+            wind_speed,
+        );
+
+        Ok((front_hs, back_hs))
+    }
+
+    /// Writes the results of [`Self::march_readonly`] into `state`: the new
+    /// node temperatures, the front/back convection coefficients, and the
+    /// resulting front/back convective heat flows (which it returns, exactly
+    /// like [`Self::march`]).
+    pub fn commit_march(
+        &self,
+        state: &mut SimulationState,
+        memory: &SurfaceMemory,
+        t_front: Float,
+        t_back: Float,
+        front_hs: Float,
+        back_hs: Float,
+    ) -> Result<(Float, Float), String> {
         self.parent.set_node_temperatures(state, &memory.temperatures);
 
-        // Calc heat flow
+        let (rows, ..) = memory.temperatures.size();
         let ts_front = memory.temperatures.get(0, 0).unwrap();
         let ts_back = memory.temperatures.get(rows - 1, 0).unwrap();
-        let (_front_env, _back_env, front_hs, back_hs) =
-            self.calc_border_conditions(state, t_front, t_back, wind_direction, wind_speed);
         self.parent
             .set_front_convection_coefficient(state, front_hs)?;
         self.parent
@@ -1013,6 +2823,22 @@ impl<T: SurfaceTrait> ThermalSurfaceData<T> {
         let flow_front = (ts_front - t_front) * front_hs;
         let flow_back = (ts_back - t_back) * back_hs;
 
+        if let Some(tabs) = &self.tabs {
+            let t_node = memory.temperatures.get(tabs.node_index, 0)?;
+            let t_fluid = state[tabs.fluid_temperature_index];
+            let m_dot = state[tabs.mass_flow_index];
+            // Energy balance: what the loop gives up is what the node gained.
+            let q_into_node = tabs.ua * (t_fluid - t_node);
+            if let Some(outlet_index) = tabs.outlet_temperature_index {
+                let t_out = if m_dot * tabs.fluid_cp > 1e-9 {
+                    t_fluid - q_into_node / (m_dot * tabs.fluid_cp)
+                } else {
+                    t_fluid
+                };
+                state[outlet_index] = t_out;
+            }
+        }
+
         Ok((flow_front, flow_back))
     }
 }
@@ -1153,10 +2979,13 @@ mod testing {
                     &mut state,
                     t_environment,
                     t_environment,
+                    t_environment,
+                    t_environment,
                     0.0,
                     0.0,
                     dt,
                     &mut memory,
+                    None,
                 )
                 .unwrap();
 
@@ -1206,7 +3035,18 @@ mod testing {
         let mut final_qback: Float = 123123123.;
         while change.abs() > 1E-10 {
             let (q_front, q_back) = ts
-                .march(&mut state, 10.0, 30.0, 0.0, 0.0, dt, &mut memory)
+                .march(
+                    &mut state,
+                    10.0,
+                    30.0,
+                    10.0,
+                    30.0,
+                    0.0,
+                    0.0,
+                    dt,
+                    &mut memory,
+                    None,
+                )
                 .unwrap();
 
             ts.parent
@@ -1233,6 +3073,131 @@ mod testing {
         assert!(final_qback < 0.0);
     }
 
+    #[test]
+    fn test_node_heat_source_splits_evenly_in_symmetric_steady_state() {
+        let mut model = SimpleModel::default();
+
+        /* SUBSTANCES */
+        let brickwork = add_brickwork(&mut model);
+
+        /* MATERIALS */
+        let m1 = add_material(&mut model, brickwork, 20. / 1000.);
+
+        /* CONSTRUCTION */
+        let mut c = Construction::new("construction".to_string());
+        c.materials.push(m1.name().clone());
+        let c = model.add_construction(c);
+
+        /* GEOMETRY */
+        let mut the_loop = Loop3D::new();
+        let l = 1. as Float;
+        the_loop.push(Point3D::new(-l, -l, 0.)).unwrap();
+        the_loop.push(Point3D::new(l, -l, 0.)).unwrap();
+        the_loop.push(Point3D::new(l, l, 0.)).unwrap();
+        the_loop.push(Point3D::new(-l, l, 0.)).unwrap();
+        the_loop.close().unwrap();
+        let p = Polygon3D::new(the_loop).unwrap();
+
+        /* SURFACE */
+        let s = Surface::new("Surface 1".to_string(), p, c.name().clone());
+        let surface = model.add_surface(s);
+
+        let main_dt = 300.0;
+        let max_dx = m1.thickness / 2.0;
+        let min_dt = 1.0;
+        let d = Discretization::new(&c, &model, main_dt, max_dx, min_dt, 1., 0.).unwrap();
+        let dt = main_dt / d.tstep_subdivision as Float;
+        let normal = geometry3d::Vector3D::new(0., 0., 1.);
+        let perimeter = 8. * l;
+        let mut state_header = SimulationStateHeader::new();
+        let mut ts = ThermalSurface::new(
+            &mut state_header,
+            &model,
+            &None,
+            0,
+            &surface,
+            surface.area(),
+            perimeter,
+            10.,
+            normal,
+            &c,
+            d,
+        )
+        .unwrap();
+
+        let mut memory = ts.allocate_memory();
+        ts.front_hs = Some(10.);
+        ts.back_hs = Some(10.);
+
+        let mut state = state_header.take_values().unwrap();
+
+        // Same environment on both sides, so the only asymmetry is the
+        // interior source.
+        let t_environment = 10.;
+        let v = crate::SIGMA * (t_environment + 273.15 as Float).powi(4);
+        ts.parent.set_front_ir_irradiance(&mut state, v).unwrap();
+        ts.parent.set_back_ir_irradiance(&mut state, v).unwrap();
+
+        // Inject a constant source at the middle node, like an embedded
+        // radiant loop cast into the centre of the construction.
+        let (n_nodes, ..) = memory.temperatures.size();
+        let mid = n_nodes / 2;
+        let watts = 50.0;
+        ts.set_node_heat_source(&mut memory, mid, watts).unwrap();
+        assert_eq!(ts.get_node_heat_source(&memory, mid).unwrap(), watts);
+
+        // March until the front/back heat flows stop changing.
+        let mut change: Float = 99.0;
+        let mut previous_q: Float = -125.0;
+        let mut final_qfront: Float = 0.0;
+        let mut final_qback: Float = 0.0;
+        let mut counter: usize = 0;
+        while change.abs() > 1E-8 {
+            let (q_front, q_back) = ts
+                .march(
+                    &mut state,
+                    t_environment,
+                    t_environment,
+                    t_environment,
+                    t_environment,
+                    0.0,
+                    0.0,
+                    dt,
+                    &mut memory,
+                    None,
+                )
+                .unwrap();
+
+            final_qfront = q_front;
+            final_qback = q_back;
+
+            change = (q_front - previous_q).abs();
+            previous_q = q_front;
+
+            counter += 1;
+            if counter > 99999 {
+                panic!("Exceded number of iterations")
+            }
+        }
+
+        // With identical boundary conditions on both sides and the source
+        // centred between them, the analytical 1-D steady-state solution
+        // splits the injected power evenly: half escapes through the front,
+        // half through the back.
+        assert!(
+            (final_qfront - watts / 2.).abs() < 0.5,
+            "expected q_front close to {}, got {}",
+            watts / 2.,
+            final_qfront
+        );
+        assert!(
+            (final_qback - watts / 2.).abs() < 0.5,
+            "expected q_back close to {}, got {}",
+            watts / 2.,
+            final_qback
+        );
+    }
+
     #[test]
     fn test_march_nomass() {
         let mut model = SimpleModel::default();
@@ -1300,7 +3265,18 @@ mod testing {
         // Try marching until q_in and q_out are zero.
 
         let (q_in, q_out) = ts
-            .march(&mut state, 10.0, 10.0, 0.0, 0.0, dt, &mut memory)
+            .march(
+                &mut state,
+                10.0,
+                10.0,
+                10.0,
+                10.0,
+                0.0,
+                0.0,
+                dt,
+                &mut memory,
+                None,
+            )
             .unwrap();
 
         // this should show instantaneous update. So,
@@ -1392,7 +3368,18 @@ mod testing {
         let t_front = 10.0;
         let t_back = 30.0;
         let (q_front, q_back) = ts
-            .march(&mut state, t_front, t_back, 0.0, 0.0, dt, &mut memory)
+            .march(
+                &mut state,
+                t_front,
+                t_back,
+                t_front,
+                t_back,
+                0.0,
+                0.0,
+                dt,
+                &mut memory,
+                None,
+            )
             .unwrap();
 
         // Expecting
@@ -1438,6 +3425,16 @@ mod testing {
             k2: Matrix::new(0.0, 2, 1),
             k3: Matrix::new(0.0, 2, 1),
             k4: Matrix::new(0.0, 2, 1),
+            k5: Matrix::new(0.0, 2, 1),
+            k6: Matrix::new(0.0, 2, 1),
+            t4: Matrix::new(0.0, 2, 1),
+            propagator: None,
+            sub_diag: vec![0.0; 2],
+            main_diag: vec![0.0; 2],
+            super_diag: vec![0.0; 2],
+            rhs: vec![0.0; 2],
+            theta_factorization: None,
+            expm_factorization: None,
         };
         let dt = 0.01;
         rearrange_k(dt, &c, &mut memory).unwrap();
@@ -1477,4 +3474,317 @@ mod testing {
             }
         }
     }
+
+    #[test]
+    fn test_rk4_adaptive() {
+        // Same analytical system as `test_rk4`, but marched in much bigger
+        // chunks via `rk4_adaptive`'s own error-controlled sub-stepping.
+        let c = Matrix::from_data(2, 2, vec![1., 0., 0., 1.]);
+        let raw_k = Matrix::from_data(2, 2, vec![1., -3., 4., -6.]);
+        let raw_q = Matrix::from_data(2, 1, vec![0., 0.]);
+
+        let temp_a_fn = |time: Float| 0.75 * (-3. * time).exp() + (-2. * time).exp();
+        let temp_b_fn = |time: Float| (-3. * time).exp() + (-2. * time).exp();
+
+        let mut temperatures = Matrix::from_data(2, 1, vec![0.75 + 1., 2.]);
+        let mut memory = ChunkMemory {
+            k: raw_k.clone(),
+            q: raw_q.clone(),
+            aux: Matrix::new(0.0, 2, 1),
+            k1: Matrix::new(0.0, 2, 1),
+            k2: Matrix::new(0.0, 2, 1),
+            k3: Matrix::new(0.0, 2, 1),
+            k4: Matrix::new(0.0, 2, 1),
+            k5: Matrix::new(0.0, 2, 1),
+            k6: Matrix::new(0.0, 2, 1),
+            t4: Matrix::new(0.0, 2, 1),
+            propagator: None,
+            sub_diag: vec![0.0; 2],
+            main_diag: vec![0.0; 2],
+            super_diag: vec![0.0; 2],
+            rhs: vec![0.0; 2],
+            theta_factorization: None,
+            expm_factorization: None,
+        };
+
+        let options = crate::discretization::Rk4AdaptiveOptions::default();
+        let dt = 1.0; // a chunk two orders of magnitude bigger than test_rk4's fixed 0.01 step
+        let mut time = 0.0;
+        loop {
+            rk4_adaptive(&raw_k, &raw_q, &c, &options, &mut memory, &mut temperatures, dt)
+                .unwrap();
+            time += dt;
+
+            let temp_a = temperatures.get(0, 0).unwrap();
+            let exp_temp_a = temp_a_fn(time);
+            let temp_b = temperatures.get(1, 0).unwrap();
+            let exp_temp_b = temp_b_fn(time);
+
+            const SMOL: Float = 1e-4;
+            assert!(
+                (temp_a - exp_temp_a).abs() < SMOL,
+                "temp_a = {} | exp_temp_a = {}",
+                temp_a,
+                exp_temp_a
+            );
+            assert!(
+                (temp_b - exp_temp_b).abs() < SMOL,
+                "temp_b = {} | exp_temp_b = {}",
+                temp_b,
+                exp_temp_b
+            );
+
+            if time > 10. {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn test_expm_matches_known_decay() {
+        // dT/dt = -T, so T(dt) = T(0) * exp(-dt)
+        let a = vec![vec![-1.0]];
+        let result = expm(&a);
+        let expected: Float = (-1.0 as Float).exp();
+        assert!((result[0][0] - expected).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_expm_march_matches_analytical_decay() {
+        // C=1, K=-1, q=0: T(t) = T(0)*exp(-t)
+        let c = Matrix::from_data(1, 1, vec![1.0]);
+        let mut memory = ChunkMemory {
+            k: Matrix::from_data(1, 1, vec![-1.0]),
+            q: Matrix::new(0.0, 1, 1),
+            aux: Matrix::new(0.0, 1, 1),
+            k1: Matrix::new(0.0, 1, 1),
+            k2: Matrix::new(0.0, 1, 1),
+            k3: Matrix::new(0.0, 1, 1),
+            k4: Matrix::new(0.0, 1, 1),
+            k5: Matrix::new(0.0, 1, 1),
+            k6: Matrix::new(0.0, 1, 1),
+            t4: Matrix::new(0.0, 1, 1),
+            propagator: None,
+            sub_diag: vec![0.0; 1],
+            main_diag: vec![0.0; 1],
+            super_diag: vec![0.0; 1],
+            rhs: vec![0.0; 1],
+            theta_factorization: None,
+            expm_factorization: None,
+        };
+        let dt = 0.37;
+        let mut t = Matrix::from_data(1, 1, vec![10.0]);
+
+        expm_march(dt, &c, &mut memory, &mut t).unwrap();
+
+        let expected = 10.0 * (-dt).exp();
+        assert!((t.get(0, 0).unwrap() - expected).abs() < 1e-6);
+        assert!(memory.propagator.is_some());
+    }
+
+    #[test]
+    fn test_surface_memory_checkpoint_restore() {
+        let mut memory = SurfaceMemory {
+            massive_chunks: Vec::new(),
+            nomass_chunks: Vec::new(),
+            temperatures: Matrix::from_data(2, 1, vec![20.0, 21.0]),
+            q: Matrix::new(0.0, 2, 1),
+            node_heat_sources: Matrix::new(0.0, 2, 1),
+            state_space_cache: Vec::new(),
+        };
+
+        let checkpoint = memory.checkpoint();
+        memory.temperatures.set(0, 0, 99.0).unwrap();
+        memory.temperatures.set(1, 0, 99.0).unwrap();
+        assert_eq!(memory.temperatures.get(0, 0).unwrap(), 99.0);
+
+        memory.restore(&checkpoint);
+        assert_eq!(memory.temperatures.get(0, 0).unwrap(), 20.0);
+        assert_eq!(memory.temperatures.get(1, 0).unwrap(), 21.0);
+
+        let mut n_calls = 0;
+        memory
+            .wind_forward(&checkpoint, 3, |mem| {
+                n_calls += 1;
+                let t = mem.temperatures.get(0, 0).unwrap();
+                mem.temperatures.set(0, 0, t + 1.0)
+            })
+            .unwrap();
+        assert_eq!(n_calls, 3);
+        assert_eq!(memory.temperatures.get(0, 0).unwrap(), 23.0);
+    }
+
+    #[test]
+    fn test_thomas_solve() {
+        // | 2 -1  0 | |x0|   |1|
+        // |-1  2 -1 | |x1| = |0|
+        // | 0 -1  2 | |x2|   |1|
+        let sub = vec![0.0, -1.0, -1.0];
+        let mut main_diag = vec![2.0, 2.0, 2.0];
+        let super_diag = vec![-1.0, -1.0, 0.0];
+        let mut rhs = vec![1.0, 0.0, 1.0];
+
+        let solution = thomas_solve(&sub, &mut main_diag, &super_diag, &mut rhs);
+
+        // Check against the original (unmodified) system.
+        let orig_sub = [0.0, -1.0, -1.0];
+        let orig_diag = [2.0, 2.0, 2.0];
+        let orig_sup = [-1.0, -1.0, 0.0];
+        let orig_rhs = [1.0, 0.0, 1.0];
+        for i in 0..3 {
+            let mut row_sum = orig_diag[i] * solution[i];
+            if i > 0 {
+                row_sum += orig_sub[i] * solution[i - 1];
+            }
+            if i < 2 {
+                row_sum += orig_sup[i] * solution[i + 1];
+            }
+            assert!((row_sum - orig_rhs[i]).abs() < 1e-8);
+        }
+    }
+
+    #[test]
+    fn test_solar_source_term_matches_front_back_alphas() {
+        let mut model = SimpleModel::default();
+        let brickwork = add_brickwork(&mut model);
+        let m1 = add_material(&mut model, brickwork, 20. / 1000.);
+
+        let mut c = Construction::new("construction".to_string());
+        c.materials.push(m1.name().clone());
+        let c = model.add_construction(c);
+
+        let mut the_loop = Loop3D::new();
+        let l = 1. as Float;
+        the_loop.push(Point3D::new(-l, -l, 0.)).unwrap();
+        the_loop.push(Point3D::new(l, -l, 0.)).unwrap();
+        the_loop.push(Point3D::new(l, l, 0.)).unwrap();
+        the_loop.push(Point3D::new(-l, l, 0.)).unwrap();
+        the_loop.close().unwrap();
+        let p = Polygon3D::new(the_loop).unwrap();
+        let s = Surface::new("Surface 1".to_string(), p, c.name().clone());
+        let surface = model.add_surface(s);
+
+        let main_dt = 300.0;
+        let max_dx = m1.thickness / 2.0;
+        let min_dt = 1.0;
+        let d = Discretization::new(&c, &model, main_dt, max_dx, min_dt, 1., 0.).unwrap();
+        let normal = geometry3d::Vector3D::new(0., 0., 1.);
+        let perimeter = 8. * l;
+        let mut state_header = SimulationStateHeader::new();
+        let ts = ThermalSurface::new(
+            &mut state_header,
+            &model,
+            &None,
+            0,
+            &surface,
+            surface.area(),
+            perimeter,
+            10.,
+            normal,
+            &c,
+            d,
+        )
+        .unwrap();
+
+        let front_irradiance = 500.0;
+        let back_irradiance = 50.0;
+        let q_solar = ts.solar_source_term(front_irradiance, back_irradiance);
+
+        let (n, ..) = q_solar.size();
+        for i in 0..n {
+            let expected = ts.front_alphas.get(i, 0).unwrap() * front_irradiance
+                + ts.back_alphas.get(i, 0).unwrap() * back_irradiance;
+            assert!((q_solar.get(i, 0).unwrap() - expected).abs() < 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_march_theta_series_converges_to_steady_state() {
+        use crate::discretization::{IntegrationScheme, UValue};
+
+        // A single solid layer of four elements, every node (boundary nodes
+        // included) carrying the same mass; marched long enough, the
+        // implicit march should settle onto the same steady-state profile
+        // `get_k_q` alone would produce.
+        let n = 4;
+        let thickness = 0.2;
+        let thermal_cond = 1.0;
+        let dx = thickness / n as Float;
+        let u = thermal_cond / dx;
+
+        let mut segments = Vec::with_capacity(n + 1);
+        for _ in 0..n {
+            segments.push((1000.0, UValue::Solid(u)));
+        }
+        segments.push((1000.0, UValue::Back));
+
+        let d = Discretization {
+            specific_heat_overrides: Vec::new(),
+            phase_change_overrides: Vec::new(),
+            heat_sources: Vec::new(),
+            segments,
+            tstep_subdivision: 1,
+            n_elements: vec![n],
+            scheme: IntegrationScheme::Theta { theta: 1.0 },
+            front_thermal_bridge: None,
+            back_thermal_bridge: None,
+            node_thermal_bridges: Vec::new(),
+        };
+
+        let front_env = ConvectionParams {
+            air_temperature: 30.,
+            air_speed: 0.,
+            rad_temperature: 30.,
+            ..ConvectionParams::default()
+        };
+        let back_env = ConvectionParams {
+            air_temperature: 10.,
+            air_speed: 0.,
+            rad_temperature: 10.,
+            ..ConvectionParams::default()
+        };
+        let front_hs = 10.0;
+        let back_hs = 10.0;
+
+        let initial_temperatures = Matrix::from_data(n + 1, 1, vec![20.0; n + 1]);
+        let solar_radiation = Matrix::new(0.0, n + 1, 1);
+
+        let steps: Vec<ThetaBoundaryStep> = (0..50)
+            .map(|_| ThetaBoundaryStep {
+                dt: 300.0,
+                front_env: front_env.clone(),
+                front_hs,
+                front_rad_hs: 0.0,
+                back_env: back_env.clone(),
+                back_hs,
+                back_rad_hs: 0.0,
+                solar_radiation: solar_radiation.clone(),
+            })
+            .collect();
+
+        let trajectory = march_theta_series(&d, &initial_temperatures, 1.0, &steps).unwrap();
+        assert_eq!(trajectory.len(), steps.len() + 1);
+
+        // Starting from the (arbitrary) 20C initial guess, every node should
+        // have landed within the front and back boundary temperatures.
+        let last = trajectory.last().unwrap();
+        for i in 0..=n {
+            let t = last.get(i, 0).unwrap();
+            assert!(
+                (10.0..=30.0).contains(&t),
+                "node {i} settled at {t}, expected it within the boundary range"
+            );
+        }
+
+        // Marching further should no longer move the solution much: the
+        // system has reached (near) steady state.
+        let one_more = march_theta_series(&d, last, 1.0, &steps[..1]).unwrap();
+        let settled = one_more.last().unwrap();
+        for i in 0..=n {
+            assert!(
+                (settled.get(i, 0).unwrap() - last.get(i, 0).unwrap()).abs() < 1e-3,
+                "node {i} still moving at steady state"
+            );
+        }
+    }
 }
@@ -161,6 +161,20 @@ pub trait SurfaceTrait : Clone + Send  {
 
     /// Gets the back IR irradiance
     fn back_infrared_irradiance(&self, state: &SimulationState) -> Float;
+
+    /// Sets the front IR irradiance
+    fn set_front_infrared_irradiance(
+        &self,
+        state: &mut SimulationState,
+        v: Float,
+    ) -> Result<(), String>;
+
+    /// Sets the back IR irradiance
+    fn set_back_infrared_irradiance(
+        &self,
+        state: &mut SimulationState,
+        v: Float,
+    ) -> Result<(), String>;
 }
 
 impl SurfaceTrait for Surface {
@@ -182,6 +196,13 @@ impl SurfaceTrait for Surface {
         self.back_ir_irradiance(state).unwrap()
     }
 
+    fn set_front_infrared_irradiance(&self, state: &mut SimulationState, v: Float) -> Result<(), String> {
+        self.set_front_ir_irradiance(state, v)
+    }
+    fn set_back_infrared_irradiance(&self, state: &mut SimulationState, v: Float) -> Result<(), String> {
+        self.set_back_ir_irradiance(state, v)
+    }
+
     fn front_solar_irradiance(&self, state: &SimulationState) -> Float {
         self.front_incident_solar_irradiance(state).unwrap()
     }
@@ -394,6 +415,12 @@ impl SurfaceTrait for Fenestration {
     fn back_infrared_irradiance(&self, state: &SimulationState) -> Float {
         self.back_ir_irradiance(state).unwrap()
     }
+    fn set_front_infrared_irradiance(&self, state: &mut SimulationState, v: Float) -> Result<(), String> {
+        self.set_front_ir_irradiance(state, v)
+    }
+    fn set_back_infrared_irradiance(&self, state: &mut SimulationState, v: Float) -> Result<(), String> {
+        self.set_back_ir_irradiance(state, v)
+    }
     fn front_solar_irradiance(&self, state: &SimulationState) -> Float {
         self.front_incident_solar_irradiance(state).unwrap()
     }
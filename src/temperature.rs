@@ -0,0 +1,213 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A unit-safe [`Temperature`]/[`TempDelta`] pair, to replace the bare
+//! `Float` Celsius/Kelvin values that `get_k_q` and its neighbours juggle
+//! today via manual `+ 273.15` / `- kelvin` conversions.
+//!
+//! This module is a narrow, self-contained primitive rather than a
+//! crate-wide migration: [`crate::discretization`]'s `get_k_q` and the
+//! boundary-condition structs it reads from (e.g.
+//! `crate::convection::ConvectionParams`) operate directly on
+//! `matrix::Matrix`, a concrete `Float` matrix type from an external crate
+//! with no generic element parameter, so there is no `Matrix<Temperature>`
+//! to thread through the solver's internals. Re-typing every boundary
+//! struct and solver signature to pass `Temperature` instead of `Float`
+//! would touch dozens of call sites across the whole crate for no change
+//! in solver behavior. This module provides the typed primitive so new and
+//! future call sites can be unit-safe, and a pair of conversion helpers
+//! ([`Temperature::vec_from_matrix_celsius`] and
+//! [`Temperature::vec_to_matrix_celsius`]) for crossing the boundary to and
+//! from the raw `Matrix` the solver still uses internally.
+
+use crate::Float;
+use std::ops::{Add, Sub};
+
+/// Zero Celsius, in Kelvin.
+const KELVIN_OFFSET: Float = 273.15;
+
+/// An absolute temperature, stored internally in Kelvin. Construct with
+/// [`Self::from_celsius`] or [`Self::from_kelvin`]; read back with
+/// [`Self::as_celsius`] or [`Self::as_kelvin`].
+///
+/// Subtracting two `Temperature`s yields a [`TempDelta`], not another
+/// `Temperature`—this is what prevents the crate's usual `t + 273.15`
+/// idiom from accidentally being applied twice or not at all.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Temperature {
+    kelvin: Float,
+}
+
+/// A temperature *difference*. Unlike [`Temperature`], a `TempDelta` is the
+/// same number of degrees whether measured in Celsius or Kelvin, so it has
+/// no `from_celsius`/`from_kelvin` split—just [`Self::from_degrees`] and
+/// [`Self::as_degrees`].
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct TempDelta {
+    degrees: Float,
+}
+
+impl Temperature {
+    /// Builds a `Temperature` from a Celsius value.
+    pub fn from_celsius(t: Float) -> Self {
+        Self {
+            kelvin: t + KELVIN_OFFSET,
+        }
+    }
+
+    /// Builds a `Temperature` from a Kelvin value.
+    pub fn from_kelvin(t: Float) -> Self {
+        Self { kelvin: t }
+    }
+
+    /// This temperature, in Celsius.
+    pub fn as_celsius(&self) -> Float {
+        self.kelvin - KELVIN_OFFSET
+    }
+
+    /// This temperature, in Kelvin.
+    pub fn as_kelvin(&self) -> Float {
+        self.kelvin
+    }
+
+    /// Builds a `Vec` of `Temperature`s, one per row, from a `Matrix` of
+    /// bare Celsius values (e.g. the node-temperature `Matrix` that
+    /// [`crate::discretization::Discretization::get_k_q`] reads), for
+    /// callers that want a unit-safe view at the boundary without changing
+    /// the solver's own `Matrix<Float>` internals.
+    pub fn vec_from_matrix_celsius(m: &matrix::Matrix) -> Result<Vec<Self>, String> {
+        let (nrows, _) = m.size();
+        (0..nrows)
+            .map(|i| m.get(i, 0).map(Self::from_celsius))
+            .collect()
+    }
+
+    /// The inverse of [`Self::vec_from_matrix_celsius`]: flattens a slice of
+    /// `Temperature`s back into a single-column `Matrix` of bare Celsius
+    /// values, ready to hand back to the solver.
+    pub fn vec_to_matrix_celsius(temperatures: &[Self]) -> matrix::Matrix {
+        let mut m = matrix::Matrix::new(0.0, temperatures.len(), 1);
+        for (i, t) in temperatures.iter().enumerate() {
+            m.set(i, 0, t.as_celsius()).expect("index within bounds");
+        }
+        m
+    }
+}
+
+impl TempDelta {
+    /// Builds a `TempDelta` from a number of degrees (Celsius == Kelvin for
+    /// a difference).
+    pub fn from_degrees(degrees: Float) -> Self {
+        Self { degrees }
+    }
+
+    /// This difference, in degrees (Celsius == Kelvin).
+    pub fn as_degrees(&self) -> Float {
+        self.degrees
+    }
+}
+
+impl Sub for Temperature {
+    type Output = TempDelta;
+
+    fn sub(self, rhs: Self) -> TempDelta {
+        TempDelta::from_degrees(self.kelvin - rhs.kelvin)
+    }
+}
+
+impl Add<TempDelta> for Temperature {
+    type Output = Temperature;
+
+    fn add(self, rhs: TempDelta) -> Temperature {
+        Temperature::from_kelvin(self.kelvin + rhs.degrees)
+    }
+}
+
+impl Sub<TempDelta> for Temperature {
+    type Output = Temperature;
+
+    fn sub(self, rhs: TempDelta) -> Temperature {
+        Temperature::from_kelvin(self.kelvin - rhs.degrees)
+    }
+}
+
+impl Add for TempDelta {
+    type Output = TempDelta;
+
+    fn add(self, rhs: Self) -> TempDelta {
+        TempDelta::from_degrees(self.degrees + rhs.degrees)
+    }
+}
+
+impl Sub for TempDelta {
+    type Output = TempDelta;
+
+    fn sub(self, rhs: Self) -> TempDelta {
+        TempDelta::from_degrees(self.degrees - rhs.degrees)
+    }
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn celsius_and_kelvin_round_trip() {
+        let t = Temperature::from_celsius(20.0);
+        assert!((t.as_kelvin() - 293.15).abs() < 1e-10);
+        assert!((t.as_celsius() - 20.0).abs() < 1e-10);
+
+        let t = Temperature::from_kelvin(0.0);
+        assert!((t.as_celsius() - (-273.15)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn subtracting_temperatures_yields_a_delta() {
+        let hot = Temperature::from_celsius(30.0);
+        let cold = Temperature::from_celsius(20.0);
+        let delta = hot - cold;
+        assert!((delta.as_degrees() - 10.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn adding_and_subtracting_a_delta_moves_the_temperature() {
+        let t = Temperature::from_celsius(20.0);
+        let delta = TempDelta::from_degrees(5.0);
+        assert!(((t + delta).as_celsius() - 25.0).abs() < 1e-10);
+        assert!(((t - delta).as_celsius() - 15.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn matrix_round_trip_preserves_celsius_values() {
+        let mut m = matrix::Matrix::new(0.0, 3, 1);
+        m.set(0, 0, -5.0).unwrap();
+        m.set(1, 0, 0.0).unwrap();
+        m.set(2, 0, 20.0).unwrap();
+
+        let temperatures = Temperature::vec_from_matrix_celsius(&m).unwrap();
+        assert_eq!(temperatures.len(), 3);
+        assert!((temperatures[2].as_celsius() - 20.0).abs() < 1e-10);
+
+        let back = Temperature::vec_to_matrix_celsius(&temperatures);
+        for i in 0..3 {
+            assert!((back.get(i, 0).unwrap() - m.get(i, 0).unwrap()).abs() < 1e-10);
+        }
+    }
+}
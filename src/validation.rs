@@ -0,0 +1,330 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! A pre-simulation input-plausibility pass.
+//!
+//! Nothing in [`crate::discretization::Discretization::new`] or
+//! [`crate::model::ThermalModel::new`] checks that a [`Construction`]'s
+//! layers are physically sensible—a zero-thickness material, a negative
+//! conductivity, or an emissivity of `1.4` doesn't fail fast; it produces
+//! `NaN`s or silently wrong numbers somewhere deep inside `march`, the same
+//! failure mode [`crate::glazing::GlazingError`] exists to catch for
+//! glazing optics specifically. This module does the equivalent walk for
+//! opaque/translucent material layers, collecting every problem found
+//! (not just the first) so a caller gets one actionable report instead of
+//! a trial-and-error hunt through diverging temperatures.
+
+use crate::Float;
+use simple_model::{Construction, SimpleModel, Substance};
+use std::sync::Arc;
+
+/// One physically-implausible or incomplete input found while validating a
+/// [`Construction`] or a resolved weather sample.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError {
+    /// A value that must be strictly positive was zero or negative.
+    NegativeOrZero {
+        /// What the value is (e.g. `"material 'brick' thickness"`).
+        context: String,
+        /// The offending value.
+        value: Float,
+    },
+
+    /// A value fell outside its physically valid range (e.g. an emissivity
+    /// outside `[0, 1]`, or a wind direction outside `[0, 360]`).
+    OutOfRange {
+        /// What the value is.
+        context: String,
+        /// The offending value.
+        value: Float,
+        /// The valid range's lower bound, inclusive.
+        min: Float,
+        /// The valid range's upper bound, inclusive.
+        max: Float,
+    },
+
+    /// A property a downstream calculation needs unconditionally (e.g.
+    /// `march` panicking on `Substance::thermal_conductivity()`'s `Err`)
+    /// was never set.
+    MissingProperty {
+        /// What is missing (e.g. `"material 'brick' substance 'red_brick'
+        /// thermal conductivity"`).
+        context: String,
+    },
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::NegativeOrZero { context, value } => {
+                write!(f, "{context} must be positive, found {value}")
+            }
+            Self::OutOfRange { context, value, min, max } => {
+                write!(f, "{context} is out of the valid [{min},{max}] range, found {value}")
+            }
+            Self::MissingProperty { context } => write!(f, "{context} is required but was not set"),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Checks `value` against `[min, max]`, pushing an [`ValidationError::OutOfRange`]
+/// onto `errors` (tagged with `context`) if it falls outside.
+fn check_range(errors: &mut Vec<ValidationError>, context: &str, value: Float, min: Float, max: Float) {
+    if value < min || value > max {
+        errors.push(ValidationError::OutOfRange {
+            context: context.to_string(),
+            value,
+            min,
+            max,
+        });
+    }
+}
+
+/// Checks that `value` is strictly positive, pushing an
+/// [`ValidationError::NegativeOrZero`] onto `errors` (tagged with `context`)
+/// if it is not.
+fn check_positive(errors: &mut Vec<ValidationError>, context: &str, value: Float) {
+    if value <= 0.0 {
+        errors.push(ValidationError::NegativeOrZero {
+            context: context.to_string(),
+            value,
+        });
+    }
+}
+
+/// Validates one [`Substance::Normal`]'s physical properties, pushing every
+/// problem found onto `errors`. `label` identifies the material/substance
+/// pair for the resulting messages (e.g. `"material 'brick' (substance
+/// 'red_brick')"`).
+fn validate_normal_substance(errors: &mut Vec<ValidationError>, label: &str, s: &simple_model::substance::Normal) {
+    match s.thermal_conductivity() {
+        Ok(k) => check_positive(errors, &format!("{label} thermal conductivity"), *k),
+        Err(_) => errors.push(ValidationError::MissingProperty {
+            context: format!("{label} thermal conductivity"),
+        }),
+    }
+    match s.density() {
+        Ok(rho) => check_positive(errors, &format!("{label} density"), *rho),
+        Err(_) => errors.push(ValidationError::MissingProperty {
+            context: format!("{label} density"),
+        }),
+    }
+    match s.specific_heat_capacity() {
+        Ok(cp) => check_positive(errors, &format!("{label} specific heat capacity"), *cp),
+        Err(_) => errors.push(ValidationError::MissingProperty {
+            context: format!("{label} specific heat capacity"),
+        }),
+    }
+
+    // Unlike the properties above, `march` already tolerates these being
+    // unset: `ThermalSurfaceData::new` reads them via
+    // `front_thermal_absorbtance_or`/`back_thermal_absorbtance_or`, which
+    // fall back to `DEFAULT_EM` rather than erroring. So a missing value
+    // isn't itself an error here either—but a present, out-of-range one
+    // still is, since the `_or` getters return whatever was actually set.
+    const DEFAULT_EM: Float = 0.84;
+    check_range(
+        errors,
+        &format!("{label} front thermal absorptance (emissivity)"),
+        s.front_thermal_absorbtance_or(crate::model::MODULE_NAME, DEFAULT_EM),
+        0.0,
+        1.0,
+    );
+    check_range(
+        errors,
+        &format!("{label} back thermal absorptance (emissivity)"),
+        s.back_thermal_absorbtance_or(crate::model::MODULE_NAME, DEFAULT_EM),
+        0.0,
+        1.0,
+    );
+    if let Ok(a) = s.front_solar_absorbtance() {
+        check_range(errors, &format!("{label} front solar absorptance"), *a, 0.0, 1.0);
+    }
+    if let Ok(a) = s.back_solar_absorbtance() {
+        check_range(errors, &format!("{label} back solar absorptance"), *a, 0.0, 1.0);
+    }
+}
+
+/// Walks every material layer of `construction`, collecting every
+/// physically-implausible or missing property found—negative/zero layer
+/// thicknesses, non-positive conductivity/density/specific heat, and
+/// out-of-range emissivity/solar absorptance. Returns an empty `Vec` if
+/// the construction is entirely plausible.
+pub fn validate_construction(construction: &Arc<Construction>, model: &SimpleModel) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    for mat_name in construction.materials.iter() {
+        let material = match model.get_material(mat_name) {
+            Ok(m) => m,
+            Err(e) => {
+                errors.push(ValidationError::MissingProperty { context: e });
+                continue;
+            }
+        };
+        let label = format!("construction '{}' material '{}'", construction.name(), material.name());
+        check_positive(&mut errors, &format!("{label} thickness"), material.thickness);
+
+        let substance = match model.get_substance(&material.substance) {
+            Ok(s) => s,
+            Err(e) => {
+                errors.push(ValidationError::MissingProperty { context: e });
+                continue;
+            }
+        };
+        match substance {
+            Substance::Normal(s) => validate_normal_substance(&mut errors, &label, s),
+            // Gas cavities carry their own geometry/gas-mixture checks in
+            // `crate::cavity`; nothing here applies to them.
+            Substance::Gas(_) => {}
+        }
+    }
+    errors
+}
+
+/// Walks every [`SimpleModel::surfaces`]/[`SimpleModel::fenestrations`]'s
+/// construction, collecting every [`ValidationError`] found across all of
+/// them. Intended to run once before a [`crate::model::ThermalModel`] is
+/// built, so bad input data is reported up front instead of being
+/// discovered through `NaN`/diverging temperatures mid-simulation.
+pub fn validate_model(model: &SimpleModel) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    for surf in model.surfaces.iter() {
+        if let Ok(construction) = model.get_construction(&surf.construction) {
+            errors.extend(validate_construction(&construction, model));
+        }
+    }
+    for fen in model.fenestrations.iter() {
+        if let Ok(construction) = model.get_construction(&fen.construction) {
+            errors.extend(validate_construction(&construction, model));
+        }
+    }
+    errors
+}
+
+/// Validates a single already-resolved wind direction sample (in degrees).
+///
+/// `weather::SyntheticWeather`'s `wind_direction` is a `Box<dyn Schedule>`
+/// with no way to enumerate its full range of future values from here, so
+/// this checks one sample the same way [`crate::model::ThermalModel::march`]
+/// already resolves one per step—callers driving their own weather loop can
+/// call this right after evaluating the schedule, instead of only finding
+/// out a bearing was nonsensical once it reaches a convection calculation.
+pub fn validate_wind_direction(degrees: Float) -> Result<(), ValidationError> {
+    if !(0.0..=360.0).contains(&degrees) {
+        return Err(ValidationError::OutOfRange {
+            context: "wind direction".to_string(),
+            value: degrees,
+            min: 0.0,
+            max: 360.0,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    fn model_with_material(
+        thickness: Float,
+        conductivity: Option<Float>,
+        density: Option<Float>,
+        specific_heat: Option<Float>,
+        thermal_absorbtance: Option<Float>,
+    ) -> (SimpleModel, Arc<Construction>) {
+        let mut model = SimpleModel::default();
+        let mut s = simple_model::substance::Normal::new("the substance");
+        if let Some(k) = conductivity {
+            s.set_thermal_conductivity(k);
+        }
+        if let Some(rho) = density {
+            s.set_density(rho);
+        }
+        if let Some(cp) = specific_heat {
+            s.set_specific_heat_capacity(cp);
+        }
+        if let Some(e) = thermal_absorbtance {
+            s.set_front_thermal_absorbtance(e);
+            s.set_back_thermal_absorbtance(e);
+        }
+        let s = s.wrap();
+        let s = model.add_substance(s);
+
+        let material = simple_model::Material::new("the material".to_string(), s.name().clone(), thickness);
+        let material = model.add_material(material);
+
+        let mut construction = Construction::new("the construction");
+        construction.materials.push(material.name().clone());
+        let construction = model.add_construction(construction);
+        (model, construction)
+    }
+
+    #[test]
+    fn plausible_construction_has_no_errors() {
+        let (model, construction) = model_with_material(0.1, Some(1.0), Some(2000.0), Some(900.0), Some(0.9));
+        assert!(validate_construction(&construction, &model).is_empty());
+    }
+
+    #[test]
+    fn zero_thickness_is_negative_or_zero() {
+        let (model, construction) = model_with_material(0.0, Some(1.0), Some(2000.0), Some(900.0), Some(0.9));
+        let errors = validate_construction(&construction, &model);
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::NegativeOrZero { context, .. } if context.contains("thickness"))));
+    }
+
+    #[test]
+    fn missing_conductivity_is_reported() {
+        let (model, construction) = model_with_material(0.1, None, Some(2000.0), Some(900.0), Some(0.9));
+        let errors = validate_construction(&construction, &model);
+        assert!(errors.iter().any(
+            |e| matches!(e, ValidationError::MissingProperty { context } if context.contains("thermal conductivity"))
+        ));
+    }
+
+    #[test]
+    fn out_of_range_emissivity_is_reported() {
+        let (model, construction) = model_with_material(0.1, Some(1.0), Some(2000.0), Some(900.0), Some(1.4));
+        let errors = validate_construction(&construction, &model);
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::OutOfRange { context, .. } if context.contains("absorptance"))));
+    }
+
+    #[test]
+    fn all_problems_are_collected_not_just_the_first() {
+        let (model, construction) = model_with_material(0.0, None, Some(-5.0), Some(900.0), Some(1.4));
+        let errors = validate_construction(&construction, &model);
+        // thickness, conductivity (missing), density (negative), and
+        // front+back emissivity (out of range): five independent problems
+        // in one material.
+        assert_eq!(errors.len(), 5);
+    }
+
+    #[test]
+    fn wind_direction_accepts_full_compass_range() {
+        assert!(validate_wind_direction(0.0).is_ok());
+        assert!(validate_wind_direction(359.9).is_ok());
+        assert!(validate_wind_direction(-1.0).is_err());
+        assert!(validate_wind_direction(360.1).is_err());
+    }
+}
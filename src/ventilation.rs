@@ -0,0 +1,347 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+//! Fixed-air-change-rate ventilation/infiltration elements, each
+//! contributing an air-flow conductance between a Zone and the outdoors
+//! to [`crate::model::ThermalModel::calculate_zones_non_surface_abc`].
+
+use crate::Float;
+
+/// A Zone's exchange of air with the outdoors at a fixed air-change
+/// rate, contributing a conductance (and driving temperature) to that
+/// Zone's heat balance—see
+/// [`Self::conductance_and_supply_temperature`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VentilationElement {
+    /// Uncontrolled air leakage: air enters at the outdoor temperature.
+    Infiltration {
+        /// The index (into [`crate::model::ThermalModel::zones`]) of the
+        /// Zone this element ventilates.
+        zone_index: usize,
+        /// Air changes per hour
+        ach: Float,
+    },
+    /// A whole-house mechanical ventilation heat-recovery unit: air is
+    /// drawn in from outdoors and tempered towards the Zone's own air
+    /// temperature with sensible effectiveness `efficiency` before
+    /// entering the Zone.
+    Mvhr {
+        /// The index (into [`crate::model::ThermalModel::zones`]) of the
+        /// Zone this element ventilates.
+        zone_index: usize,
+        /// Air changes per hour
+        ach: Float,
+        /// Sensible heat-recovery effectiveness, in `[0, 1]`. `0.0` is a
+        /// bypass (e.g. summer free-cooling): the unit moves air but
+        /// recovers no heat, so `T_supply` falls back to `T_out`.
+        efficiency: Float,
+        /// The unit's electric fan draw (W), tracked separately from the
+        /// air's thermal conductance since it is parasitic electricity
+        /// consumption rather than a heat path into the Zone—see
+        /// [`Self::fan_power`].
+        fan_power: Float,
+    },
+    /// A whole-house mechanical extract fan: air is extracted from the
+    /// Zone and replaced by outdoor air, with no heat recovery.
+    WholeHouseExtract {
+        /// The index (into [`crate::model::ThermalModel::zones`]) of the
+        /// Zone this element ventilates.
+        zone_index: usize,
+        /// Air changes per hour
+        ach: Float,
+    },
+    /// Fan-assisted night purge ventilation: a controller opens a high-
+    /// flow path between the Zone and outdoors overnight to dump heat
+    /// stored in the Zone's thermal mass during the day, closing again
+    /// once the Zone has cooled or the window has ended. Outside the
+    /// control window, or whenever the control rule isn't satisfied, this
+    /// element contributes nothing (equivalent to `ach = 0`)—it isn't a
+    /// background leakage path the way [`Self::Infiltration`] is, so
+    /// there's no `base_ach` term to fall back to.
+    NightVentilation {
+        /// The index (into [`crate::model::ThermalModel::zones`]) of the
+        /// Zone this element ventilates.
+        zone_index: usize,
+        /// Air changes per hour while purging.
+        ach: Float,
+        /// The Zone must be warmer than this (C) for the controller to
+        /// purge—i.e. don't bother night-cooling a Zone that's already
+        /// comfortable.
+        setpoint_temperature: Float,
+        /// The first hour of the day (0-24, in the same "hours since local
+        /// midnight" convention as [`Self::conductance_and_supply_temperature`]'s
+        /// `hour_of_day` argument) at which purging may start.
+        window_start_hour: Float,
+        /// The last hour of the day at which purging may still be active.
+        /// If this is less than `window_start_hour`, the window is taken
+        /// to wrap past midnight (e.g. `22` to `6`).
+        window_end_hour: Float,
+    },
+    /// A naturally-vented cavity (e.g. a Trombe wall's high/low vents)
+    /// coupling a Zone directly to a cavity's own air rather than to the
+    /// outdoors: the cavity's own physics (see
+    /// [`crate::cavity::Ventilation::buoyancy_driven`]) determines the mass
+    /// flow and outlet air temperature each step, so unlike every other
+    /// variant here this carries a `mass_flow` (kg/s) directly instead of
+    /// an air-change rate scaled by Zone volume.
+    CavityVent {
+        /// The index (into [`crate::model::ThermalModel::zones`]) of the
+        /// Zone this element ventilates.
+        zone_index: usize,
+        /// The cavity air's mass flow rate into the Zone, in kg/s—computed
+        /// externally (e.g. by [`crate::cavity::Ventilation::buoyancy_driven`])
+        /// and updated every step, since it depends on the cavity's own
+        /// (separately marched) temperature.
+        mass_flow: Float,
+        /// The cavity air's outlet temperature (C), entering the Zone
+        /// directly as this element's `T_supply`.
+        supply_temperature: Float,
+    },
+}
+
+impl VentilationElement {
+    /// The Zone this element applies to.
+    pub fn zone_index(&self) -> usize {
+        match self {
+            Self::Infiltration { zone_index, .. }
+            | Self::Mvhr { zone_index, .. }
+            | Self::WholeHouseExtract { zone_index, .. }
+            | Self::NightVentilation { zone_index, .. }
+            | Self::CavityVent { zone_index, .. } => *zone_index,
+        }
+    }
+
+    fn ach(&self) -> Float {
+        match self {
+            Self::Infiltration { ach, .. }
+            | Self::Mvhr { ach, .. }
+            | Self::WholeHouseExtract { ach, .. }
+            | Self::NightVentilation { ach, .. } => *ach,
+            // Driven by its own `mass_flow` instead—see
+            // `conductance_and_supply_temperature`'s early return.
+            Self::CavityVent { .. } => 0.0,
+        }
+    }
+
+    /// Whether `hour_of_day` (0-24, hours since local midnight) falls
+    /// within `[window_start_hour, window_end_hour)`, accounting for a
+    /// window that wraps past midnight when `window_end_hour <
+    /// window_start_hour`.
+    fn in_window(hour_of_day: Float, window_start_hour: Float, window_end_hour: Float) -> bool {
+        if window_start_hour <= window_end_hour {
+            hour_of_day >= window_start_hour && hour_of_day < window_end_hour
+        } else {
+            hour_of_day >= window_start_hour || hour_of_day < window_end_hour
+        }
+    }
+
+    /// The unit's electric fan draw (W)—`0.0` for [`Self::Infiltration`]
+    /// (uncontrolled leakage has no fan), [`Self::WholeHouseExtract`] and
+    /// [`Self::NightVentilation`] (neither modelled here), or
+    /// [`Self::Mvhr`]'s own `fan_power` field. Callers are responsible for
+    /// folding this into their own electricity accounting (e.g.
+    /// [`crate::energy_supply::FuelDemand::add`]); it has no effect on the
+    /// Zone's thermal balance.
+    pub fn fan_power(&self) -> Float {
+        match self {
+            Self::Mvhr { fan_power, .. } => *fan_power,
+            Self::Infiltration { .. }
+            | Self::WholeHouseExtract { .. }
+            | Self::NightVentilation { .. }
+            | Self::CavityVent { .. } => 0.0,
+        }
+    }
+
+    /// The conductance `G` (W/K) and supply temperature `T_supply` (C)
+    /// this element contributes to its Zone's heat balance (i.e.
+    /// `b[zone_index] += G` and `a[zone_index] += G*T_supply`), given the
+    /// Zone's volume (m3) and current air temperature, the outdoor
+    /// temperature, outdoor air's density/specific heat, and the current
+    /// hour of the day (0-24, hours since local midnight—used only by
+    /// [`Self::NightVentilation`]'s control rule).
+    ///
+    /// The volumetric flow is `q = ach*zone_volume/3600` (m3/s) and the
+    /// conductance is `G = rho_air*cp_air*q` (W/K). `T_supply` is the
+    /// outdoor temperature for [`Self::Infiltration`],
+    /// [`Self::WholeHouseExtract`] and [`Self::NightVentilation`], or
+    /// `T_out + efficiency*(T_zone - T_out)` for [`Self::Mvhr`].
+    ///
+    /// [`Self::NightVentilation`] purges (`q`, and so `G`, as given by its
+    /// own `ach`) only while `hour_of_day` is within its control window
+    /// *and* the Zone is both above its `setpoint_temperature` and warmer
+    /// than outdoors (there's no point purging a Zone that's already
+    /// cooler than the air it would be traded for); otherwise it
+    /// contributes `(0.0, t_out)`, i.e. nothing.
+    pub fn conductance_and_supply_temperature(
+        &self,
+        zone_volume: Float,
+        t_zone: Float,
+        t_out: Float,
+        rho_air: Float,
+        cp_air: Float,
+        hour_of_day: Float,
+    ) -> (Float, Float) {
+        if let Self::CavityVent {
+            mass_flow,
+            supply_temperature,
+            ..
+        } = self
+        {
+            return (*mass_flow * cp_air, *supply_temperature);
+        }
+        if let Self::NightVentilation {
+            setpoint_temperature,
+            window_start_hour,
+            window_end_hour,
+            ..
+        } = self
+        {
+            let purging = t_zone > *setpoint_temperature
+                && t_zone > t_out
+                && Self::in_window(hour_of_day, *window_start_hour, *window_end_hour);
+            if !purging {
+                return (0.0, t_out);
+            }
+        }
+        let q = self.ach() * zone_volume / 3600.0;
+        let g = rho_air * cp_air * q;
+        let t_supply = match self {
+            Self::Infiltration { .. } | Self::WholeHouseExtract { .. } | Self::NightVentilation { .. } => t_out,
+            Self::Mvhr { efficiency, .. } => t_out + efficiency * (t_zone - t_out),
+            // Handled by the early return above.
+            Self::CavityVent { .. } => unreachable!(),
+        };
+        (g, t_supply)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infiltration_supplies_at_outdoor_temperature() {
+        let element = VentilationElement::Infiltration {
+            zone_index: 0,
+            ach: 0.5,
+        };
+        let (g, t_supply) = element.conductance_and_supply_temperature(100.0, 20.0, -5.0, 1.2, 1000.0, 12.0);
+        let expected_q = 0.5 * 100.0 / 3600.0;
+        assert!((g - 1.2 * 1000.0 * expected_q).abs() < 1e-9);
+        assert_eq!(t_supply, -5.0);
+    }
+
+    #[test]
+    fn mvhr_blends_towards_zone_temperature() {
+        let element = VentilationElement::Mvhr {
+            zone_index: 0,
+            ach: 0.5,
+            efficiency: 0.9,
+            fan_power: 30.0,
+        };
+        let (_, t_supply) = element.conductance_and_supply_temperature(100.0, 20.0, -5.0, 1.2, 1000.0, 12.0);
+        assert_eq!(t_supply, -5.0 + 0.9 * (20.0 - (-5.0)));
+        assert_eq!(element.fan_power(), 30.0);
+    }
+
+    #[test]
+    fn non_mvhr_elements_have_no_fan_power() {
+        let infiltration = VentilationElement::Infiltration {
+            zone_index: 0,
+            ach: 0.5,
+        };
+        let extract = VentilationElement::WholeHouseExtract {
+            zone_index: 0,
+            ach: 0.5,
+        };
+        assert_eq!(infiltration.fan_power(), 0.0);
+        assert_eq!(extract.fan_power(), 0.0);
+    }
+
+    #[test]
+    fn whole_house_extract_has_no_recovery() {
+        let element = VentilationElement::WholeHouseExtract {
+            zone_index: 0,
+            ach: 1.0,
+        };
+        let (_, t_supply) = element.conductance_and_supply_temperature(100.0, 20.0, -5.0, 1.2, 1000.0, 12.0);
+        assert_eq!(t_supply, -5.0);
+    }
+
+    #[test]
+    fn cavity_vent_supplies_its_own_mass_flow_and_temperature_regardless_of_zone_volume() {
+        let element = VentilationElement::CavityVent {
+            zone_index: 0,
+            mass_flow: 0.05,
+            supply_temperature: 45.0,
+        };
+        // Zone volume is irrelevant: the flow comes from the cavity, not an
+        // air-change rate scaled by this Zone's own volume.
+        let (g, t_supply) = element.conductance_and_supply_temperature(1.0, 20.0, -5.0, 1.2, 1000.0, 12.0);
+        assert_eq!(g, 0.05 * 1000.0);
+        assert_eq!(t_supply, 45.0);
+        assert_eq!(element.fan_power(), 0.0);
+    }
+
+    fn night_ventilation() -> VentilationElement {
+        VentilationElement::NightVentilation {
+            zone_index: 0,
+            ach: 4.0,
+            setpoint_temperature: 24.0,
+            window_start_hour: 22.0,
+            window_end_hour: 6.0,
+        }
+    }
+
+    #[test]
+    fn night_ventilation_purges_inside_its_window_when_zone_is_hot() {
+        let element = night_ventilation();
+        // 23:00, zone hotter than both its setpoint and outdoors.
+        let (g, t_supply) = element.conductance_and_supply_temperature(100.0, 28.0, 15.0, 1.2, 1000.0, 23.0);
+        let expected_q = 4.0 * 100.0 / 3600.0;
+        assert!((g - 1.2 * 1000.0 * expected_q).abs() < 1e-9);
+        assert_eq!(t_supply, 15.0);
+    }
+
+    #[test]
+    fn night_ventilation_stays_shut_outside_its_window() {
+        let element = night_ventilation();
+        // Midday: same temperatures as above, but outside the 22-6 window.
+        let (g, t_supply) = element.conductance_and_supply_temperature(100.0, 28.0, 15.0, 1.2, 1000.0, 13.0);
+        assert_eq!(g, 0.0);
+        assert_eq!(t_supply, 15.0);
+    }
+
+    #[test]
+    fn night_ventilation_stays_shut_below_its_setpoint() {
+        let element = night_ventilation();
+        // In-window, but the zone is already below its setpoint.
+        let (g, _) = element.conductance_and_supply_temperature(100.0, 20.0, 15.0, 1.2, 1000.0, 23.0);
+        assert_eq!(g, 0.0);
+    }
+
+    #[test]
+    fn night_ventilation_stays_shut_when_outdoors_is_warmer() {
+        let element = night_ventilation();
+        // In-window and above setpoint, but outdoors offers no cooling.
+        let (g, _) = element.conductance_and_supply_temperature(100.0, 28.0, 30.0, 1.2, 1000.0, 23.0);
+        assert_eq!(g, 0.0);
+    }
+}
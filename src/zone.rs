@@ -25,17 +25,25 @@ use gas_properties::air;
 use simple_model::simulation_state::SimulationStateHeader;
 use simple_model::simulation_state_element::SimulationStateElement;
 
+use crate::duct::VentilationSystem;
 
 
 pub struct ThermalZone {
-    
+
     /// The `Space` that this [`Thermal Zone`] represents
     pub reference_space: Rc<Space>,
 
-    
+
     /// volume of the zone
     volume: f64,
-    
+
+    /// The ductwork (and, optionally, heat recovery) carrying this
+    /// Zone's mechanically supplied/ventilated air in, if any. When
+    /// `None`, ventilation air is assumed to be delivered at exactly
+    /// `reference_space.ventilation_temperature()`, with no distribution
+    /// losses.
+    pub ventilation_duct: Option<VentilationSystem>,
+
 }
 
 impl ThermalZone {
@@ -43,22 +51,30 @@ impl ThermalZone {
     /// It will copy the index of the space, so it should be used
     /// by iterating the spaces in a model (so there is no mismatch).
     pub fn from_space(space: &Rc<Space>, state: &mut SimulationStateHeader, space_index: usize) -> Self {
-        
+
         let volume = *space.volume().unwrap();
         // Add Space Temperature state
         let state_index = state.push(
             // start, by default, at 22.0 C
-            SimulationStateElement::SpaceDryBulbTemperature(space_index), 
+            SimulationStateElement::SpaceDryBulbTemperature(space_index),
             22.0
         );
         space.set_dry_bulb_temperature_index(state_index);
 
-        ThermalZone {            
+        ThermalZone {
             reference_space: Rc::clone(space),
             volume,
+            ventilation_duct: None,
         }
     }
 
+    /// Attaches a [`VentilationSystem`] to this Zone, so its mechanically
+    /// supplied/ventilated air is corrected for duct (and, optionally,
+    /// heat-recovery) losses before entering the heat balance.
+    pub fn set_ventilation_duct(&mut self, duct: VentilationSystem) {
+        self.ventilation_duct = Some(duct);
+    }
+
     /// Retrieves the heat capacity of the ThermalZone's air
     pub fn mcp(&self) -> f64 {
         let air_density = air::density(); //kg/m3
@@ -67,5 +83,10 @@ impl ThermalZone {
         self.volume * air_density * air_specific_heat
     }
 
-    
+    /// Retrieves the volume of the ThermalZone (m3)
+    pub fn volume(&self) -> f64 {
+        self.volume
+    }
+
+
 }
@@ -0,0 +1,71 @@
+/*
+MIT License
+Copyright (c) 2021 Germán Molina
+Permission is hereby granted, free of charge, to any person obtaining a copy
+of this software and associated documentation files (the "Software"), to deal
+in the Software without restriction, including without limitation the rights
+to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+copies of the Software, and to permit persons to whom the Software is
+furnished to do so, subject to the following conditions:
+The above copyright notice and this permission notice shall be included in all
+copies or substantial portions of the Software.
+THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
+AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+SOFTWARE.
+*/
+
+use simple_model::{SimpleModel, ZoneMixing};
+use std::rc::Rc;
+
+/// An air-mass-flow link between two [`simple_model::Space`]s (e.g. an open
+/// door, a transfer-air grille, or a coupled-space connection), from the
+/// point of view of the thermal model.
+///
+/// Unlike infiltration and ventilation—which exchange air with the
+/// outdoors—a [`ThermalZoneMixing`] exchanges air between two Zones, so it
+/// contributes to both zones' heat balance from the single mass flow
+/// reported by its parent.
+pub struct ThermalZoneMixing {
+    /// The parent ZoneMixing
+    pub(crate) parent: Rc<ZoneMixing>,
+
+    /// The index (into [`crate::model::ThermalModel::zones`]) of one side of
+    /// this link
+    pub(crate) zone_a_index: usize,
+
+    /// The index of the other side of this link
+    pub(crate) zone_b_index: usize,
+}
+
+impl ThermalZoneMixing {
+    /// Builds a new [`ThermalZoneMixing`] from a ZoneMixing and the model
+    /// that contains it, resolving the two Spaces it connects into indices.
+    pub fn from(mix: &Rc<ZoneMixing>, model: &SimpleModel) -> Result<Self, String> {
+        let parent = Rc::clone(mix);
+
+        let find_space = |space_name: &str| -> Result<usize, String> {
+            for (i, s) in model.spaces.iter().enumerate() {
+                if s.name() == space_name {
+                    return Ok(i);
+                }
+            }
+            Err(format!(
+                "ZoneMixing is supposed to connect a space called '{}'... but it was not found",
+                space_name
+            ))
+        };
+
+        let zone_a_index = find_space(parent.zone_a()?)?;
+        let zone_b_index = find_space(parent.zone_b()?)?;
+
+        Ok(Self {
+            parent,
+            zone_a_index,
+            zone_b_index,
+        })
+    }
+}
@@ -0,0 +1,190 @@
+//! Checks [`ZoneCouplingScheme::Adaptive`] (the embedded Dormand-Prince
+//! RK45 zone-air marcher) against the same closed-form single-zone
+//! solution the sibling `validate_wall_heat_transfer` harness uses for
+//! [`ZoneCouplingScheme::Staged`], at both a tight and a loose tolerance.
+
+use calendar::Date;
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+use heat::ode::DormandPrinceOptions;
+use heat::Float;
+use schedule::ScheduleConstant;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+/// A single-zone test model with walls assumed to have no mass, matching
+/// `validate_wall_heat_transfer`'s model of the same name: it has a closed
+/// solution to `dT/dt = (a - b*T)/c`, which is what
+/// [`ZoneCouplingScheme::Adaptive`] is itself integrating.
+#[derive(Default)]
+struct SingleZoneTestModel {
+    zone_volume: Float,
+    surface_area: Float,
+    facade_r: Float,
+    temp_out: Float,
+    temp_start: Float,
+}
+
+impl SingleZoneTestModel {
+    fn get_closed_solution(&self) -> Box<impl Fn(Float) -> Float> {
+        let air = heat::gas::AIR;
+        let rho = air.density(22. + 273.15);
+        let cp = air.heat_capacity(22. + 273.15);
+        let u = 1. / self.facade_r;
+
+        let c = self.zone_volume * rho * cp;
+        let a = self.temp_out * u * self.surface_area;
+        let b = u * self.surface_area;
+
+        let k1 = self.temp_start - a / b;
+        let f = move |t: Float| -> Float { a / b + k1 * (-b * t / c).exp() };
+        Box::new(f)
+    }
+}
+
+/// Marches the no-mass, no-window single-zone test building under
+/// [`ZoneCouplingScheme::Adaptive`] with the given tolerance options, and
+/// returns `(expected, found)` zone temperature series against the
+/// analytic closed solution.
+fn march_adaptive(options: DormandPrinceOptions) -> (Vec<Float>, Vec<Float>) {
+    let zone_volume = 40.;
+    let surface_width = 2.;
+    let surface_height = 2.;
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume,
+        surface_height,
+        surface_width,
+        construction: vec![TestMat::Polyurethane(0.02)],
+        emissivity: 0.0,
+        ..Default::default()
+    });
+
+    let n: usize = 60;
+    let main_dt = 60. * 60. / n as Float;
+    let mut thermal_model = ThermalModel::new(
+        &META_OPTIONS,
+        ZoneCouplingScheme::Adaptive { options },
+        &simple_model,
+        &mut state_header,
+        n,
+    )
+    .unwrap();
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    let hs_front = 10.;
+    let hs_back = 10.;
+    thermal_model.surfaces[0].front_hs = Some(hs_front);
+    thermal_model.surfaces[0].back_hs = Some(hs_back);
+
+    let r = thermal_model.surfaces[0].discretization.r_value() + 1. / hs_front + 1. / hs_back;
+
+    let t_start = thermal_model.zones[0]
+        .reference_space
+        .dry_bulb_temperature(&state)
+        .unwrap();
+    let t_out: Float = 30.0;
+
+    let tester = SingleZoneTestModel {
+        zone_volume,
+        surface_area: surface_height * surface_width,
+        facade_r: r,
+        temp_out: t_out,
+        temp_start: t_start,
+    };
+    let exp_fn = tester.get_closed_solution();
+
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(t_out));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let mut date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+
+    let n_steps = 1000;
+    let mut exp = Vec::with_capacity(n_steps);
+    let mut found = Vec::with_capacity(n_steps);
+    for i in 0..n_steps {
+        let time = (i as Float) * main_dt;
+        date.add_seconds(time);
+
+        let found_v = thermal_model.zones[0]
+            .reference_space
+            .dry_bulb_temperature(&state)
+            .unwrap();
+
+        thermal_model
+            .march(date, &weather, &simple_model, &mut state, &mut memory)
+            .unwrap();
+
+        exp.push(exp_fn(time));
+        found.push(found_v);
+    }
+
+    (exp, found)
+}
+
+fn max_abs_error(expected: &[Float], found: &[Float]) -> Float {
+    expected
+        .iter()
+        .zip(found)
+        .map(|(e, f)| (e - f).abs())
+        .fold(0.0, Float::max)
+}
+
+#[test]
+fn adaptive_scheme_matches_closed_solution_at_tight_tolerance() {
+    let options = DormandPrinceOptions {
+        atol: 1e-8,
+        rtol: 1e-8,
+        min_step: 0.01,
+        max_step: 60.,
+    };
+    let (expected, found) = march_adaptive(options);
+    let err = max_abs_error(&expected, &found);
+    assert!(err < 1e-3, "max error {err} too large at tight tolerance");
+}
+
+/// A looser tolerance should still track the closed solution reasonably
+/// well (it governs the embedded RK45's own local error, not the coupling
+/// between the surface and zone air passes), just with more slack than
+/// the tight-tolerance case above.
+#[test]
+fn adaptive_scheme_matches_closed_solution_at_loose_tolerance() {
+    let options = DormandPrinceOptions {
+        atol: 1e-2,
+        rtol: 1e-2,
+        min_step: 0.01,
+        max_step: 60.,
+    };
+    let (expected, found) = march_adaptive(options);
+    let err = max_abs_error(&expected, &found);
+    assert!(err < 0.5, "max error {err} too large at loose tolerance");
+}
+
+/// Same check as the tight-tolerance case above, but built through
+/// [`ZoneCouplingScheme::adaptive`] instead of a hand-assembled
+/// [`DormandPrinceOptions`], since that's the constructor most callers
+/// should reach for.
+#[test]
+fn adaptive_convenience_constructor_matches_closed_solution() {
+    let scheme = ZoneCouplingScheme::adaptive(1e-8, 1e-8, 0.01, 60.);
+    let options = match scheme {
+        ZoneCouplingScheme::Adaptive { options } => options,
+        _ => unreachable!(),
+    };
+    let (expected, found) = march_adaptive(options);
+    let err = max_abs_error(&expected, &found);
+    assert!(err < 1e-3, "max error {err} too large at tight tolerance");
+}
@@ -0,0 +1,282 @@
+//! BESTEST-style (ASHRAE Standard 140) envelope cases: a single-zone box
+//! of a lightweight or heavyweight construction, either free-floating or
+//! held to a fixed heating setpoint by an idealized heater, marched under
+//! constant outdoor conditions and checked against an acceptance band.
+//!
+//! The real BESTEST suite drives these cases with a full year of hourly
+//! weather and checks the results against published reference ranges from
+//! a panel of simulation programs; neither the weather file nor those
+//! reference ranges are available here, so this harness instead reuses
+//! the repo's existing `SyntheticWeather`/`get_single_zone_test_building`
+//! fixtures (the same ones the sibling `validate_*` files already use)
+//! under constant weather, with the acceptance band derived analytically
+//! from each case's own lumped heat balance. It is meant as a repeatable
+//! regression harness for this crate's convection/IR/conduction code
+//! paths, not a certified BESTEST conformance run.
+
+use calendar::Date;
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+use heat::Float;
+use schedule::ScheduleConstant;
+use simple_model::HVAC;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+/// One BESTEST-style envelope case: a single-zone box, marched under
+/// constant outdoor conditions for `duration_hours`.
+struct EnvelopeCase {
+    /// Zone air volume (m3)
+    zone_volume: Float,
+    /// Facade height and width (m)
+    surface_height: Float,
+    surface_width: Float,
+    /// Wall construction—lightweight (e.g. `TestMat::Polyurethane`) or
+    /// heavyweight (e.g. `TestMat::Concrete`)
+    construction: Vec<TestMat>,
+    /// Outdoor dry-bulb temperature, held constant for the whole run (C)
+    outdoor_temperature: Float,
+    /// Starting zone air temperature (C)
+    start_temperature: Float,
+    /// If `Some`, an idealized, capacity-unlimited heater supplies exactly
+    /// the power needed each step to bring the zone to this temperature
+    /// (see [`run_case`]). If `None`, the zone free-floats.
+    heating_setpoint: Option<Float>,
+    /// How many hours to march
+    duration_hours: Float,
+    /// Number of substeps per hour
+    substeps_per_hour: usize,
+}
+
+/// Peak/minimum zone air temperature and total heating energy delivered
+/// over an [`EnvelopeCase`] run.
+struct CaseResult {
+    peak_temperature: Float,
+    min_temperature: Float,
+    /// Total heating energy delivered (Wh); zero for free-floating cases.
+    heating_energy_wh: Float,
+}
+
+/// An inclusive `[low, high]` band a [`CaseResult`] field is expected to
+/// fall within.
+struct AcceptanceBand {
+    low: Float,
+    high: Float,
+}
+
+impl AcceptanceBand {
+    fn contains(&self, value: Float) -> bool {
+        value >= self.low && value <= self.high
+    }
+}
+
+/// Runs an [`EnvelopeCase`] and reports its [`CaseResult`].
+fn run_case(case: EnvelopeCase) -> CaseResult {
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume: case.zone_volume,
+        surface_height: case.surface_height,
+        surface_width: case.surface_width,
+        construction: case.construction,
+        emissivity: 0.0,
+        ..Default::default()
+    });
+
+    let n = case.substeps_per_hour;
+    let dt = 60. * 60. / n as Float;
+    let mut thermal_model =
+        ThermalModel::new(&META_OPTIONS, ZoneCouplingScheme::Staged, &simple_model, &mut state_header, n)
+            .unwrap();
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    thermal_model.zones[0]
+        .reference_space
+        .set_dry_bulb_temperature(&mut state, case.start_temperature)
+        .unwrap();
+
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(case.outdoor_temperature));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let hvac_state_i = match &simple_model.hvacs[0] {
+        HVAC::ElectricHeater(heater) => heater.heating_cooling_consumption_index().unwrap(),
+        _ => panic!("expected get_single_zone_test_building to wire in an ElectricHeater"),
+    };
+
+    // The zone air's thermal capacitance, used to size the idealized
+    // heater below—same rho*cp*volume lumping `SingleZoneTestModel` (in
+    // the sibling `validate_wall_heat_transfer.rs`) uses for its own
+    // closed-form solutions.
+    let air = heat::gas::AIR;
+    let rho = air.density(22. + 273.15);
+    let cp = air.heat_capacity(22. + 273.15);
+    let c_zone_air = case.zone_volume * rho * cp;
+
+    let mut date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+    let n_steps = (case.duration_hours * n as Float) as usize;
+
+    let mut peak_temperature = case.start_temperature;
+    let mut min_temperature = case.start_temperature;
+    let mut heating_energy_wh = 0.0;
+
+    for _ in 0..n_steps {
+        let t = thermal_model.zones[0]
+            .reference_space
+            .dry_bulb_temperature(&state)
+            .unwrap();
+        peak_temperature = peak_temperature.max(t);
+        min_temperature = min_temperature.min(t);
+
+        if let Some(setpoint) = case.heating_setpoint {
+            // An idealized, capacity-unlimited heater: exactly the power
+            // that would bring the zone air to `setpoint` by the end of
+            // this step, ignoring what the walls/infiltration do meanwhile.
+            let heating_power = if t < setpoint {
+                (setpoint - t) * c_zone_air / dt
+            } else {
+                0.0
+            };
+            state[hvac_state_i] = heating_power;
+            heating_energy_wh += heating_power * dt / 3600.0;
+        }
+
+        thermal_model
+            .march(date, &weather, &simple_model, &mut state, &mut memory)
+            .unwrap();
+        date.add_seconds(dt);
+    }
+
+    CaseResult {
+        peak_temperature,
+        min_temperature,
+        heating_energy_wh,
+    }
+}
+
+/// A free-floating lightweight box should settle near the outdoor
+/// temperature, since there is no mass to delay it and no internal gains
+/// or solar to hold it above outdoor conditions.
+#[test]
+fn free_floating_lightweight() {
+    let outdoor_temperature = 2.0;
+    let result = run_case(EnvelopeCase {
+        zone_volume: 40.,
+        surface_height: 2.,
+        surface_width: 2.,
+        construction: vec![TestMat::Polyurethane(0.02)],
+        outdoor_temperature,
+        start_temperature: 20.0,
+        heating_setpoint: None,
+        duration_hours: 48.0,
+        substeps_per_hour: 6,
+    });
+
+    let band = AcceptanceBand {
+        low: outdoor_temperature - 0.5,
+        high: outdoor_temperature + 0.5,
+    };
+    assert!(
+        band.contains(result.min_temperature),
+        "min temperature {} outside [{}, {}]",
+        result.min_temperature,
+        band.low,
+        band.high
+    );
+    assert_eq!(result.heating_energy_wh, 0.0);
+}
+
+/// A free-floating heavyweight box's thermal mass should damp it closer
+/// to the starting temperature than the lightweight case over the same
+/// short window, since it takes longer to discharge its stored heat.
+#[test]
+fn free_floating_heavyweight_lags_lightweight() {
+    let outdoor_temperature = 2.0;
+    let start_temperature = 20.0;
+
+    let light = run_case(EnvelopeCase {
+        zone_volume: 40.,
+        surface_height: 2.,
+        surface_width: 2.,
+        construction: vec![TestMat::Polyurethane(0.02)],
+        outdoor_temperature,
+        start_temperature,
+        heating_setpoint: None,
+        duration_hours: 6.0,
+        substeps_per_hour: 6,
+    });
+
+    let heavy = run_case(EnvelopeCase {
+        zone_volume: 40.,
+        surface_height: 2.,
+        surface_width: 2.,
+        construction: vec![TestMat::Concrete(0.2)],
+        outdoor_temperature,
+        start_temperature,
+        heating_setpoint: None,
+        duration_hours: 6.0,
+        substeps_per_hour: 6,
+    });
+
+    assert!(
+        heavy.min_temperature > light.min_temperature,
+        "heavyweight box (min {}) should lag the lightweight box (min {}) towards outdoor conditions",
+        heavy.min_temperature,
+        light.min_temperature
+    );
+}
+
+/// A heated box, lightweight or heavyweight, should be held close to its
+/// setpoint by the idealized heater in [`run_case`].
+#[test]
+fn heated_boxes_hold_setpoint() {
+    let setpoint = 20.0;
+    let band = AcceptanceBand {
+        low: setpoint - 0.5,
+        high: setpoint + 0.5,
+    };
+
+    for construction in [
+        vec![TestMat::Polyurethane(0.02)],
+        vec![TestMat::Concrete(0.2)],
+    ] {
+        let result = run_case(EnvelopeCase {
+            zone_volume: 40.,
+            surface_height: 2.,
+            surface_width: 2.,
+            construction,
+            outdoor_temperature: -10.0,
+            start_temperature: setpoint,
+            heating_setpoint: Some(setpoint),
+            duration_hours: 48.0,
+            substeps_per_hour: 6,
+        });
+
+        assert!(
+            band.contains(result.min_temperature),
+            "min temperature {} outside [{}, {}]",
+            result.min_temperature,
+            band.low,
+            band.high
+        );
+        assert!(
+            band.contains(result.peak_temperature),
+            "peak temperature {} outside [{}, {}]",
+            result.peak_temperature,
+            band.low,
+            band.high
+        );
+        assert!(result.heating_energy_wh > 0.0);
+    }
+}
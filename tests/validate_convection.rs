@@ -1,17 +1,111 @@
 use geometry3d::Vector3D;
-use heat::convection::ConvectionParams;
+use heat::convection::{AdaptiveConvection, ConvectionParams, MoWittConstants};
 use heat::surface::is_windward;
 use heat::Float;
 use validate::*;
 
-fn get_validator(expected: Vec<f64>, found: Vec<f64>) -> Box<dyn Validate> {
+/// Pass/fail tolerances checked by [`get_validator`], following the
+/// hourly-data calibration criteria in ASHRAE Guideline 14: a series is
+/// accepted if its CV(RMSE) and NMBE (as percentages) both fall within
+/// these bounds. `None` disables the corresponding check.
+#[derive(Debug, Clone, Copy)]
+struct GofThresholds {
+    /// Maximum allowed CV(RMSE), in percent
+    max_cvrmse: Option<Float>,
+    /// Maximum allowed `|NMBE|`, in percent
+    max_nmbe: Option<Float>,
+}
+
+impl Default for GofThresholds {
+    fn default() -> Self {
+        // ASHRAE Guideline 14 hourly-calibration tolerances
+        Self {
+            max_cvrmse: Some(30.),
+            max_nmbe: Some(10.),
+        }
+    }
+}
+
+/// Goodness-of-fit statistics between an `expected` and a `found` series,
+/// as computed by [`goodness_of_fit`].
+#[derive(Debug, Clone, Copy)]
+struct GoodnessOfFit {
+    /// Root-mean-square error
+    rmse: Float,
+    /// Mean bias error, `mean(found - expected)`
+    mbe: Float,
+    /// RMSE normalized by `mean(expected)`, as a percentage
+    cvrmse: Float,
+    /// MBE normalized by `mean(expected)`, as a percentage
+    nmbe: Float,
+    /// Coefficient of determination
+    r2: Float,
+}
+
+/// Computes [`GoodnessOfFit`] statistics (RMSE, MBE, CV(RMSE), NMBE, R²)
+/// between `expected` and `found`.
+fn goodness_of_fit(expected: &[f64], found: &[f64]) -> GoodnessOfFit {
+    let n = expected.len() as Float;
+    let mean_expected = expected.iter().sum::<Float>() / n;
+
+    let mut sse = 0.;
+    let mut bias = 0.;
+    let mut ss_tot = 0.;
+    for (e, f) in expected.iter().zip(found.iter()) {
+        let err = f - e;
+        sse += err * err;
+        bias += err;
+        let dev = e - mean_expected;
+        ss_tot += dev * dev;
+    }
+    let rmse = (sse / n).sqrt();
+    let mbe = bias / n;
+    let cvrmse = 100. * rmse / mean_expected.abs();
+    let nmbe = 100. * mbe / mean_expected.abs();
+    let r2 = if ss_tot > 1e-12 { 1. - sse / ss_tot } else { 1. };
+
+    GoodnessOfFit {
+        rmse,
+        mbe,
+        cvrmse,
+        nmbe,
+        r2,
+    }
+}
+
+fn get_validator(
+    expected: Vec<f64>,
+    found: Vec<f64>,
+    found_legend: &'static str,
+    thresholds: GofThresholds,
+) -> Box<dyn Validate> {
+    let gof = goodness_of_fit(&expected, &found);
+    eprintln!(
+        "{found_legend}: RMSE={:.3} MBE={:.3} CV(RMSE)={:.1}% NMBE={:.1}% R2={:.4}",
+        gof.rmse, gof.mbe, gof.cvrmse, gof.nmbe, gof.r2
+    );
+    if let Some(max_cvrmse) = thresholds.max_cvrmse {
+        assert!(
+            gof.cvrmse.abs() <= max_cvrmse,
+            "{found_legend}: CV(RMSE) of {:.1}% exceeds the {max_cvrmse}% tolerance",
+            gof.cvrmse
+        );
+    }
+    if let Some(max_nmbe) = thresholds.max_nmbe {
+        assert!(
+            gof.nmbe.abs() <= max_nmbe,
+            "{found_legend}: NMBE of {:.1}% exceeds the {max_nmbe}% tolerance",
+            gof.nmbe
+        );
+    }
+
     Box::new(SeriesValidator {
         x_label: Some("time step"),
         y_label: Some("Convection Coefficient"),
         y_units: Some("W/m2.K"),
 
         expected_legend: Some("EnergyPlus (TARP)"),
-        found_legend: Some("SIMPLE"),
+        found_legend: Some(found_legend),
         expected,
         found,
         ..validate::SeriesValidator::default()
@@ -30,12 +124,36 @@ fn get_validator(expected: Vec<f64>, found: Vec<f64>) -> Box<dyn Validate> {
     // })
 }
 
+/// The inside-face convection coefficients found by SIMPLE: the TARP
+/// natural-convection fit, the coefficient [`AdaptiveConvection`]'s
+/// default table auto-selects (which, by default, is also TARP—see
+/// `adaptive()` in `vertical`/`tilted`/`horizontal`), and the other
+/// published interior natural-convection correlations.
+struct FoundInsideConvection {
+    tarp: Vec<Float>,
+    adaptive: Vec<Float>,
+    alamdari_hammond: Vec<Float>,
+    fohanno_polidori: Vec<Float>,
+    khalifa_marshall: Vec<Float>,
+}
+
+/// The outside-face convection coefficients found by SIMPLE, one series per
+/// model, plus the coefficient [`AdaptiveConvection`]'s default table
+/// auto-selects.
+struct FoundOutsideConvection {
+    tarp: Vec<Float>,
+    mowitt: Vec<Float>,
+    doe2: Vec<Float>,
+    adaptive: Vec<Float>,
+}
+
 fn calc_convection(
     dir: &'static str,
     area: Float,
     perimeter: Float,
+    characteristic_length: Float,
     normal: Vector3D,
-) -> (Vec<Float>, Vec<Float>, Vec<Float>, Vec<Float>) {
+) -> (Vec<Float>, FoundInsideConvection, Vec<Float>, FoundOutsideConvection) {
     let path_string = format!("./tests/{}/eplusout.csv", dir);
     let path = path_string.as_str();
     let cols = validate::from_csv(path, &[1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
@@ -53,10 +171,20 @@ fn calc_convection(
                                // let outdoor_thermal_heat_gain = &cols[10];   // 11	WALL EXTERIOR:Surface Outside Face Net Thermal Radiation Heat Gain Rate [W](TimeStep)
     let zone_air_temp = &cols[11]; // 12	INTERIOR SPACE:Zone Mean Air Temperature [C](TimeStep)
 
+    let mowitt_constants = MoWittConstants::default();
+    let adaptive_table = AdaptiveConvection::default();
+
     let cos_tilt = normal * Vector3D::new(0., 0., 1.);
     let n = outdoor_temp.len();
-    let mut found_hs_in = Vec::with_capacity(n);
-    let mut found_hs_out = Vec::with_capacity(n);
+    let mut found_hs_in_tarp = Vec::with_capacity(n);
+    let mut found_hs_in_adaptive = Vec::with_capacity(n);
+    let mut found_hs_in_alamdari_hammond = Vec::with_capacity(n);
+    let mut found_hs_in_fohanno_polidori = Vec::with_capacity(n);
+    let mut found_hs_in_khalifa_marshall = Vec::with_capacity(n);
+    let mut found_hs_out_tarp = Vec::with_capacity(n);
+    let mut found_hs_out_mowitt = Vec::with_capacity(n);
+    let mut found_hs_out_doe2 = Vec::with_capacity(n);
+    let mut found_hs_out_adaptive = Vec::with_capacity(n);
     for i in 0..n {
         let env_in = ConvectionParams {
             air_temperature: zone_air_temp[i],
@@ -77,20 +205,53 @@ fn calc_convection(
 
         let windward = is_windward(site_wind_direction[i].to_radians(), cos_tilt, normal);
 
-        found_hs_in.push(env_in.get_tarp_natural_convection_coefficient());
-        found_hs_out.push(env_out.get_tarp_convection_coefficient(area, perimeter, windward))
+        found_hs_in_tarp.push(env_in.get_tarp_natural_convection_coefficient());
+        found_hs_in_adaptive
+            .push(env_in.get_adaptive_interior_convection_coefficient(&adaptive_table).0);
+        found_hs_in_alamdari_hammond
+            .push(env_in.get_alamdari_hammond_coefficient(characteristic_length));
+        found_hs_in_fohanno_polidori.push(env_in.get_fohanno_polidori_coefficient());
+        found_hs_in_khalifa_marshall.push(env_in.get_khalifa_marshall_coefficient());
+        found_hs_out_tarp.push(env_out.get_tarp_convection_coefficient(area, perimeter, windward));
+        found_hs_out_mowitt
+            .push(env_out.get_mowitt_convection_coefficient(&mowitt_constants, windward));
+        found_hs_out_doe2
+            .push(env_out.get_doe2_convection_coefficient(&mowitt_constants, windward));
+        found_hs_out_adaptive.push(
+            env_out
+                .get_adaptive_exterior_convection_coefficient(&adaptive_table, area, perimeter, windward)
+                .0,
+        );
     }
 
     (
         exp_hs_in.clone(),
-        found_hs_in,
+        FoundInsideConvection {
+            tarp: found_hs_in_tarp,
+            adaptive: found_hs_in_adaptive,
+            alamdari_hammond: found_hs_in_alamdari_hammond,
+            fohanno_polidori: found_hs_in_fohanno_polidori,
+            khalifa_marshall: found_hs_in_khalifa_marshall,
+        },
         exp_hs_out.clone(),
-        found_hs_out,
+        FoundOutsideConvection {
+            tarp: found_hs_out_tarp,
+            mowitt: found_hs_out_mowitt,
+            doe2: found_hs_out_doe2,
+            adaptive: found_hs_out_adaptive,
+        },
     )
 }
 
 const AREA: Float = 20. * 3.;
 const PERIMETER: Float = (20. + 3.) * 2.; //30.9838667697;
+/// Wall height, i.e. the `H` used by [`heat::convection::ConvectionParams::get_alamdari_hammond_coefficient`]
+/// for a vertical or tilted surface.
+const WALL_HEIGHT: Float = 3.;
+/// `area/perimeter`, i.e. the `L` used by
+/// [`heat::convection::ConvectionParams::get_alamdari_hammond_coefficient`]
+/// for a horizontal surface.
+const HORIZONTAL_LENGTH: Float = AREA / PERIMETER;
 fn vertical(validations: &mut Validator) {
     /// Heat Transfer Coefficients calculated in SIMPLE, compared to those calculated by the TARP model in EnergyPlus
     #[valid(Vertical Wall - Natural (i.e., Interior) Convection Coefficient )]
@@ -99,25 +260,140 @@ fn vertical(validations: &mut Validator) {
             "massive_full",
             AREA,
             PERIMETER,
+            WALL_HEIGHT,
             Vector3D::new(0., -1., 0.), // South
         );
-        get_validator(expected_in, found_in)
+        get_validator(expected_in, found_in.tarp, "SIMPLE", GofThresholds::default())
     }
 
-    /// Heat Transfer Coefficients calculated in SIMPLE, compared to those calculated by the TARP model in EnergyPlus
-    #[valid(Vertical Wall - Forced (i.e., Exterior) Convection Coefficient )]
-    fn forced() -> Box<dyn Validate> {
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Natural (i.e., Interior) Convection Coefficient - Adaptive )]
+    fn natural_adaptive() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(expected_in, found_in.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (TARP), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Forced (i.e., Exterior) Convection Coefficient - TARP )]
+    fn forced_tarp() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(expected_out, found_out.tarp, "SIMPLE (TARP)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (MoWiTT), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Forced (i.e., Exterior) Convection Coefficient - MoWiTT )]
+    fn forced_mowitt() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(expected_out, found_out.mowitt, "SIMPLE (MoWiTT)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (DOE-2), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Forced (i.e., Exterior) Convection Coefficient - DOE-2 )]
+    fn forced_doe2() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(expected_out, found_out.doe2, "SIMPLE (DOE-2)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Forced (i.e., Exterior) Convection Coefficient - Adaptive )]
+    fn forced_adaptive() -> Box<dyn Validate> {
         let (.., expected_out, found_out) = calc_convection(
             "massive_full",
             AREA,
             PERIMETER,
+            WALL_HEIGHT,
             Vector3D::new(0., -1., 0.), // South
         );
-        get_validator(expected_out, found_out)
+        get_validator(expected_out, found_out.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Alamdari-Hammond), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Natural (i.e., Interior) Convection Coefficient - Alamdari-Hammond )]
+    fn natural_alamdari_hammond() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(
+            expected_in,
+            found_in.alamdari_hammond,
+            "SIMPLE (Alamdari-Hammond)",
+            GofThresholds::default(),
+        )
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Fohanno-Polidori), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Natural (i.e., Interior) Convection Coefficient - Fohanno-Polidori )]
+    fn natural_fohanno_polidori() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(
+            expected_in,
+            found_in.fohanno_polidori,
+            "SIMPLE (Fohanno-Polidori)",
+            GofThresholds::default(),
+        )
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Khalifa-Marshall), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Vertical Wall - Natural (i.e., Interior) Convection Coefficient - Khalifa-Marshall )]
+    fn natural_khalifa_marshall() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "massive_full",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 0.), // South
+        );
+        get_validator(
+            expected_in,
+            found_in.khalifa_marshall,
+            "SIMPLE (Khalifa-Marshall)",
+            GofThresholds::default(),
+        )
     }
 
     validations.push(natural());
-    validations.push(forced());
+    validations.push(natural_adaptive());
+    validations.push(natural_alamdari_hammond());
+    validations.push(natural_fohanno_polidori());
+    validations.push(natural_khalifa_marshall());
+    validations.push(forced_tarp());
+    validations.push(forced_mowitt());
+    validations.push(forced_doe2());
+    validations.push(forced_adaptive());
 }
 
 fn tilted(validations: &mut Validator) {
@@ -128,25 +404,83 @@ fn tilted(validations: &mut Validator) {
             "tilted",
             AREA,
             PERIMETER,
+            WALL_HEIGHT,
             Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
         );
-        get_validator(expected_in, found_in)
+        get_validator(expected_in, found_in.tarp, "SIMPLE", GofThresholds::default())
     }
 
-    /// Heat Transfer Coefficients calculated in SIMPLE, compared to those calculated by the TARP model in EnergyPlus
-    #[valid(Tilted Wall - Forced (i.e., Exterior) Convection Coefficient )]
-    fn forced() -> Box<dyn Validate> {
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Tilted Wall - Natural (i.e., Interior) Convection Coefficient - Adaptive )]
+    fn natural_adaptive() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "tilted",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_in, found_in.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (TARP), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Tilted Wall - Forced (i.e., Exterior) Convection Coefficient - TARP )]
+    fn forced_tarp() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "tilted",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.tarp, "SIMPLE (TARP)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (MoWiTT), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Tilted Wall - Forced (i.e., Exterior) Convection Coefficient - MoWiTT )]
+    fn forced_mowitt() -> Box<dyn Validate> {
         let (.., expected_out, found_out) = calc_convection(
             "tilted",
             AREA,
             PERIMETER,
+            WALL_HEIGHT,
             Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
         );
-        get_validator(expected_out, found_out)
+        get_validator(expected_out, found_out.mowitt, "SIMPLE (MoWiTT)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (DOE-2), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Tilted Wall - Forced (i.e., Exterior) Convection Coefficient - DOE-2 )]
+    fn forced_doe2() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "tilted",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.doe2, "SIMPLE (DOE-2)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Tilted Wall - Forced (i.e., Exterior) Convection Coefficient - Adaptive )]
+    fn forced_adaptive() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "tilted",
+            AREA,
+            PERIMETER,
+            WALL_HEIGHT,
+            Vector3D::new(0., -1., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
     }
 
     validations.push(natural());
-    validations.push(forced());
+    validations.push(natural_adaptive());
+    validations.push(forced_tarp());
+    validations.push(forced_mowitt());
+    validations.push(forced_doe2());
+    validations.push(forced_adaptive());
 }
 
 fn horizontal(validations: &mut Validator) {
@@ -157,25 +491,140 @@ fn horizontal(validations: &mut Validator) {
             "horizontal",
             AREA,
             PERIMETER,
+            HORIZONTAL_LENGTH,
             Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
         );
-        get_validator(expected_in, found_in)
+        get_validator(expected_in, found_in.tarp, "SIMPLE", GofThresholds::default())
     }
 
-    /// Heat Transfer Coefficients calculated in SIMPLE, compared to those calculated by the TARP model in EnergyPlus
-    #[valid(Horizontal Wall - Forced (i.e., Exterior) Convection Coefficient )]
-    fn forced() -> Box<dyn Validate> {
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Natural (i.e., Interior) Convection Coefficient - Adaptive )]
+    fn natural_adaptive() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_in, found_in.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (TARP), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Forced (i.e., Exterior) Convection Coefficient - TARP )]
+    fn forced_tarp() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.tarp, "SIMPLE (TARP)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (MoWiTT), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Forced (i.e., Exterior) Convection Coefficient - MoWiTT )]
+    fn forced_mowitt() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.mowitt, "SIMPLE (MoWiTT)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (DOE-2), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Forced (i.e., Exterior) Convection Coefficient - DOE-2 )]
+    fn forced_doe2() -> Box<dyn Validate> {
         let (.., expected_out, found_out) = calc_convection(
             "horizontal",
             AREA,
             PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.doe2, "SIMPLE (DOE-2)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (auto-selected via AdaptiveConvection), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Forced (i.e., Exterior) Convection Coefficient - Adaptive )]
+    fn forced_adaptive() -> Box<dyn Validate> {
+        let (.., expected_out, found_out) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(expected_out, found_out.adaptive, "SIMPLE (Adaptive)", GofThresholds::default())
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Alamdari-Hammond), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Natural (i.e., Interior) Convection Coefficient - Alamdari-Hammond )]
+    fn natural_alamdari_hammond() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(
+            expected_in,
+            found_in.alamdari_hammond,
+            "SIMPLE (Alamdari-Hammond)",
+            GofThresholds::default(),
+        )
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Fohanno-Polidori), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Natural (i.e., Interior) Convection Coefficient - Fohanno-Polidori )]
+    fn natural_fohanno_polidori() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
+            Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
+        );
+        get_validator(
+            expected_in,
+            found_in.fohanno_polidori,
+            "SIMPLE (Fohanno-Polidori)",
+            GofThresholds::default(),
+        )
+    }
+
+    /// Heat Transfer Coefficients calculated in SIMPLE (Khalifa-Marshall), compared to those calculated by the TARP model in EnergyPlus
+    #[valid(Horizontal Wall - Natural (i.e., Interior) Convection Coefficient - Khalifa-Marshall )]
+    fn natural_khalifa_marshall() -> Box<dyn Validate> {
+        let (expected_in, found_in, ..) = calc_convection(
+            "horizontal",
+            AREA,
+            PERIMETER,
+            HORIZONTAL_LENGTH,
             Vector3D::new(0., 0., 1.).get_normalized(), // South, tilted
         );
-        get_validator(expected_out, found_out)
+        get_validator(
+            expected_in,
+            found_in.khalifa_marshall,
+            "SIMPLE (Khalifa-Marshall)",
+            GofThresholds::default(),
+        )
     }
 
     validations.push(natural());
-    validations.push(forced());
+    validations.push(natural_adaptive());
+    validations.push(natural_alamdari_hammond());
+    validations.push(natural_fohanno_polidori());
+    validations.push(natural_khalifa_marshall());
+    validations.push(forced_tarp());
+    validations.push(forced_mowitt());
+    validations.push(forced_doe2());
+    validations.push(forced_adaptive());
 }
 
 #[test]
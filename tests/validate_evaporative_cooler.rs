@@ -0,0 +1,82 @@
+//! Checks [`heat::heating_cooling::EvaporativeCooler`] (a direct
+//! evaporative cooler) in isolation: this crate has no zone humidity state
+//! to march it against (see the struct's own doc comment), so these drive
+//! its pure supply-temperature and RH-throttled-output functions directly
+//! under hot, dry conditions, the way the sibling `validate_*` model
+//! harnesses drive a full march but without one to drive here.
+
+use heat::heating_cooling::EvaporativeCooler;
+use heat::psychrometrics::wet_bulb_temperature;
+
+fn cooler() -> EvaporativeCooler {
+    EvaporativeCooler {
+        effectiveness: 0.75,
+        design_outdoor_rh: 15.0,
+        rated_capacity: 2000.0,
+        dry_bulb_setpoint: 26.0,
+        rh_limit: 60.0,
+        rh_throttle_band: 10.0,
+        target_space_index: 0,
+    }
+}
+
+#[test]
+fn supply_temperature_sits_between_dry_bulb_and_wet_bulb() {
+    let c = cooler();
+    let t_out = 38.0;
+    let t_wb = wet_bulb_temperature(t_out, c.design_outdoor_rh);
+    let t_supply = c.supply_temperature(t_out, t_wb);
+    assert!(t_supply < t_out);
+    assert!(t_supply > t_wb);
+    // effectiveness = 0.75 closes 75% of the dry-bulb/wet-bulb gap.
+    let expected = t_out - 0.75 * (t_out - t_wb);
+    assert!((t_supply - expected).abs() < 1e-9);
+}
+
+#[test]
+fn cooler_is_off_below_its_dry_bulb_setpoint() {
+    let c = cooler();
+    assert_eq!(c.modulation_fraction(20.0, 30.0), 0.0);
+    assert_eq!(c.cooling_output(20.0, 38.0, 30.0), 0.0);
+}
+
+#[test]
+fn cooler_runs_at_full_output_when_hot_dry_and_under_its_throttle_band() {
+    let c = cooler();
+    let t_room = 30.0;
+    let t_out = 38.0;
+    let rh_room = 40.0; // 20% below rh_limit, beyond the 10%-wide throttle band
+    assert_eq!(c.modulation_fraction(t_room, rh_room), 1.0);
+    let delivered = c.cooling_output(t_room, t_out, rh_room);
+    assert_eq!(delivered, -c.rated_capacity);
+}
+
+#[test]
+fn cooler_throttles_down_as_zone_humidity_approaches_its_limit() {
+    let c = cooler();
+    let t_room = 30.0;
+    // 5% of headroom left within a 10%-wide throttle band -> half output.
+    let rh_room = c.rh_limit - 5.0;
+    let fraction = c.modulation_fraction(t_room, rh_room);
+    assert!((fraction - 0.5).abs() < 1e-9);
+}
+
+#[test]
+fn cooler_shuts_off_once_zone_humidity_reaches_its_limit_even_if_hot() {
+    let c = cooler();
+    let t_room = 35.0;
+    assert_eq!(c.modulation_fraction(t_room, c.rh_limit), 0.0);
+    assert_eq!(c.cooling_output(t_room, 38.0, c.rh_limit), 0.0);
+}
+
+#[test]
+fn cooler_will_not_run_if_outdoor_air_is_too_humid_to_cool_the_room() {
+    let mut c = cooler();
+    // Outdoor air almost saturated: the wet-bulb-limited supply temperature
+    // can no longer drop below a moderately warm room, so running the unit
+    // would do nothing useful.
+    c.design_outdoor_rh = 99.0;
+    let t_room = 27.0;
+    let t_out = 28.0;
+    assert_eq!(c.cooling_output(t_room, t_out, 20.0), 0.0);
+}
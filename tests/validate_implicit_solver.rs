@@ -0,0 +1,124 @@
+//! Checks [`heat::discretization::IntegrationScheme::Theta`] (backward
+//! Euler and Crank-Nicolson) against the explicit
+//! [`heat::discretization::IntegrationScheme::RK4`] default, on a massive
+//! wall subjected to a step change in outdoor temperature.
+//!
+//! [`heat::model::ThermalModel::new`] still sizes its own internal
+//! sub-stepping for [`heat::discretization::IntegrationScheme::RK4`]'s
+//! explicit stability limit regardless of the scheme later selected with
+//! [`heat::model::ThermalModel::set_scheme`] (see that method's doc
+//! comment), so this can't yet demonstrate an implicit march holding up at
+//! a *coarser* step than explicit would tolerate. What it does show is
+//! that, at the same (RK4-safe) step, the unconditionally-stable implicit
+//! schemes reproduce the explicit reference closely—i.e. switching schemes
+//! doesn't trade accuracy away.
+
+use calendar::Date;
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::discretization::IntegrationScheme;
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+use heat::Float;
+use schedule::ScheduleConstant;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+/// Marches a massive, single-zone test wall (no solar, no long-wave) from
+/// a uniform starting temperature against a step change in outdoor
+/// temperature, using `scheme` for the wall's conduction, and returns the
+/// zone air temperature series.
+fn march_with_scheme(scheme: IntegrationScheme) -> Vec<Float> {
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume: 60.,
+        surface_height: 3.,
+        surface_width: 3.,
+        construction: vec![TestMat::Concrete(0.2)],
+        emissivity: 0.0,
+        ..Default::default()
+    });
+
+    let n: usize = 20;
+    let main_dt = 60. * 60. / n as Float;
+    let mut thermal_model = ThermalModel::new(
+        &META_OPTIONS,
+        ZoneCouplingScheme::Staged,
+        &simple_model,
+        &mut state_header,
+        n,
+    )
+    .unwrap();
+    thermal_model.set_scheme(scheme);
+
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(35.0));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let mut date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+
+    let n_steps = 400;
+    let mut found = Vec::with_capacity(n_steps);
+    for i in 0..n_steps {
+        let time = (i as Float) * main_dt;
+        date.add_seconds(time);
+
+        found.push(
+            thermal_model.zones[0]
+                .reference_space
+                .dry_bulb_temperature(&state)
+                .unwrap(),
+        );
+
+        thermal_model
+            .march(date, &weather, &simple_model, &mut state, &mut memory)
+            .unwrap();
+    }
+    found
+}
+
+fn max_abs_diff(a: &[Float], b: &[Float]) -> Float {
+    a.iter().zip(b).map(|(x, y)| (x - y).abs()).fold(0.0, Float::max)
+}
+
+#[test]
+fn backward_euler_matches_explicit_rk4() {
+    let explicit = march_with_scheme(IntegrationScheme::RK4);
+    let implicit = march_with_scheme(IntegrationScheme::backward_euler());
+    let err = max_abs_diff(&explicit, &implicit);
+    assert!(
+        err < 0.1,
+        "backward Euler's zone temperature diverged from RK4's by {err} C"
+    );
+}
+
+#[test]
+fn crank_nicolson_matches_explicit_rk4() {
+    let explicit = march_with_scheme(IntegrationScheme::RK4);
+    let implicit = march_with_scheme(IntegrationScheme::crank_nicolson());
+    let err = max_abs_diff(&explicit, &implicit);
+    assert!(
+        err < 0.05,
+        "Crank-Nicolson's zone temperature diverged from RK4's by {err} C"
+    );
+}
+
+#[test]
+fn neither_implicit_scheme_produces_nan_or_diverging_temperatures() {
+    for scheme in [IntegrationScheme::backward_euler(), IntegrationScheme::crank_nicolson()] {
+        let found = march_with_scheme(scheme);
+        assert!(found.iter().all(|t| t.is_finite() && *t < 100.0));
+    }
+}
@@ -0,0 +1,77 @@
+//! Checks [`heat::model::ThermalModel::set_sky_clearness`]: once enabled, a
+//! surface's exterior (front) infrared irradiance is driven automatically by
+//! [`heat::sky::SkyModel`] every march, instead of requiring the caller to
+//! poke [`heat::surface::ThermalSurfaceData::set_sky_ir_irradiance`] (or the
+//! lower-level `set_front_ir_irradiance`) by hand.
+
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+
+use calendar::Date;
+use schedule::ScheduleConstant;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+#[test]
+fn sky_clearness_drives_front_ir_irradiance_automatically() {
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume: 60.,
+        surface_height: 3.,
+        surface_width: 3.,
+        construction: vec![TestMat::Concrete(0.2)],
+        ..Default::default()
+    });
+
+    let mut thermal_model = ThermalModel::new(
+        &META_OPTIONS,
+        ZoneCouplingScheme::Staged,
+        &simple_model,
+        &mut state_header,
+        1,
+    )
+    .unwrap();
+    thermal_model.set_sky_clearness(1.0);
+
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(10.0));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+    let surface = &simple_model.surfaces[0];
+
+    // Nobody poked `set_front_ir_irradiance` for this timestep, yet the
+    // field shouldn't be left at its constructed default any more—
+    // `ThermalModel::march` should have driven it from a clear-sky model
+    // colder than the 10C outdoor dry-bulb.
+    thermal_model
+        .march(date, &weather, &simple_model, &mut state, &mut memory)
+        .unwrap();
+
+    let front_ir = surface.front_infrared_irradiance(&state);
+    assert!(
+        front_ir > 0.0,
+        "expected automatic sky IR irradiance to be a positive incident flux, got {front_ir}"
+    );
+
+    let t_out_kelvin = 10.0 + 273.15;
+    let blackbody_at_t_out = heat::SIGMA * t_out_kelvin.powi(4);
+    assert!(
+        front_ir < blackbody_at_t_out,
+        "a clear sky should read colder than ambient air: got {front_ir}, ambient blackbody is {blackbody_at_t_out}"
+    );
+}
@@ -0,0 +1,154 @@
+//! Checks [`heat::surface::ThermochromicCoating`]: a surface whose front
+//! absorptance switches to a lower value once its own front-node
+//! temperature crosses a threshold should reach a lower peak front-node
+//! temperature than an otherwise-identical surface with a fixed (high)
+//! absorptance, under the same solar exposure.
+
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+use heat::surface::ThermochromicCoating;
+use heat::Float;
+
+use calendar::Date;
+use schedule::ScheduleConstant;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+/// Marches a single-zone test building under constant solar exposure,
+/// optionally attaching `coating` to the exterior face of its one wall,
+/// and returns the peak front-node (exterior) surface temperature reached
+/// over the run.
+fn peak_front_node_temperature(coating: Option<ThermochromicCoating>, construction: Vec<TestMat>) -> Float {
+    let surface_height = 3.;
+    let surface_width = 3.;
+    let zone_volume = 60.;
+    let solar_absorbtance = 0.7;
+
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume,
+        surface_height,
+        surface_width,
+        construction,
+        emissivity: 0.9,
+        solar_absorbtance,
+        ..Default::default()
+    });
+
+    let n: usize = 20;
+    let main_dt = 60. * 60. / n as Float;
+    let mut thermal_model = ThermalModel::new(
+        &META_OPTIONS,
+        ZoneCouplingScheme::Staged,
+        &simple_model,
+        &mut state_header,
+        n,
+    )
+    .unwrap();
+    thermal_model.surfaces[0].front_coating = coating;
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    let t_out: Float = 25.0;
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(t_out));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let mut date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+    let surface = &simple_model.surfaces[0];
+
+    let n_steps = 200;
+    let mut peak = Float::MIN;
+    for i in 0..n_steps {
+        let time = (i as Float) * main_dt;
+        date.add_seconds(time);
+
+        thermal_model.surfaces[0].update_coatings(&state).unwrap();
+
+        surface
+            .set_front_incident_solar_irradiance(&mut state, 700.)
+            .unwrap();
+
+        thermal_model
+            .march(date, &weather, &simple_model, &mut state, &mut memory)
+            .unwrap();
+
+        let t_front = surface.first_node_temperature(&state).unwrap();
+        if t_front > peak {
+            peak = t_front;
+        }
+    }
+    peak
+}
+
+fn switching_coating() -> ThermochromicCoating {
+    ThermochromicCoating {
+        rising_threshold_temperature: 35.,
+        falling_threshold_temperature: 35.,
+        low_solar_absorptance: 0.7,
+        high_solar_absorptance: 0.2,
+        low_emissivity: 0.9,
+        high_emissivity: 0.9,
+        currently_high: false,
+    }
+}
+
+#[test]
+fn thermochromic_coating_reduces_peak_surface_temperature_on_massive_wall() {
+    let fixed_peak = peak_front_node_temperature(None, vec![TestMat::Concrete(0.2)]);
+    let coated_peak = peak_front_node_temperature(Some(switching_coating()), vec![TestMat::Concrete(0.2)]);
+
+    assert!(
+        coated_peak < fixed_peak,
+        "expected coated peak ({coated_peak}) to be cooler than fixed peak ({fixed_peak})"
+    );
+}
+
+#[test]
+fn thermochromic_coating_reduces_peak_surface_temperature_on_nomass_wall() {
+    let fixed_peak = peak_front_node_temperature(None, vec![TestMat::Polyurethane(0.02)]);
+    let coated_peak = peak_front_node_temperature(Some(switching_coating()), vec![TestMat::Polyurethane(0.02)]);
+
+    assert!(
+        coated_peak < fixed_peak,
+        "expected coated peak ({coated_peak}) to be cooler than fixed peak ({fixed_peak})"
+    );
+}
+
+#[test]
+fn hysteresis_band_prevents_chattering_at_the_threshold() {
+    let mut coating = ThermochromicCoating {
+        rising_threshold_temperature: 36.,
+        falling_threshold_temperature: 34.,
+        low_solar_absorptance: 0.7,
+        high_solar_absorptance: 0.2,
+        low_emissivity: 0.9,
+        high_emissivity: 0.9,
+        currently_high: false,
+    };
+
+    // Rises past the upper threshold: switches to "high".
+    let (absorptance, _) = coating.properties_at(37.);
+    assert_eq!(absorptance, 0.2);
+
+    // Dips back into the band (between the two thresholds): a
+    // non-hysteresis (single-threshold) switch would flip back to "low"
+    // here, but the coating should hold its "high" state.
+    let (absorptance, _) = coating.properties_at(35.);
+    assert_eq!(absorptance, 0.2);
+
+    // Only falls back to "low" once it crosses the lower threshold.
+    let (absorptance, _) = coating.properties_at(33.);
+    assert_eq!(absorptance, 0.7);
+}
@@ -0,0 +1,114 @@
+//! Checks [`heat::heating_cooling::ThermalHVAC::WetDistribution`] (a
+//! [`heat::heating_cooling::WetEmitter`]): marched alongside a single-zone
+//! test building's own heat balance, it should settle to the same zone air
+//! steady state as a closed-form hand calculation, matching the sibling
+//! `march_with_window_and_heater` harnesses' no-mass single-zone setup.
+
+use calendar::Date;
+use communication_protocols::{MetaOptions, SimulationModel};
+use heat::heating_cooling::{ThermalHVAC, WetEmitter};
+use heat::model::{ThermalModel, ZoneCouplingScheme};
+use heat::Float;
+use schedule::ScheduleConstant;
+use simple_test_models::{get_single_zone_test_building, SingleZoneTestBuildingOptions, TestMat};
+use weather::SyntheticWeather;
+
+const META_OPTIONS: MetaOptions = MetaOptions {
+    latitude: 0.,
+    longitude: 0.,
+    standard_meridian: 0.,
+    elevation: 0.0,
+};
+
+/// Marches a no-mass, no-window, no-solar single-zone test building heated
+/// by a purely-convective [`WetEmitter`] (`frac_convective = 1.0`, so none
+/// of its output needs to pass through the interior surfaces' own balance)
+/// for long enough to settle, and returns the final zone air temperature
+/// alongside the conductance `U*A` (W/K) between the zone and outdoors.
+fn march_to_steady_state(q_nom: Float) -> (Float, Float) {
+    let zone_volume = 40.;
+    let surface_width = 2.;
+    let surface_height = 2.;
+    let (simple_model, mut state_header) = get_single_zone_test_building(&SingleZoneTestBuildingOptions {
+        zone_volume,
+        surface_height,
+        surface_width,
+        construction: vec![TestMat::Polyurethane(0.02)],
+        emissivity: 0.0,
+        ..Default::default()
+    });
+
+    let n: usize = 20;
+    let main_dt = 60. * 60. / n as Float;
+    let mut thermal_model = ThermalModel::new(
+        &META_OPTIONS,
+        ZoneCouplingScheme::Staged,
+        &simple_model,
+        &mut state_header,
+        n,
+    )
+    .unwrap();
+
+    let hs_front = 10.;
+    let hs_back = 10.;
+    thermal_model.surfaces[0].front_hs = Some(hs_front);
+    thermal_model.surfaces[0].back_hs = Some(hs_back);
+    let r = thermal_model.surfaces[0].discretization.r_value() + 1. / hs_front + 1. / hs_back;
+    let u_a = surface_height * surface_width / r;
+
+    thermal_model.hvacs.push(ThermalHVAC::new_wet_distribution(WetEmitter {
+        c: 8500.,
+        q_nom,
+        dt_nom: 50.,
+        n: 1.3,
+        frac_convective: 1.0,
+        target_space_index: 0,
+    }));
+
+    let mut memory = thermal_model.allocate_memory().unwrap();
+    let mut state = state_header.take_values().unwrap();
+
+    let mut weather = SyntheticWeather::default();
+    weather.dry_bulb_temperature = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_direction = Box::new(ScheduleConstant::new(0.0));
+    weather.wind_speed = Box::new(ScheduleConstant::new(0.0));
+
+    let mut date = Date {
+        day: 1,
+        hour: 0.0,
+        month: 1,
+    };
+
+    // Long enough for both the emitter's own lag and the (no-mass) zone
+    // balance to settle.
+    let n_steps = 4000;
+    for i in 0..n_steps {
+        let time = (i as Float) * main_dt;
+        date.add_seconds(time);
+        thermal_model
+            .march(date, &weather, &simple_model, &mut state, &mut memory)
+            .unwrap();
+    }
+
+    let t_room = thermal_model.zones[0]
+        .reference_space
+        .dry_bulb_temperature(&state)
+        .unwrap();
+    (t_room, u_a)
+}
+
+#[test]
+fn wet_emitter_reaches_expected_convective_steady_state() {
+    // With a purely-convective emitter (frac_convective = 1.0) and a
+    // constant heat source (q_in defaults to q_nom, left unmodulated),
+    // steady state (dT_e/dt = 0) forces Q_out = q_in = q_nom, and the
+    // zone's own steady state is U*A*(T_room - T_out) = Q_out, so
+    // T_room = T_out + q_nom/(U*A). T_out is fixed at 0C above.
+    let q_nom = 400.;
+    let (t_room, u_a) = march_to_steady_state(q_nom);
+    let expected = q_nom / u_a;
+    assert!(
+        (t_room - expected).abs() < 0.1,
+        "expected steady-state zone temp {expected}, found {t_room}"
+    );
+}